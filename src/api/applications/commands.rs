@@ -0,0 +1,281 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+
+use crate::{
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{
+        ApplicationCommand, ApplicationCommandCreateSchema, ApplicationCommandModifySchema,
+        ApplicationCommandPermission, ApplicationCommandPermissionsModifySchema,
+        ApplicationCommandScope, GuildApplicationCommandPermissions, LimitType, Snowflake,
+    },
+};
+
+impl ApplicationCommand {
+    /// Returns the commands of an application in the given scope.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/application-commands#get-global-application-commands>
+    pub async fn get_all(
+        user: &mut ChorusUser,
+        application_id: Snowflake,
+        scope: ApplicationCommandScope,
+    ) -> ChorusResult<Vec<ApplicationCommand>> {
+        let url = commands_url(user, application_id, scope);
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: limit_type(scope),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<ApplicationCommand>>(user)
+        .await
+    }
+
+    /// Returns a single command of an application.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/application-commands#get-global-application-command>
+    pub async fn get(
+        user: &mut ChorusUser,
+        application_id: Snowflake,
+        scope: ApplicationCommandScope,
+        command_id: Snowflake,
+    ) -> ChorusResult<ApplicationCommand> {
+        let url = format!(
+            "{}/{}",
+            commands_url(user, application_id, scope),
+            command_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: limit_type(scope),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<ApplicationCommand>(user)
+        .await
+    }
+
+    /// Creates a command, returning the created command.
+    ///
+    /// If a command with the same name and type already exists in the scope, it is overwritten.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/application-commands#create-global-application-command>
+    pub async fn create(
+        user: &mut ChorusUser,
+        application_id: Snowflake,
+        scope: ApplicationCommandScope,
+        schema: ApplicationCommandCreateSchema,
+    ) -> ChorusResult<ApplicationCommand> {
+        let url = commands_url(user, application_id, scope);
+
+        ChorusRequest {
+            request: user.http_client().post(url).json(&schema),
+            limit_type: limit_type(scope),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<ApplicationCommand>(user)
+        .await
+    }
+
+    /// Modifies a command, returning the updated command.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/application-commands#edit-global-application-command>
+    pub async fn modify(
+        user: &mut ChorusUser,
+        application_id: Snowflake,
+        scope: ApplicationCommandScope,
+        command_id: Snowflake,
+        schema: ApplicationCommandModifySchema,
+    ) -> ChorusResult<ApplicationCommand> {
+        let url = format!(
+            "{}/{}",
+            commands_url(user, application_id, scope),
+            command_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().patch(url).json(&schema),
+            limit_type: limit_type(scope),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<ApplicationCommand>(user)
+        .await
+    }
+
+    /// Deletes a command.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/application-commands#delete-global-application-command>
+    pub async fn delete(
+        user: &mut ChorusUser,
+        application_id: Snowflake,
+        scope: ApplicationCommandScope,
+        command_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/{}",
+            commands_url(user, application_id, scope),
+            command_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().delete(url),
+            limit_type: limit_type(scope),
+        }
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+
+    /// Replaces all commands of an application in the given scope, returning the new commands.
+    ///
+    /// Commands which are not in `schemas` are deleted; commands with the same name and type as
+    /// an existing command keep its id.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/application-commands#bulk-overwrite-global-application-commands>
+    pub async fn bulk_overwrite(
+        user: &mut ChorusUser,
+        application_id: Snowflake,
+        scope: ApplicationCommandScope,
+        schemas: Vec<ApplicationCommandCreateSchema>,
+    ) -> ChorusResult<Vec<ApplicationCommand>> {
+        let url = commands_url(user, application_id, scope);
+
+        ChorusRequest {
+            request: user.http_client().put(url).json(&schemas),
+            limit_type: limit_type(scope),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<ApplicationCommand>>(user)
+        .await
+    }
+
+    /// Returns the permission overwrites of all commands of an application in a guild.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/application-commands#get-guild-application-command-permissions>
+    pub async fn get_guild_permissions(
+        user: &mut ChorusUser,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+    ) -> ChorusResult<Vec<GuildApplicationCommandPermissions>> {
+        let url = format!(
+            "{}/permissions",
+            commands_url(
+                user,
+                application_id,
+                ApplicationCommandScope::Guild(guild_id)
+            )
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<GuildApplicationCommandPermissions>>(user)
+        .await
+    }
+
+    /// Returns the permission overwrites of a command in a guild.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/application-commands#get-application-command-permissions>
+    pub async fn get_permissions(
+        user: &mut ChorusUser,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+        command_id: Snowflake,
+    ) -> ChorusResult<GuildApplicationCommandPermissions> {
+        let url = format!(
+            "{}/{}/permissions",
+            commands_url(
+                user,
+                application_id,
+                ApplicationCommandScope::Guild(guild_id)
+            ),
+            command_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<GuildApplicationCommandPermissions>(user)
+        .await
+    }
+
+    /// Replaces the permission overwrites of a command in a guild, returning the new overwrites.
+    ///
+    /// `command_id` can also be the application's id, to set the overwrites of all its commands.
+    ///
+    /// # Notes
+    /// This route can't be used with a bot token; it requires the user to be able to manage the
+    /// guild and its roles.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/application-commands#edit-application-command-permissions>
+    pub async fn modify_permissions(
+        user: &mut ChorusUser,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+        command_id: Snowflake,
+        permissions: Vec<ApplicationCommandPermission>,
+    ) -> ChorusResult<GuildApplicationCommandPermissions> {
+        let url = format!(
+            "{}/{}/permissions",
+            commands_url(
+                user,
+                application_id,
+                ApplicationCommandScope::Guild(guild_id)
+            ),
+            command_id
+        );
+
+        ChorusRequest {
+            request: user
+                .http_client()
+                .put(url)
+                .json(&ApplicationCommandPermissionsModifySchema { permissions }),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<GuildApplicationCommandPermissions>(user)
+        .await
+    }
+}
+
+/// Returns the url of the commands of an application in the given scope
+fn commands_url(
+    user: &ChorusUser,
+    application_id: Snowflake,
+    scope: ApplicationCommandScope,
+) -> String {
+    let api_url = user.belongs_to.read().unwrap().urls.api.clone();
+    match scope {
+        ApplicationCommandScope::Global => {
+            format!("{}/applications/{}/commands", api_url, application_id)
+        }
+        ApplicationCommandScope::Guild(guild_id) => format!(
+            "{}/applications/{}/guilds/{}/commands",
+            api_url, application_id, guild_id
+        ),
+    }
+}
+
+fn limit_type(scope: ApplicationCommandScope) -> LimitType {
+    match scope {
+        ApplicationCommandScope::Global => LimitType::Global,
+        ApplicationCommandScope::Guild(guild_id) => LimitType::Guild(guild_id),
+    }
+}
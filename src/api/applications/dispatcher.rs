@@ -0,0 +1,394 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Routing application command interactions to handlers.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::warn;
+use pubserve::Subscriber;
+use serde_json::Value;
+
+use crate::{
+    api::WebhookClient,
+    errors::{ChorusError, ChorusResult},
+    instance::ChorusUser,
+    types::{
+        ApplicationCommandInteractionData, ApplicationCommandInteractionDataOption,
+        ApplicationCommandOptionType, Interaction, InteractionApplicationCommandCallbackData,
+        InteractionCreate, InteractionType, Shared, Snowflake,
+    },
+};
+
+/// Handles the invocations of an application command, see [InteractionDispatcher::register].
+///
+/// Implemented for async functions and closures taking a [CommandContext].
+///
+/// On wasm, handlers are run with `spawn_local`, so the futures they return don't have to be
+/// [Send]. The handlers themselves are shared with the gateway, so they still have to be [Send]
+/// and [Sync].
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait CommandHandler: Send + Sync {
+    async fn handle(&self, context: CommandContext) -> ChorusResult<()>;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl<F, Fut> CommandHandler for F
+where
+    F: Fn(CommandContext) -> Fut + Send + Sync,
+    Fut: Future<Output = ChorusResult<()>> + Send,
+{
+    async fn handle(&self, context: CommandContext) -> ChorusResult<()> {
+        (self)(context).await
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[async_trait(?Send)]
+impl<F, Fut> CommandHandler for F
+where
+    F: Fn(CommandContext) -> Fut + Send + Sync,
+    Fut: Future<Output = ChorusResult<()>>,
+{
+    async fn handle(&self, context: CommandContext) -> ChorusResult<()> {
+        (self)(context).await
+    }
+}
+
+/// Routes application command interactions to handlers registered by command name.
+///
+/// To receive interactions from the gateway, subscribe the dispatcher to the
+/// [InteractionCreate] event:
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use chorus::api::{CommandContext, InteractionDispatcher};
+/// # use chorus::types::InteractionApplicationCommandCallbackData;
+/// # async fn example(mut user: chorus::instance::ChorusUser) -> chorus::errors::ChorusResult<()> {
+/// let mut dispatcher = InteractionDispatcher::new(user.clone());
+/// dispatcher.register("ping", |mut context: CommandContext| async move {
+///     context
+///         .reply(InteractionApplicationCommandCallbackData {
+///             content: Some("Pong!".to_string()),
+///             ..Default::default()
+///         })
+///         .await
+/// });
+///
+/// let dispatcher = Arc::new(dispatcher);
+/// user.connect_gateway()
+///     .await?
+///     .events
+///     .lock()
+///     .await
+///     .interaction
+///     .create
+///     .subscribe(dispatcher);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// When subscribed to the gateway, each handler runs in its own task, so that it doesn't hold up
+/// the handling of other events; errors returned by handlers are logged.
+///
+/// Subcommands can be handled separately by registering their full path, e.g. `"role add"`;
+/// an interaction is routed to the handler of its longest registered path.
+pub struct InteractionDispatcher {
+    user: ChorusUser,
+    handlers: HashMap<String, Arc<dyn CommandHandler>>,
+}
+
+impl std::fmt::Debug for InteractionDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InteractionDispatcher")
+            .field("handlers", &self.handlers.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl InteractionDispatcher {
+    /// Creates a dispatcher which responds to interactions as `user`.
+    pub fn new(user: ChorusUser) -> InteractionDispatcher {
+        InteractionDispatcher {
+            user,
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// Registers the handler of a command, or of a subcommand if `name` is a path like
+    /// `"role add"`, replacing any previous handler.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        handler: impl CommandHandler + 'static,
+    ) -> &mut Self {
+        self.handlers.insert(name.into(), Arc::new(handler));
+        self
+    }
+
+    /// Removes the handler of a command, returning whether there was one.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.handlers.remove(name).is_some()
+    }
+
+    /// Returns the registered path which would handle a command invocation, if any.
+    pub fn route(&self, data: &ApplicationCommandInteractionData) -> Option<&str> {
+        let options = CommandOptions::from_data(data);
+        let mut path = vec![data.name.as_str()];
+        path.extend(options.subcommand_path().iter().map(String::as_str));
+
+        while !path.is_empty() {
+            if let Some((name, _)) = self.handlers.get_key_value(&path.join(" ")) {
+                return Some(name);
+            }
+            path.pop();
+        }
+        None
+    }
+
+    /// Passes an interaction to its handler, returning whether it was handled.
+    ///
+    /// Interactions other than [InteractionType::ApplicationCommand] and commands without a
+    /// handler are ignored.
+    pub async fn dispatch(&self, interaction: &Interaction) -> ChorusResult<bool> {
+        match self.handler_for(interaction)? {
+            Some((handler, context)) => {
+                handler.handle(context).await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the handler of an interaction and the context to call it with, if it has one
+    fn handler_for(
+        &self,
+        interaction: &Interaction,
+    ) -> ChorusResult<Option<(Arc<dyn CommandHandler>, CommandContext)>> {
+        if interaction.r#type != InteractionType::ApplicationCommand {
+            return Ok(None);
+        }
+
+        let data = interaction.command_data()?;
+        let Some(handler) = self.route(&data).and_then(|name| self.handlers.get(name)) else {
+            return Ok(None);
+        };
+
+        let context = CommandContext {
+            user: self.user.clone(),
+            interaction: interaction.clone(),
+            options: CommandOptions::from_data(&data),
+            data,
+        };
+        Ok(Some((handler.clone(), context)))
+    }
+}
+
+#[async_trait]
+impl Subscriber<InteractionCreate> for InteractionDispatcher {
+    async fn update(&self, event: &InteractionCreate) {
+        let interaction_id = event.interaction.id;
+        let (handler, context) = match self.handler_for(&event.interaction) {
+            Ok(Some(handler)) => handler,
+            Ok(None) => return,
+            Err(e) => {
+                warn!(
+                    "InteractionDispatcher failed to handle interaction {}: {}",
+                    interaction_id, e
+                );
+                return;
+            }
+        };
+
+        let handle = async move {
+            if let Err(e) = handler.handle(context).await {
+                warn!(
+                    "InteractionDispatcher failed to handle interaction {}: {}",
+                    interaction_id, e
+                );
+            }
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        tokio::task::spawn(handle);
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(handle);
+    }
+}
+
+/// An invocation of an application command, passed to its [CommandHandler]
+#[derive(Debug, Clone)]
+pub struct CommandContext {
+    /// The user the dispatcher responds as
+    pub user: ChorusUser,
+    pub interaction: Interaction,
+    pub data: ApplicationCommandInteractionData,
+    /// The options of the invoked (sub)command
+    pub options: CommandOptions,
+}
+
+impl CommandContext {
+    /// Responds to the interaction with a message, see [Interaction::reply]
+    pub async fn reply(
+        &mut self,
+        data: InteractionApplicationCommandCallbackData,
+    ) -> ChorusResult<()> {
+        self.interaction.reply(&mut self.user, data).await
+    }
+
+    /// Acknowledges the interaction to respond later, see [Interaction::defer]
+    pub async fn defer(&mut self, ephemeral: bool) -> ChorusResult<()> {
+        self.interaction.defer(&mut self.user, ephemeral).await
+    }
+
+    /// Returns a client to edit the response and send followup messages, see
+    /// [Interaction::followup]
+    pub fn followup(&self) -> WebhookClient {
+        self.interaction.followup(&self.user)
+    }
+
+    /// Parses the options of the invoked (sub)command into `T`
+    pub fn parse<T: FromCommandOptions>(&self) -> ChorusResult<T> {
+        T::from_options(&self.options)
+    }
+}
+
+/// The options of an application command invocation, with subcommands resolved
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommandOptions {
+    subcommand_path: Vec<String>,
+    values: HashMap<String, Value>,
+    focused: Option<String>,
+}
+
+impl CommandOptions {
+    /// Collects the options of the invoked (sub)command.
+    pub fn from_data(data: &ApplicationCommandInteractionData) -> CommandOptions {
+        let mut options = CommandOptions::default();
+        let mut current = data.options.clone();
+
+        loop {
+            let subcommand = current.iter().find(|option| {
+                matches!(
+                    option.read().unwrap().option_type,
+                    ApplicationCommandOptionType::SubCommand
+                        | ApplicationCommandOptionType::SubCommandGroup
+                )
+            });
+            let Some(subcommand) = subcommand.cloned() else {
+                break;
+            };
+            let subcommand = subcommand.read().unwrap();
+            options.subcommand_path.push(subcommand.name.clone());
+            current = subcommand.options.clone();
+        }
+
+        for option in current.iter() {
+            options.insert(option);
+        }
+        options
+    }
+
+    fn insert(&mut self, option: &Shared<ApplicationCommandInteractionDataOption>) {
+        let option = option.read().unwrap();
+        if option.focused == Some(true) {
+            self.focused = Some(option.name.clone());
+        }
+        self.values
+            .insert(option.name.clone(), option.value.clone());
+    }
+
+    /// Returns the names of the invoked subcommand group and subcommand, outermost first
+    pub fn subcommand_path(&self) -> &[String] {
+        &self.subcommand_path
+    }
+
+    /// Returns the name of the invoked subcommand, if any
+    pub fn subcommand(&self) -> Option<&str> {
+        self.subcommand_path.last().map(String::as_str)
+    }
+
+    /// Returns the name of the option the user is typing, in autocomplete interactions
+    pub fn focused(&self) -> Option<&str> {
+        self.focused.as_deref()
+    }
+
+    /// Returns the raw value of an option
+    pub fn raw(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+
+    /// Parses a required option.
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the option is missing or of the wrong type.
+    pub fn get<T: FromCommandOption>(&self, name: &str) -> ChorusResult<T> {
+        self.get_optional(name)?
+            .ok_or_else(|| ChorusError::InvalidArguments {
+                error: format!("Missing command option {}", name),
+            })
+    }
+
+    /// Parses an optional option.
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the option is of the wrong type.
+    pub fn get_optional<T: FromCommandOption>(&self, name: &str) -> ChorusResult<Option<T>> {
+        match self.values.get(name) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => {
+                T::from_option(value)
+                    .map(Some)
+                    .ok_or_else(|| ChorusError::InvalidArguments {
+                        error: format!("Invalid value for command option {}: {}", name, value),
+                    })
+            }
+        }
+    }
+}
+
+/// A type a single command option can be parsed into, see [CommandOptions::get]
+pub trait FromCommandOption: Sized {
+    /// Parses the option's value, or returns [None] if it is of the wrong type
+    fn from_option(value: &Value) -> Option<Self>;
+}
+
+impl FromCommandOption for String {
+    fn from_option(value: &Value) -> Option<Self> {
+        value.as_str().map(str::to_string)
+    }
+}
+
+impl FromCommandOption for i64 {
+    fn from_option(value: &Value) -> Option<Self> {
+        value.as_i64()
+    }
+}
+
+impl FromCommandOption for f64 {
+    fn from_option(value: &Value) -> Option<Self> {
+        value.as_f64()
+    }
+}
+
+impl FromCommandOption for bool {
+    fn from_option(value: &Value) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+/// User, channel, role, mentionable and attachment options
+impl FromCommandOption for Snowflake {
+    fn from_option(value: &Value) -> Option<Self> {
+        serde_json::from_value(value.clone()).ok()
+    }
+}
+
+/// A type the options of a command can be parsed into as a whole, see [CommandContext::parse]
+pub trait FromCommandOptions: Sized {
+    fn from_options(options: &CommandOptions) -> ChorusResult<Self>;
+}
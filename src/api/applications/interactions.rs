@@ -0,0 +1,155 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+
+use crate::{
+    api::WebhookClient,
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{
+        ApplicationCommandOptionChoice, Interaction, InteractionApplicationCommandCallbackData,
+        InteractionResponse, InteractionResponseType, LimitType, MessageFlags,
+    },
+};
+
+impl Interaction {
+    /// Responds to the interaction.
+    ///
+    /// Every interaction has to be responded to within 3 seconds, and only once; to take longer,
+    /// see [Interaction::defer]. Further messages can be sent with [Interaction::followup].
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/receiving-and-responding#create-interaction-response>
+    pub async fn respond(
+        &self,
+        user: &mut ChorusUser,
+        response: InteractionResponse,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/interactions/{}/{}/callback",
+            user.belongs_to.read().unwrap().urls.api,
+            self.id,
+            self.token
+        );
+
+        ChorusRequest {
+            request: user.http_client().post(url).json(&response),
+            limit_type: LimitType::Global,
+        }
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+
+    /// Responds to the interaction with a message.
+    ///
+    /// See [Interaction::respond].
+    pub async fn reply(
+        &self,
+        user: &mut ChorusUser,
+        data: InteractionApplicationCommandCallbackData,
+    ) -> ChorusResult<()> {
+        self.respond(
+            user,
+            InteractionResponse {
+                response_type: InteractionResponseType::ChannelMessageWithSource,
+                data: Some(data),
+            },
+        )
+        .await
+    }
+
+    /// Acknowledges the interaction, showing a loading state until the response is sent with
+    /// [WebhookClient::edit_original_message] (see [Interaction::followup]).
+    ///
+    /// If `ephemeral` is set, the response will only be visible to the invoking user.
+    ///
+    /// See [Interaction::respond].
+    pub async fn defer(&self, user: &mut ChorusUser, ephemeral: bool) -> ChorusResult<()> {
+        let data = ephemeral.then(|| InteractionApplicationCommandCallbackData {
+            flags: Some(MessageFlags::EPHEMERAL),
+            ..Default::default()
+        });
+        self.respond(
+            user,
+            InteractionResponse {
+                response_type: InteractionResponseType::AcknowledgeWithSource,
+                data,
+            },
+        )
+        .await
+    }
+
+    /// Acknowledges a component interaction without changing its message yet; the message can be
+    /// edited later with [WebhookClient::edit_original_message] (see [Interaction::followup]).
+    ///
+    /// See [Interaction::respond].
+    pub async fn defer_update(&self, user: &mut ChorusUser) -> ChorusResult<()> {
+        self.respond(
+            user,
+            InteractionResponse {
+                response_type: InteractionResponseType::DeferredUpdateMessage,
+                data: None,
+            },
+        )
+        .await
+    }
+
+    /// Responds to a component interaction by editing the message the component is attached to.
+    ///
+    /// See [Interaction::respond].
+    pub async fn update_message(
+        &self,
+        user: &mut ChorusUser,
+        data: InteractionApplicationCommandCallbackData,
+    ) -> ChorusResult<()> {
+        self.respond(
+            user,
+            InteractionResponse {
+                response_type: InteractionResponseType::UpdateMessage,
+                data: Some(data),
+            },
+        )
+        .await
+    }
+
+    /// Responds to an autocomplete interaction with suggestions (max 25).
+    ///
+    /// See [Interaction::respond].
+    pub async fn autocomplete(
+        &self,
+        user: &mut ChorusUser,
+        choices: Vec<ApplicationCommandOptionChoice>,
+    ) -> ChorusResult<()> {
+        self.respond(
+            user,
+            InteractionResponse {
+                response_type: InteractionResponseType::ApplicationCommandAutocompleteResult,
+                data: Some(InteractionApplicationCommandCallbackData {
+                    choices: Some(choices),
+                    ..Default::default()
+                }),
+            },
+        )
+        .await
+    }
+
+    /// Returns a [WebhookClient] for the interaction's webhook, which edits the original response
+    /// and sends followup messages.
+    ///
+    /// The webhook can be used for 15 minutes after the interaction was received. It is sent with
+    /// the instance's client and [RetryPolicy](crate::ratelimiter::RetryPolicy).
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/receiving-and-responding#followup-messages>
+    pub fn followup(&self, user: &ChorusUser) -> WebhookClient {
+        let client = user.http_client();
+        let instance = user.belongs_to.read().unwrap();
+        WebhookClient::new(&instance.urls.api, self.application_id, &self.token)
+            .with_client(client)
+            .with_retry_policy(instance.retry_policy.clone())
+    }
+}
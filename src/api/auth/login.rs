@@ -25,7 +25,7 @@ impl Instance {
     pub async fn login_account(&mut self, login_schema: LoginSchema) -> ChorusResult<ChorusUser> {
         let endpoint_url = self.urls.api.clone() + "/auth/login";
         let chorus_request = ChorusRequest {
-            request: Client::new().post(endpoint_url).json(&login_schema),
+            request: self.client.post(endpoint_url).json(&login_schema),
             limit_type: LimitType::AuthLogin,
         }
         // Note: yes, this is still sent even for login and register
@@ -58,7 +58,7 @@ impl Instance {
         let endpoint_url = self.urls.api.clone() + "/auth/mfa/" + &authenticator.to_string();
 
         let chorus_request = ChorusRequest {
-            request: Client::new().post(endpoint_url).json(&schema),
+            request: self.client.post(endpoint_url).json(&schema),
             limit_type: LimitType::AuthLogin,
         }
         // Note: yes, this is still sent even for login and register
@@ -101,7 +101,8 @@ impl Instance {
     ) -> ChorusResult<SendMfaSmsResponse> {
         let endpoint_url = self.urls.api.clone() + "/auth/mfa/sms/send";
         let chorus_request = ChorusRequest {
-            request: Client::new()
+            request: self
+                .client
                 .post(endpoint_url)
                 .header("Content-Type", "application/json")
                 .json(&schema),
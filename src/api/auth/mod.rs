@@ -10,6 +10,9 @@ pub use login::*;
 #[allow(unused_imports)]
 pub use register::*;
 
+#[allow(unused_imports)]
+pub use session::*;
+
 use crate::gateway::Gateway;
 use crate::{
     errors::ChorusResult,
@@ -19,6 +22,7 @@ use crate::{
 
 pub mod login;
 pub mod register;
+pub mod session;
 
 impl Instance {
     /// Logs into an existing account on the spacebar server, using only a token.
@@ -28,7 +28,7 @@ impl Instance {
     ) -> ChorusResult<ChorusUser> {
         let endpoint_url = self.urls.api.clone() + "/auth/register";
         let chorus_request = ChorusRequest {
-            request: Client::new().post(endpoint_url).json(&register_schema),
+            request: self.client.post(endpoint_url).json(&register_schema),
             limit_type: LimitType::AuthRegister,
         }
         // Note: yes, this is still sent even for login and register
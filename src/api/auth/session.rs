@@ -0,0 +1,268 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Saving a user's session and restoring it later, without logging in again.
+
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{ChorusError, ChorusResult};
+use crate::gateway::{GatewayHandle, GatewaySessionState};
+use crate::instance::{ChorusUser, Instance};
+use crate::types::{ClientProperties, GatewayResume, Limit, MfaToken, User, UserSettings};
+use crate::UrlBundle;
+
+#[derive(Clone, Serialize, Deserialize)]
+/// The state of a [ChorusUser]'s session, which can be saved with [ChorusUser::snapshot] and
+/// restored with [Instance::restore_session].
+///
+/// The snapshot contains the user's token; store it like you would store a password, e. g.
+/// with [SessionSnapshot::encrypt]. The token and mfa token are left out of the snapshot's
+/// [Debug](std::fmt::Debug) output.
+pub struct SessionSnapshot {
+    /// The urls of the instance the user is logged in on
+    pub urls: UrlBundle,
+    /// The user's authentication token
+    pub token: String,
+    /// The telemetry data the user sent to the instance
+    pub client_properties: ClientProperties,
+    /// Whether the client properties are sent as headers in the HTTP API, see
+    /// [ClientProperties::send_telemetry_headers], which is not serialized with them
+    pub send_telemetry_headers: bool,
+    /// A token for bypassing mfa, if the user had one
+    pub mfa_token: Option<MfaToken>,
+    /// The user's ratelimits
+    pub limits: Option<Vec<Limit>>,
+    /// The user's settings at the time of the snapshot
+    pub settings: UserSettings,
+    /// Information about the user at the time of the snapshot
+    pub object: User,
+    /// The user's gateway session, if they were connected
+    pub gateway_session: Option<GatewaySessionState>,
+}
+
+impl std::fmt::Debug for SessionSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionSnapshot")
+            .field("urls", &self.urls)
+            .field("token", &"[redacted]")
+            .field("client_properties", &self.client_properties)
+            .field("send_telemetry_headers", &self.send_telemetry_headers)
+            .field("mfa_token", &self.mfa_token.as_ref().map(|_| "[redacted]"))
+            .field("limits", &self.limits)
+            .field("settings", &self.settings)
+            .field("object", &self.object)
+            .field("gateway_session", &self.gateway_session)
+            .finish()
+    }
+}
+
+impl SessionSnapshot {
+    /// Serializes the snapshot to json.
+    pub fn to_json(&self) -> ChorusResult<String> {
+        serde_json::to_string(self).map_err(|e| ChorusError::InvalidSessionSnapshot {
+            error: e.to_string(),
+        })
+    }
+
+    /// Deserializes a snapshot saved with [SessionSnapshot::to_json].
+    pub fn from_json(json: &str) -> ChorusResult<SessionSnapshot> {
+        serde_json::from_str(json).map_err(|e| ChorusError::InvalidSessionSnapshot {
+            error: e.to_string(),
+        })
+    }
+
+    /// Serializes and encrypts the snapshot with a 256 bit key, using XSalsa20-Poly1305.
+    ///
+    /// The returned bytes contain a random nonce followed by the encrypted snapshot, and can be
+    /// read with [SessionSnapshot::decrypt].
+    #[cfg(feature = "session_encryption")]
+    pub fn encrypt(&self, key: &[u8; 32]) -> ChorusResult<Vec<u8>> {
+        use crypto_secretbox::aead::{Aead, AeadCore};
+        use crypto_secretbox::{KeyInit, XSalsa20Poly1305};
+
+        let cipher = XSalsa20Poly1305::new(key.into());
+        let nonce = XSalsa20Poly1305::generate_nonce(&mut rand::thread_rng());
+
+        let encrypted = cipher
+            .encrypt(&nonce, self.to_json()?.as_bytes())
+            .map_err(|e| ChorusError::InvalidSessionSnapshot {
+                error: e.to_string(),
+            })?;
+
+        let mut data = nonce.to_vec();
+        data.extend(encrypted);
+        Ok(data)
+    }
+
+    /// Decrypts a snapshot saved with [SessionSnapshot::encrypt].
+    ///
+    /// Returns [ChorusError::InvalidSessionSnapshot] if the key is wrong or the data was
+    /// tampered with.
+    #[cfg(feature = "session_encryption")]
+    pub fn decrypt(data: &[u8], key: &[u8; 32]) -> ChorusResult<SessionSnapshot> {
+        use crypto_secretbox::aead::{Aead, AeadCore};
+        use crypto_secretbox::cipher::generic_array::typenum::Unsigned;
+        use crypto_secretbox::{KeyInit, Nonce, XSalsa20Poly1305};
+
+        let nonce_size = <XSalsa20Poly1305 as AeadCore>::NonceSize::USIZE;
+        if data.len() < nonce_size {
+            return Err(ChorusError::InvalidSessionSnapshot {
+                error: "The data is too short to contain a nonce".to_string(),
+            });
+        }
+        let (nonce, encrypted) = data.split_at(nonce_size);
+
+        let cipher = XSalsa20Poly1305::new(key.into());
+        let decrypted = cipher
+            .decrypt(Nonce::from_slice(nonce), encrypted)
+            .map_err(|_| ChorusError::InvalidSessionSnapshot {
+                error: "Decryption failed, the key may be wrong".to_string(),
+            })?;
+
+        let json =
+            String::from_utf8(decrypted).map_err(|e| ChorusError::InvalidSessionSnapshot {
+                error: e.to_string(),
+            })?;
+        SessionSnapshot::from_json(&json)
+    }
+}
+
+impl ChorusUser {
+    /// Saves the user's session, so it can be restored later with [Instance::restore_session].
+    ///
+    /// If the user is connected to the gateway, the gateway session is saved as well, so it can
+    /// be resumed.
+    pub async fn snapshot(&self) -> SessionSnapshot {
+        // The identify payload contains the token and is rebuilt when resuming anyway
+        let gateway_session = match &self.gateway {
+            Some(gateway) => Some(GatewaySessionState {
+                identify: None,
+                ..gateway.session_state().await
+            }),
+            None => None,
+        };
+
+        SessionSnapshot {
+            urls: self.belongs_to.read().unwrap().urls.clone(),
+            token: self.token.clone(),
+            client_properties: self.client_properties.clone(),
+            send_telemetry_headers: self.client_properties.send_telemetry_headers,
+            mfa_token: self.mfa_token.clone(),
+            limits: self
+                .limits
+                .as_ref()
+                .map(|limits| limits.values().copied().collect()),
+            settings: self.settings.read().unwrap().clone(),
+            object: self.object.read().unwrap().clone(),
+            gateway_session,
+        }
+    }
+
+    /// Connects the user to the gateway and resumes a previous gateway session, replaying the
+    /// events missed since then, e. g. one saved in a [SessionSnapshot].
+    ///
+    /// The connection is opened to the session's `resume_gateway_url` if it has one, and to the
+    /// instance's gateway otherwise.
+    ///
+    /// If the server can no longer resume the session, a new one is identified instead.
+    ///
+    /// If the user is already connected, the existing connection is returned.
+    pub async fn resume_gateway(
+        &mut self,
+        session: GatewaySessionState,
+    ) -> ChorusResult<&GatewayHandle> {
+        let (Some(session_id), Some(sequence_number)) =
+            (session.session_id.clone(), session.sequence_number)
+        else {
+            return Err(ChorusError::InvalidArguments {
+                error: "The gateway session has no session id or sequence number".to_string(),
+            });
+        };
+
+        if self.gateway.is_none() {
+            let gateway = self
+                .spawn_gateway(session.resume_gateway_url.as_deref())
+                .await?;
+
+            // Kept so a new session can be identified if the old one can't be resumed
+            *gateway.session.lock().await = GatewaySessionState {
+                identify: Some(self.identify_payload()),
+                ..session
+            };
+
+            gateway
+                .send_resume(GatewayResume {
+                    token: self.token.clone(),
+                    session_id,
+                    seq: sequence_number.to_string(),
+                })
+                .await;
+
+            self.gateway = Some(gateway);
+        }
+
+        self.gateway_handle()
+    }
+}
+
+impl Instance {
+    /// Restores a session saved with [ChorusUser::snapshot], without logging in again.
+    ///
+    /// The token is revalidated by fetching the user and their settings, so this fails with
+    /// [ChorusError::TokenExpired] if the user has logged out since. Unless the instance's
+    /// [GatewayConnectBehaviour](crate::instance::GatewayConnectBehaviour) is `Manual`, the user
+    /// then connects to the gateway, resuming the saved gateway session if there is one (see
+    /// [ChorusUser::resume_gateway]).
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the snapshot was saved on another instance.
+    pub async fn restore_session(&mut self, snapshot: SessionSnapshot) -> ChorusResult<ChorusUser> {
+        if snapshot.urls.api != self.urls.api {
+            return Err(ChorusError::InvalidArguments {
+                error: format!(
+                    "The session was saved on {}, not on {}",
+                    snapshot.urls.api, self.urls.api
+                ),
+            });
+        }
+
+        let mut user =
+            ChorusUser::shell(Arc::new(RwLock::new(self.clone())), &snapshot.token).await;
+
+        user.client_properties = ClientProperties {
+            send_telemetry_headers: snapshot.send_telemetry_headers,
+            ..snapshot.client_properties
+        };
+        user.mfa_token = snapshot.mfa_token.filter(MfaToken::is_valid);
+        if let Some(limits) = snapshot.limits {
+            user.limits = Some(
+                limits
+                    .into_iter()
+                    .map(|limit| (limit.bucket, limit))
+                    .collect(),
+            );
+        }
+
+        // Not done with Instance::login_with_token, since the restored client properties, mfa
+        // token and limits have to be used for the requests revalidating the token, and the
+        // saved gateway session is resumed below instead of identifying a new one
+        user.fetch_login_data(snapshot.token, None).await?;
+
+        if user.connects_on_login() {
+            match snapshot.gateway_session {
+                Some(session)
+                    if session.session_id.is_some() && session.sequence_number.is_some() =>
+                {
+                    user.resume_gateway(session).await?;
+                }
+                _ => {
+                    user.connect_gateway().await?;
+                }
+            }
+        }
+
+        Ok(user)
+    }
+}
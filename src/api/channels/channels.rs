@@ -22,7 +22,7 @@ impl Channel {
     /// See <https://discord-userdoccers.vercel.app/resources/channel#get-channel>
     pub async fn get(user: &mut ChorusUser, channel_id: Snowflake) -> ChorusResult<Channel> {
         let chorus_request = ChorusRequest {
-            request: Client::new().get(format!(
+            request: user.http_client().get(format!(
                 "{}/channels/{}",
                 user.belongs_to.read().unwrap().urls.api.clone(),
                 channel_id
@@ -53,7 +53,7 @@ impl Channel {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Channel(self.id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -90,7 +90,7 @@ impl Channel {
         );
 
         let request = ChorusRequest {
-            request: Client::new().patch(url).json(&modify_data),
+            request: user.http_client().patch(url).json(&modify_data),
             limit_type: LimitType::Channel(channel_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -120,7 +120,7 @@ impl Channel {
         );
 
         let mut chorus_request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: Default::default(),
         }
         .with_headers_for(user);
@@ -142,7 +142,7 @@ impl Channel {
         user: &mut ChorusUser,
         add_channel_recipient_schema: Option<AddChannelRecipientSchema>,
     ) -> ChorusResult<()> {
-        let mut request = Client::new().put(format!(
+        let mut request = user.http_client().put(format!(
             "{}/channels/{}/recipients/{}",
             user.belongs_to.read().unwrap().urls.api,
             self.id,
@@ -179,7 +179,7 @@ impl Channel {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Channel(self.id),
         }
         .with_headers_for(user);
@@ -204,7 +204,7 @@ impl Channel {
         );
 
         let request = ChorusRequest {
-            request: Client::new().patch(url).json(&schema),
+            request: user.http_client().patch(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -0,0 +1,102 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+
+use crate::{
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{
+        AddFollowingChannelSchema, Channel, FollowedChannel, LimitType, Snowflake, Webhook,
+        WebhookType,
+    },
+};
+
+impl Channel {
+    /// Follows an announcement channel, so that its crossposted messages (see
+    /// [Message::crosspost](crate::types::Message::crosspost)) are also sent to
+    /// `target_channel_id`.
+    ///
+    /// The messages are sent by a [WebhookType::ChannelFollower] webhook, which is created in the
+    /// target channel and returned as [FollowedChannel::webhook_id].
+    ///
+    /// Requires the [MANAGE_WEBHOOKS](crate::types::PermissionFlags::MANAGE_WEBHOOKS) permission
+    /// in the target channel.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#follow-channel>
+    pub async fn follow(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+        target_channel_id: Snowflake,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<FollowedChannel> {
+        let url = format!(
+            "{}/channels/{}/followers",
+            user.belongs_to.read().unwrap().urls.api,
+            channel_id
+        );
+
+        ChorusRequest {
+            request: user
+                .http_client()
+                .post(url)
+                .json(&AddFollowingChannelSchema {
+                    webhook_channel_id: target_channel_id,
+                }),
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<FollowedChannel>(user)
+        .await
+    }
+
+    /// Returns the [WebhookType::ChannelFollower] webhooks of a channel, i.e. the announcement
+    /// channels it follows.
+    ///
+    /// [Webhook::source_channel] and [Webhook::source_guild] say which channel each webhook
+    /// follows.
+    ///
+    /// Requires the [MANAGE_WEBHOOKS](crate::types::PermissionFlags::MANAGE_WEBHOOKS) permission.
+    pub async fn get_followed_channels(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+    ) -> ChorusResult<Vec<Webhook>> {
+        let webhooks = Webhook::get_channel_webhooks(user, channel_id).await?;
+        Ok(webhooks
+            .into_iter()
+            .filter(|webhook| webhook.webhook_type == WebhookType::ChannelFollower)
+            .collect())
+    }
+
+    /// Stops following an announcement channel, by deleting the webhooks in `target_channel_id`
+    /// which follow `channel_id`.
+    ///
+    /// Returns the number of deleted webhooks; `0` if the channel was not followed.
+    ///
+    /// Requires the [MANAGE_WEBHOOKS](crate::types::PermissionFlags::MANAGE_WEBHOOKS) permission
+    /// in the target channel.
+    pub async fn unfollow(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+        target_channel_id: Snowflake,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<usize> {
+        let followers = Channel::get_followed_channels(user, target_channel_id).await?;
+
+        let mut deleted = 0;
+        for webhook in followers.iter().filter(|webhook| {
+            webhook
+                .source_channel
+                .as_ref()
+                .is_some_and(|source| source.id == channel_id)
+        }) {
+            Webhook::delete(user, webhook.id, audit_log_reason.clone()).await?;
+            deleted += 1;
+        }
+        Ok(deleted)
+    }
+}
@@ -4,7 +4,7 @@
 
 use http::header::CONTENT_DISPOSITION;
 use http::HeaderMap;
-use reqwest::{multipart, Client};
+use reqwest::multipart;
 use serde_json::{from_value, to_string, Value};
 
 use crate::errors::{ChorusError, ChorusResult};
@@ -31,7 +31,8 @@ impl Message {
 
         if message.attachments.is_none() {
             let chorus_request = ChorusRequest {
-                request: Client::new()
+                request: user
+                    .http_client()
                     .post(format!("{}/channels/{}/messages", url_api, channel_id))
                     .json(&message),
                 limit_type: LimitType::Channel(channel_id),
@@ -68,7 +69,8 @@ impl Message {
             }
 
             let chorus_request = ChorusRequest {
-                request: Client::new()
+                request: user
+                    .http_client()
                     .post(format!("{}/channels/{}/messages", url_api, channel_id))
                     .multipart(form),
                 limit_type: LimitType::Channel(channel_id),
@@ -100,7 +102,8 @@ impl Message {
         };
         let request = ChorusRequest {
             limit_type,
-            request: Client::new()
+            request: user
+                .http_client()
                 .get(format!(
                     "{}/{}/messages/search",
                     &user.belongs_to.read().unwrap().urls.api,
@@ -144,7 +147,7 @@ impl Message {
         user: &mut ChorusUser,
     ) -> ChorusResult<Vec<Message>> {
         let request = ChorusRequest {
-            request: Client::new().get(format!(
+            request: user.http_client().get(format!(
                 "{}/channels/{}/pins",
                 user.belongs_to.read().unwrap().urls.api,
                 channel_id
@@ -168,7 +171,7 @@ impl Message {
         user: &mut ChorusUser,
     ) -> ChorusResult<()> {
         let request = ChorusRequest {
-            request: Client::new().put(format!(
+            request: user.http_client().put(format!(
                 "{}/channels/{}/pins/{}",
                 user.belongs_to.read().unwrap().urls.api,
                 channel_id,
@@ -192,7 +195,7 @@ impl Message {
         user: &mut ChorusUser,
     ) -> ChorusResult<()> {
         let request = ChorusRequest {
-            request: Client::new().delete(format!(
+            request: user.http_client().delete(format!(
                 "{}/channels/{}/pins/{}",
                 user.belongs_to.read().unwrap().urls.api,
                 channel_id,
@@ -216,7 +219,7 @@ impl Message {
         user: &mut ChorusUser,
     ) -> ChorusResult<Message> {
         let chorus_request = ChorusRequest {
-            request: Client::new().get(format!(
+            request: user.http_client().get(format!(
                 "{}/channels/{}/messages/{}",
                 user.belongs_to.read().unwrap().urls.api,
                 channel_id,
@@ -238,7 +241,8 @@ impl Message {
         user: &mut ChorusUser,
     ) -> ChorusResult<Message> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .post(format!(
                     "{}/channels/{}/messages/greet",
                     user.belongs_to.read().unwrap().urls.api,
@@ -269,7 +273,8 @@ impl Message {
         user: &mut ChorusUser,
     ) -> ChorusResult<Option<String>> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .post(format!(
                     "{}/channels/{}/messages/{}/ack",
                     user.belongs_to.read().unwrap().urls.api,
@@ -296,7 +301,7 @@ impl Message {
         user: &mut ChorusUser,
     ) -> ChorusResult<Message> {
         let request = ChorusRequest {
-            request: Client::new().post(format!(
+            request: user.http_client().post(format!(
                 "{}/channels/{}/messages/{}/crosspost",
                 user.belongs_to.read().unwrap().urls.api,
                 channel_id,
@@ -326,7 +331,7 @@ impl Message {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Channel(channel_id),
         }
         .with_headers_for(user);
@@ -358,7 +363,7 @@ impl Message {
         );
 
         let request = ChorusRequest {
-            request: Client::new().patch(url).json(&schema),
+            request: user.http_client().patch(url).json(&schema),
             limit_type: LimitType::Channel(channel_id),
         }
         .with_headers_for(user);
@@ -382,7 +387,7 @@ impl Message {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Channel(channel_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -413,7 +418,8 @@ impl Message {
         }
 
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .post(format!(
                     "{}/channels/{}/messages/bulk-delete",
                     user.belongs_to.read().unwrap().urls.api,
@@ -437,7 +443,7 @@ impl Message {
         user: &mut ChorusUser,
     ) -> ChorusResult<()> {
         let request = ChorusRequest {
-            request: Client::new().post(format!(
+            request: user.http_client().post(format!(
                 "{}/channels/{}/pins/ack",
                 user.belongs_to.read().unwrap().urls.api,
                 channel_id,
@@ -4,11 +4,19 @@
 
 #![allow(unused_imports)]
 pub use channels::*;
+pub use followers::*;
 pub use messages::*;
 pub use permissions::*;
 pub use reactions::*;
+pub use stage_instances::*;
+pub use stage_speakers::*;
+pub use threads::*;
 
 pub mod channels;
+pub mod followers;
 pub mod messages;
 pub mod permissions;
 pub mod reactions;
+pub mod stage_instances;
+pub mod stage_speakers;
+pub mod threads;
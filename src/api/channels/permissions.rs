@@ -37,7 +37,7 @@ impl types::Channel {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().put(url).json(&overwrite),
+            request: user.http_client().put(url).json(&overwrite),
             limit_type: LimitType::Channel(channel_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -67,7 +67,7 @@ impl types::Channel {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Channel(channel_id),
         }
         .with_headers_for(user);
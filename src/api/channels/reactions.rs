@@ -34,7 +34,7 @@ impl ReactionMeta {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Channel(self.channel_id),
         }
         .with_headers_for(user);
@@ -59,7 +59,7 @@ impl ReactionMeta {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Channel(self.channel_id),
         }
         .with_headers_for(user);
@@ -86,7 +86,7 @@ impl ReactionMeta {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Channel(self.channel_id),
         }
         .with_headers_for(user);
@@ -116,7 +116,7 @@ impl ReactionMeta {
         );
 
         let request = ChorusRequest {
-            request: Client::new().put(url),
+            request: user.http_client().put(url),
             limit_type: LimitType::Channel(self.channel_id),
         }
         .with_headers_for(user);
@@ -141,7 +141,7 @@ impl ReactionMeta {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Channel(self.channel_id),
         }
         .with_headers_for(user);
@@ -174,7 +174,7 @@ impl ReactionMeta {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Channel(self.channel_id),
         }
         .with_headers_for(user);
@@ -0,0 +1,129 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+
+use crate::{
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{
+        LimitType, Snowflake, StageInstance, StageInstanceCreateSchema, StageInstanceModifySchema,
+    },
+};
+
+impl StageInstance {
+    /// Starts a stage instance in a stage channel, making the current user a moderator of it.
+    ///
+    /// The schema is checked with [StageInstanceCreateSchema::validate] before it is sent.
+    ///
+    /// Requires the user to be a stage moderator, i.e. to have the
+    /// [MANAGE_CHANNELS](crate::types::PermissionFlags::MANAGE_CHANNELS),
+    /// [MUTE_MEMBERS](crate::types::PermissionFlags::MUTE_MEMBERS) and
+    /// [MOVE_MEMBERS](crate::types::PermissionFlags::MOVE_MEMBERS) permissions in the channel.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/stage-instance#create-stage-instance>
+    pub async fn create(
+        user: &mut ChorusUser,
+        schema: StageInstanceCreateSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<StageInstance> {
+        schema.validate()?;
+
+        let url = format!(
+            "{}/stage-instances",
+            user.belongs_to.read().unwrap().urls.api
+        );
+
+        ChorusRequest {
+            request: user.http_client().post(url).json(&schema),
+            limit_type: LimitType::Channel(schema.channel_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<StageInstance>(user)
+        .await
+    }
+
+    /// Returns the active stage instance of a stage channel.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/stage-instance#get-stage-instance>
+    pub async fn get(user: &mut ChorusUser, channel_id: Snowflake) -> ChorusResult<StageInstance> {
+        let url = format!(
+            "{}/stage-instances/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            channel_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<StageInstance>(user)
+        .await
+    }
+
+    /// Modifies the active stage instance of a stage channel, returning the updated stage
+    /// instance.
+    ///
+    /// The schema is checked with [StageInstanceModifySchema::validate] before it is sent.
+    ///
+    /// Requires the user to be a stage moderator, see [StageInstance::create].
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/stage-instance#modify-stage-instance>
+    pub async fn modify(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+        schema: StageInstanceModifySchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<StageInstance> {
+        schema.validate()?;
+
+        let url = format!(
+            "{}/stage-instances/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            channel_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().patch(url).json(&schema),
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<StageInstance>(user)
+        .await
+    }
+
+    /// Ends the active stage instance of a stage channel.
+    ///
+    /// Requires the user to be a stage moderator, see [StageInstance::create].
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/stage-instance#delete-stage-instance>
+    pub async fn delete(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/stage-instances/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            channel_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().delete(url),
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+}
@@ -0,0 +1,275 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Moderating the speakers of stage channels.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::warn;
+use pubserve::Subscriber;
+
+use crate::errors::{ChorusError, ChorusResult};
+use crate::instance::ChorusUser;
+use crate::types::{Snowflake, VoiceState, VoiceStateUpdate, VoiceStateUpdateSchema};
+
+/// A user in a stage channel who raised their hand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpeakerRequest {
+    pub guild_id: Snowflake,
+    pub channel_id: Snowflake,
+    pub user_id: Snowflake,
+    pub requested_at: DateTime<Utc>,
+}
+
+/// The open requests to speak in the stage channels of a guild, collected from voice states.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpeakerQueue {
+    guild_id: Snowflake,
+    channel_id: Option<Snowflake>,
+    requests: HashMap<Snowflake, SpeakerRequest>,
+}
+
+impl SpeakerQueue {
+    /// Creates an empty queue for the stage channels of a guild.
+    pub fn new(guild_id: Snowflake) -> SpeakerQueue {
+        SpeakerQueue {
+            guild_id,
+            channel_id: None,
+            requests: HashMap::new(),
+        }
+    }
+
+    /// Creates an empty queue for a single stage channel of a guild.
+    pub fn for_channel(guild_id: Snowflake, channel_id: Snowflake) -> SpeakerQueue {
+        SpeakerQueue {
+            channel_id: Some(channel_id),
+            ..SpeakerQueue::new(guild_id)
+        }
+    }
+
+    /// Updates the queue with a user's new voice state, returning their request if they just
+    /// raised their hand.
+    ///
+    /// A request is closed once the user lowers their hand, becomes a speaker or leaves the
+    /// channel. Voice states of other guilds and channels are ignored.
+    pub fn observe(&mut self, state: &VoiceState) -> Option<SpeakerRequest> {
+        if state.guild_id != Some(self.guild_id) {
+            return None;
+        }
+
+        let request = match (state.channel_id, state.request_to_speak_timestamp) {
+            (Some(channel_id), Some(requested_at))
+                if state.suppress && self.channel_id.map_or(true, |id| id == channel_id) =>
+            {
+                SpeakerRequest {
+                    guild_id: self.guild_id,
+                    channel_id,
+                    user_id: state.user_id,
+                    requested_at,
+                }
+            }
+            _ => {
+                self.requests.remove(&state.user_id);
+                return None;
+            }
+        };
+
+        match self.requests.insert(state.user_id, request) {
+            Some(previous) if previous == request => None,
+            _ => Some(request),
+        }
+    }
+
+    /// Returns the open requests, oldest first
+    pub fn pending(&self) -> Vec<SpeakerRequest> {
+        let mut requests: Vec<SpeakerRequest> = self.requests.values().copied().collect();
+        requests.sort_by_key(|request| (request.requested_at, request.user_id.0));
+        requests
+    }
+
+    /// Returns the open request of a user, if any
+    pub fn get(&self, user_id: Snowflake) -> Option<SpeakerRequest> {
+        self.requests.get(&user_id).copied()
+    }
+
+    /// Closes the request of a user without changing their voice state, returning it.
+    pub fn remove(&mut self, user_id: Snowflake) -> Option<SpeakerRequest> {
+        self.requests.remove(&user_id)
+    }
+}
+
+/// Decides whether a [SpeakerRequest] is approved automatically, see
+/// [StageSpeakerController::with_auto_approve]
+type SpeakerRequestFilter = Box<dyn Fn(&SpeakerRequest) -> bool + Send + Sync>;
+
+/// Lets a moderator approve the requests to speak in the stage channels of a guild.
+///
+/// Subscribe the controller to the gateway's
+/// [voice state updates](crate::gateway::events::Voice::state_update) to collect requests, then
+/// approve them with [StageSpeakerController::approve] or [StageSpeakerController::approve_next].
+/// With [StageSpeakerController::with_auto_approve], requests can also be approved as soon as
+/// they come in; the approval is sent in its own task, so that it doesn't hold up the handling
+/// of other events.
+///
+/// Approving requests requires the
+/// [MUTE_MEMBERS](crate::types::PermissionFlags::MUTE_MEMBERS) permission.
+pub struct StageSpeakerController {
+    user: ChorusUser,
+    queue: Arc<Mutex<SpeakerQueue>>,
+    auto_approve: Option<SpeakerRequestFilter>,
+}
+
+impl std::fmt::Debug for StageSpeakerController {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StageSpeakerController")
+            .field("queue", &self.queue)
+            .field("auto_approve", &self.auto_approve.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl StageSpeakerController {
+    /// Creates a controller which moderates the stage channels of a guild as `user`.
+    pub fn new(user: ChorusUser, guild_id: Snowflake) -> StageSpeakerController {
+        StageSpeakerController::with_queue(user, SpeakerQueue::new(guild_id))
+    }
+
+    /// Creates a controller which collects requests into `queue`, e.g. a
+    /// [SpeakerQueue::for_channel].
+    pub fn with_queue(user: ChorusUser, queue: SpeakerQueue) -> StageSpeakerController {
+        StageSpeakerController {
+            user,
+            queue: Arc::new(Mutex::new(queue)),
+            auto_approve: None,
+        }
+    }
+
+    /// Approves new requests for which `filter` returns true as soon as they are received.
+    pub fn with_auto_approve(
+        mut self,
+        filter: impl Fn(&SpeakerRequest) -> bool + Send + Sync + 'static,
+    ) -> StageSpeakerController {
+        self.auto_approve = Some(Box::new(filter));
+        self
+    }
+
+    /// Returns the open requests, oldest first
+    pub fn pending(&self) -> Vec<SpeakerRequest> {
+        self.queue.lock().unwrap().pending()
+    }
+
+    /// Closes the request of a user without inviting them to speak, returning it.
+    pub fn dismiss(&self, user_id: Snowflake) -> Option<SpeakerRequest> {
+        self.queue.lock().unwrap().remove(user_id)
+    }
+
+    /// Updates the open requests with a user's new voice state, see [SpeakerQueue::observe].
+    pub fn observe(&self, state: &VoiceState) -> Option<SpeakerRequest> {
+        self.queue.lock().unwrap().observe(state)
+    }
+
+    /// Invites the user of an open request to speak, by unsuppressing them.
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the user has no open request.
+    pub async fn approve(&self, user_id: Snowflake) -> ChorusResult<SpeakerRequest> {
+        StageSpeakerController::approve_in(self.user.clone(), &self.queue, user_id).await
+    }
+
+    /// Approves an open request of `queue` as `user`, see [StageSpeakerController::approve]
+    async fn approve_in(
+        mut user: ChorusUser,
+        queue: &Mutex<SpeakerQueue>,
+        user_id: Snowflake,
+    ) -> ChorusResult<SpeakerRequest> {
+        let request =
+            queue
+                .lock()
+                .unwrap()
+                .get(user_id)
+                .ok_or_else(|| ChorusError::InvalidArguments {
+                    error: format!("User {} has not requested to speak", user_id),
+                })?;
+
+        VoiceState::modify(
+            &mut user,
+            request.guild_id,
+            request.user_id,
+            VoiceStateUpdateSchema {
+                channel_id: Some(request.channel_id),
+                suppress: Some(false),
+                request_to_speak_timestamp: None,
+            },
+        )
+        .await?;
+
+        queue.lock().unwrap().remove(user_id);
+        Ok(request)
+    }
+
+    /// Approves the oldest open request, if there is one.
+    pub async fn approve_next(&self) -> ChorusResult<Option<SpeakerRequest>> {
+        let next = self.queue.lock().unwrap().pending().first().copied();
+        match next {
+            Some(request) => self.approve(request.user_id).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Moves a speaker back to the audience of a stage channel, by suppressing them.
+    pub async fn move_to_audience(
+        &self,
+        user_id: Snowflake,
+        channel_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let guild_id = self.queue.lock().unwrap().guild_id;
+        let mut user = self.user.clone();
+        VoiceState::modify(
+            &mut user,
+            guild_id,
+            user_id,
+            VoiceStateUpdateSchema {
+                channel_id: Some(channel_id),
+                suppress: Some(true),
+                request_to_speak_timestamp: None,
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Subscriber<VoiceStateUpdate> for StageSpeakerController {
+    async fn update(&self, event: &VoiceStateUpdate) {
+        let Some(request) = self.observe(&event.state) else {
+            return;
+        };
+
+        if !self
+            .auto_approve
+            .as_ref()
+            .is_some_and(|filter| filter(&request))
+        {
+            return;
+        }
+
+        let (user, queue) = (self.user.clone(), self.queue.clone());
+        let approve = async move {
+            if let Err(e) = StageSpeakerController::approve_in(user, &queue, request.user_id).await
+            {
+                warn!(
+                    "StageSpeakerController failed to approve request of user {}: {}",
+                    request.user_id, e
+                );
+            }
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        tokio::task::spawn(approve);
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(approve);
+    }
+}
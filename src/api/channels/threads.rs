@@ -0,0 +1,412 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use async_trait::async_trait;
+use reqwest::Client;
+
+use crate::{
+    api::{
+        webhooks::client::{take_files, with_payload},
+        PaginatedRequest, Paginator,
+    },
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{
+        ActiveThreads, ArchivedThreads, ArchivedThreadsType, Channel, ForumPostCreateSchema,
+        GetArchivedThreadsQuery, GetThreadMembersQuery, Guild, LimitType, Snowflake,
+        StartThreadFromMessageSchema, StartThreadSchema, ThreadMember,
+    },
+};
+
+impl Channel {
+    /// Starts a thread from an existing message, returning the new thread.
+    ///
+    /// Creates a [GuildNewsThread](crate::types::ChannelType::GuildNewsThread) in news channels
+    /// and a [GuildPublicThread](crate::types::ChannelType::GuildPublicThread) otherwise.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#create-thread-from-message>
+    pub async fn start_thread_from_message(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        schema: StartThreadFromMessageSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<Channel> {
+        let url = format!(
+            "{}/channels/{}/messages/{}/threads",
+            user.belongs_to.read().unwrap().urls.api,
+            channel_id,
+            message_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().post(url).json(&schema),
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<Channel>(user)
+        .await
+    }
+
+    /// Starts a thread which is not attached to a message, returning the new thread.
+    ///
+    /// To create a post in a forum or media channel, see [Channel::create_forum_post].
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#create-thread>
+    pub async fn start_thread(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+        schema: StartThreadSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<Channel> {
+        let url = format!(
+            "{}/channels/{}/threads",
+            user.belongs_to.read().unwrap().urls.api,
+            channel_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().post(url).json(&schema),
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<Channel>(user)
+        .await
+    }
+
+    /// Creates a post in a [GuildForum](crate::types::ChannelType::GuildForum) or
+    /// [GuildMedia](crate::types::ChannelType::GuildMedia) channel, returning the new thread.
+    ///
+    /// Attachments of the first message which have content are uploaded along with it.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#create-thread>
+    pub async fn create_forum_post(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+        mut schema: ForumPostCreateSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<Channel> {
+        let url = format!(
+            "{}/channels/{}/threads",
+            user.belongs_to.read().unwrap().urls.api,
+            channel_id
+        );
+
+        let files = take_files(&mut schema.message.attachments);
+        let request = with_payload(user.http_client().post(url), &schema, files)?;
+
+        ChorusRequest {
+            request,
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<Channel>(user)
+        .await
+    }
+
+    /// Returns a page of a channel's archived threads, newest first.
+    ///
+    /// To fetch more than one page, see [Channel::archived_threads_paginator].
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#get-public-archived-threads>
+    pub async fn get_archived_threads(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+        threads_type: ArchivedThreadsType,
+        query: GetArchivedThreadsQuery,
+    ) -> ChorusResult<ArchivedThreads> {
+        let path = match threads_type {
+            ArchivedThreadsType::Public => "threads/archived/public",
+            ArchivedThreadsType::Private => "threads/archived/private",
+            ArchivedThreadsType::JoinedPrivate => "users/@me/threads/archived/private",
+        };
+        let url = format!(
+            "{}/channels/{}/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            channel_id,
+            path
+        );
+
+        ChorusRequest {
+            request: user
+                .http_client()
+                .get(url)
+                .query(&query.to_query(threads_type)),
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<ArchivedThreads>(user)
+        .await
+    }
+
+    /// Returns an [ArchivedThreadsPaginator], which fetches a channel's archived threads page by
+    /// page, starting at [GetArchivedThreadsQuery::before] (or the most recently archived thread).
+    pub fn archived_threads_paginator(
+        user: &ChorusUser,
+        channel_id: Snowflake,
+        threads_type: ArchivedThreadsType,
+        query: GetArchivedThreadsQuery,
+    ) -> ArchivedThreadsPaginator {
+        Paginator::new(
+            user,
+            ArchivedThreadsRequest {
+                channel_id,
+                threads_type,
+                query,
+            },
+        )
+    }
+
+    /// Adds the current user to a thread.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#join-thread>
+    pub async fn join_thread(user: &mut ChorusUser, thread_id: Snowflake) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/thread-members/@me",
+            user.belongs_to.read().unwrap().urls.api,
+            thread_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().put(url),
+            limit_type: LimitType::Channel(thread_id),
+        }
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+
+    /// Removes the current user from a thread.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#leave-thread>
+    pub async fn leave_thread(user: &mut ChorusUser, thread_id: Snowflake) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/thread-members/@me",
+            user.belongs_to.read().unwrap().urls.api,
+            thread_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().delete(url),
+            limit_type: LimitType::Channel(thread_id),
+        }
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+
+    /// Adds another user to a thread.
+    ///
+    /// Requires the current user to be able to send messages in the thread.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#add-thread-member>
+    pub async fn add_thread_member(
+        user: &mut ChorusUser,
+        thread_id: Snowflake,
+        user_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/thread-members/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            thread_id,
+            user_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().put(url),
+            limit_type: LimitType::Channel(thread_id),
+        }
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+
+    /// Removes another user from a thread.
+    ///
+    /// Requires the [MANAGE_THREADS](crate::types::PermissionFlags::MANAGE_THREADS) permission,
+    /// unless the current user created the (private) thread.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#remove-thread-member>
+    pub async fn remove_thread_member(
+        user: &mut ChorusUser,
+        thread_id: Snowflake,
+        user_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/channels/{}/thread-members/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            thread_id,
+            user_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().delete(url),
+            limit_type: LimitType::Channel(thread_id),
+        }
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+
+    /// Returns a single member of a thread.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#get-thread-member>
+    pub async fn get_thread_member(
+        user: &mut ChorusUser,
+        thread_id: Snowflake,
+        user_id: Snowflake,
+        with_member: bool,
+    ) -> ChorusResult<ThreadMember> {
+        let url = format!(
+            "{}/channels/{}/thread-members/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            thread_id,
+            user_id
+        );
+
+        ChorusRequest {
+            request: user
+                .http_client()
+                .get(url)
+                .query(&[("with_member", with_member.to_string())]),
+            limit_type: LimitType::Channel(thread_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<ThreadMember>(user)
+        .await
+    }
+
+    /// Returns the members of a thread.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#get-thread-members>
+    pub async fn get_thread_members(
+        user: &mut ChorusUser,
+        thread_id: Snowflake,
+        query: GetThreadMembersQuery,
+    ) -> ChorusResult<Vec<ThreadMember>> {
+        let url = format!(
+            "{}/channels/{}/thread-members",
+            user.belongs_to.read().unwrap().urls.api,
+            thread_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url).query(&query.to_query()),
+            limit_type: LimitType::Channel(thread_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<ThreadMember>>(user)
+        .await
+    }
+}
+
+impl Guild {
+    /// Returns all active threads of a guild the current user can access, along with the
+    /// current user's thread members for them.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/channel#get-guild-active-threads>
+    pub async fn get_active_threads(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+    ) -> ChorusResult<ActiveThreads> {
+        let url = format!(
+            "{}/guilds/{}/threads/active",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<ActiveThreads>(user)
+        .await
+    }
+}
+
+/// Fetches a channel's archived threads page by page, see [Channel::archived_threads_paginator].
+///
+/// Use [Paginator::next_page] to get whole pages (including the current user's thread members),
+/// or [Paginator::next_item] and [Paginator::into_stream] to go through the threads one by one.
+///
+/// # Example
+/// ```no_run
+/// # async fn run(user: chorus::instance::ChorusUser, channel_id: chorus::types::Snowflake) -> chorus::errors::ChorusResult<()> {
+/// use chorus::types::{ArchivedThreadsType, Channel, GetArchivedThreadsQuery};
+///
+/// let mut paginator = Channel::archived_threads_paginator(
+///     &user,
+///     channel_id,
+///     ArchivedThreadsType::Public,
+///     GetArchivedThreadsQuery::default(),
+/// );
+///
+/// while let Some(page) = paginator.next_page().await? {
+///     for thread in page.threads {
+///         println!("{:?}", thread.name);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub type ArchivedThreadsPaginator = Paginator<ArchivedThreadsRequest>;
+
+/// A request for a page of a channel's archived threads, see [ArchivedThreadsPaginator].
+#[derive(Debug, Clone, Copy)]
+pub struct ArchivedThreadsRequest {
+    channel_id: Snowflake,
+    threads_type: ArchivedThreadsType,
+    /// The query for the next page
+    query: GetArchivedThreadsQuery,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PaginatedRequest for ArchivedThreadsRequest {
+    type Page = ArchivedThreads;
+    type Item = Channel;
+
+    async fn fetch_page(&self, user: &mut ChorusUser) -> ChorusResult<ArchivedThreads> {
+        Channel::get_archived_threads(user, self.channel_id, self.threads_type, self.query).await
+    }
+
+    fn advance(&mut self, page: &ArchivedThreads) -> bool {
+        // Threads are sorted by when they were archived, or by id for joined private threads
+        let last = page.threads.last();
+        self.query.before = last
+            .and_then(|thread| thread.thread_metadata.as_ref())
+            .map(|metadata| metadata.archive_timestamp);
+        self.query.before_id = last.map(|thread| thread.id);
+
+        let next = match self.threads_type {
+            ArchivedThreadsType::JoinedPrivate => self.query.before_id.is_some(),
+            _ => self.query.before.is_some(),
+        };
+        page.has_more && next
+    }
+
+    fn items(page: &ArchivedThreads) -> &[Channel] {
+        &page.threads
+    }
+
+    fn into_items(page: ArchivedThreads) -> Vec<Channel> {
+        page.threads
+    }
+}
@@ -0,0 +1,145 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use async_trait::async_trait;
+use reqwest::Client;
+
+use crate::{
+    api::{CursorDirection, PaginatedRequest, Paginator},
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{AuditLogEntry, AuditLogObject, GetAuditLogsQuery, Guild, LimitType, Snowflake},
+};
+
+/// How many entries the server returns per page if no limit is set
+const DEFAULT_AUDIT_LOG_LIMIT: usize = 50;
+
+impl Guild {
+    /// Returns a page of the guild's audit log.
+    ///
+    /// Entries are sorted newest first, unless only [GetAuditLogsQuery::after] is set, in which
+    /// case they are sorted oldest first.
+    ///
+    /// Requires the [VIEW_AUDIT_LOG](crate::types::PermissionFlags::VIEW_AUDIT_LOG) permission.
+    ///
+    /// To fetch more than one page, see [Guild::audit_log_paginator].
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/audit-log#get-guild-audit-log>
+    pub async fn get_audit_logs(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        query: GetAuditLogsQuery,
+    ) -> ChorusResult<AuditLogObject> {
+        let url = format!(
+            "{}/guilds/{}/audit-logs",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url).query(&query.to_query()),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<AuditLogObject>(user)
+        .await
+    }
+
+    /// Returns an [AuditLogPaginator], which fetches the guild's audit log page by page.
+    ///
+    /// The paginator starts at [GetAuditLogsQuery::before] (or the newest entry) and walks
+    /// backwards in time. If only [GetAuditLogsQuery::after] is set, it walks forwards from
+    /// there instead. The user and action type filters apply to every page.
+    pub fn audit_log_paginator(
+        user: &ChorusUser,
+        guild_id: Snowflake,
+        query: GetAuditLogsQuery,
+    ) -> AuditLogPaginator {
+        let direction = match query.after.is_some() && query.before.is_none() {
+            true => CursorDirection::After,
+            false => CursorDirection::Before,
+        };
+
+        Paginator::new(
+            user,
+            AuditLogRequest {
+                guild_id,
+                query,
+                direction,
+            },
+        )
+    }
+}
+
+/// Fetches a guild's audit log page by page, see [Guild::audit_log_paginator].
+///
+/// Use [Paginator::next_page] to get whole pages (including the users, webhooks, etc. the
+/// entries refer to), or [Paginator::next_item] and [Paginator::into_stream] to go through the
+/// entries one by one.
+///
+/// # Example
+/// ```no_run
+/// # async fn run(user: chorus::instance::ChorusUser, guild_id: chorus::types::Snowflake) -> chorus::errors::ChorusResult<()> {
+/// use chorus::types::{GetAuditLogsQuery, Guild};
+///
+/// let mut paginator = Guild::audit_log_paginator(&user, guild_id, GetAuditLogsQuery::default());
+///
+/// while let Some(entry) = paginator.next_item().await? {
+///     for change in entry.decoded_changes() {
+///         println!("{:?} changed {}", entry.user_id, change.key);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub type AuditLogPaginator = Paginator<AuditLogRequest>;
+
+/// A request for a page of a guild's audit log, see [AuditLogPaginator].
+#[derive(Debug, Clone, Copy)]
+pub struct AuditLogRequest {
+    guild_id: Snowflake,
+    /// The query for the next page
+    query: GetAuditLogsQuery,
+    /// Which way we walk through the entries
+    direction: CursorDirection,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PaginatedRequest for AuditLogRequest {
+    type Page = AuditLogObject;
+    type Item = AuditLogEntry;
+
+    async fn fetch_page(&self, user: &mut ChorusUser) -> ChorusResult<AuditLogObject> {
+        Guild::get_audit_logs(user, self.guild_id, self.query).await
+    }
+
+    fn advance(&mut self, page: &AuditLogObject) -> bool {
+        let limit = self
+            .query
+            .limit
+            .map(usize::from)
+            .unwrap_or(DEFAULT_AUDIT_LOG_LIMIT);
+        let ids = page.audit_log_entries.iter().map(|entry| entry.id);
+
+        let Some(next) = self.direction.next_cursor(ids, limit) else {
+            return false;
+        };
+        match self.direction {
+            CursorDirection::Before => self.query.before = Some(next),
+            CursorDirection::After => self.query.after = Some(next),
+        }
+        true
+    }
+
+    fn items(page: &AuditLogObject) -> &[AuditLogEntry] {
+        &page.audit_log_entries
+    }
+
+    fn into_items(page: AuditLogObject) -> Vec<AuditLogEntry> {
+        page.audit_log_entries
+    }
+}
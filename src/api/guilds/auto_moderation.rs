@@ -0,0 +1,165 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+
+use crate::{
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{
+        AutoModerationRule, AutoModerationRuleCreateSchema, AutoModerationRuleModifySchema, Guild,
+        LimitType, Snowflake,
+    },
+};
+
+impl Guild {
+    /// Returns the auto moderation rules of a guild.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/auto-moderation#list-auto-moderation-rules>
+    pub async fn get_auto_moderation_rules(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+    ) -> ChorusResult<Vec<AutoModerationRule>> {
+        let url = format!(
+            "{}/guilds/{}/auto-moderation/rules",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<AutoModerationRule>>(user)
+        .await
+    }
+
+    /// Returns a single auto moderation rule of a guild.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/auto-moderation#get-auto-moderation-rule>
+    pub async fn get_auto_moderation_rule(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        rule_id: Snowflake,
+    ) -> ChorusResult<AutoModerationRule> {
+        let url = format!(
+            "{}/guilds/{}/auto-moderation/rules/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            rule_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<AutoModerationRule>(user)
+        .await
+    }
+
+    /// Creates an auto moderation rule in a guild.
+    ///
+    /// The schema is checked with [AutoModerationRuleCreateSchema::validate] before it is sent,
+    /// so mismatched trigger and action metadata is reported without a request being made.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/auto-moderation#create-auto-moderation-rule>
+    pub async fn create_auto_moderation_rule(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        schema: AutoModerationRuleCreateSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<AutoModerationRule> {
+        schema.validate()?;
+
+        let url = format!(
+            "{}/guilds/{}/auto-moderation/rules",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().post(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<AutoModerationRule>(user)
+        .await
+    }
+
+    /// Modifies an auto moderation rule of a guild, returning the updated rule.
+    ///
+    /// The schema is checked with [AutoModerationRuleModifySchema::validate] before it is sent.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/auto-moderation#modify-auto-moderation-rule>
+    pub async fn modify_auto_moderation_rule(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        rule_id: Snowflake,
+        schema: AutoModerationRuleModifySchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<AutoModerationRule> {
+        schema.validate()?;
+
+        let url = format!(
+            "{}/guilds/{}/auto-moderation/rules/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            rule_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().patch(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<AutoModerationRule>(user)
+        .await
+    }
+
+    /// Deletes an auto moderation rule from a guild.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/auto-moderation#delete-auto-moderation-rule>
+    pub async fn delete_auto_moderation_rule(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        rule_id: Snowflake,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/auto-moderation/rules/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            rule_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().delete(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+}
@@ -0,0 +1,155 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+
+use crate::{
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{Emoji, EmojiCreateSchema, EmojiModifySchema, LimitType, Snowflake},
+};
+
+impl Emoji {
+    /// Returns the emojis of a guild.
+    ///
+    /// Includes the user who uploaded each emoji if the current user has the
+    /// [MANAGE_GUILD_EXPRESSIONS](crate::types::PermissionFlags::MANAGE_GUILD_EXPRESSIONS)
+    /// permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/emoji#list-guild-emojis>
+    pub async fn get_all(user: &mut ChorusUser, guild_id: Snowflake) -> ChorusResult<Vec<Emoji>> {
+        let url = format!(
+            "{}/guilds/{}/emojis",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<Emoji>>(user)
+        .await
+    }
+
+    /// Returns a single emoji of a guild.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/emoji#get-guild-emoji>
+    pub async fn get(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        emoji_id: Snowflake,
+    ) -> ChorusResult<Emoji> {
+        let url = format!(
+            "{}/guilds/{}/emojis/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            emoji_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Emoji>(user)
+        .await
+    }
+
+    /// Creates a new emoji in a guild.
+    ///
+    /// Requires the [MANAGE_GUILD_EXPRESSIONS](crate::types::PermissionFlags::MANAGE_GUILD_EXPRESSIONS)
+    /// permission.
+    ///
+    /// See [EmojiCreateSchema::from_image] to create the schema from an image's bytes.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/emoji#create-guild-emoji>
+    pub async fn create(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        schema: EmojiCreateSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<Emoji> {
+        let url = format!(
+            "{}/guilds/{}/emojis",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().post(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<Emoji>(user)
+        .await
+    }
+
+    /// Modifies an emoji of a guild, returning the updated emoji.
+    ///
+    /// Requires the [MANAGE_GUILD_EXPRESSIONS](crate::types::PermissionFlags::MANAGE_GUILD_EXPRESSIONS)
+    /// permission, unless the current user created the emoji.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/emoji#modify-guild-emoji>
+    pub async fn modify(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        emoji_id: Snowflake,
+        schema: EmojiModifySchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<Emoji> {
+        let url = format!(
+            "{}/guilds/{}/emojis/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            emoji_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().patch(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<Emoji>(user)
+        .await
+    }
+
+    /// Deletes an emoji from a guild.
+    ///
+    /// Requires the [MANAGE_GUILD_EXPRESSIONS](crate::types::PermissionFlags::MANAGE_GUILD_EXPRESSIONS)
+    /// permission, unless the current user created the emoji.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/emoji#delete-guild-emoji>
+    pub async fn delete(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        emoji_id: Snowflake,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/emojis/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            emoji_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().delete(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+}
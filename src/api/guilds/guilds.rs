@@ -74,7 +74,7 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<Guild> {
         let mut chorus_request = ChorusRequest {
-            request: Client::new().get(format!(
+            request: user.http_client().get(format!(
                 "{}/guilds/{}",
                 user.belongs_to.read().unwrap().urls.api,
                 guild_id
@@ -106,7 +106,10 @@ impl Guild {
     ) -> ChorusResult<Guild> {
         let url = format!("{}/guilds", user.belongs_to.read().unwrap().urls.api);
         let chorus_request = ChorusRequest {
-            request: Client::new().post(url.clone()).json(&guild_create_schema),
+            request: user
+                .http_client()
+                .post(url.clone())
+                .json(&guild_create_schema),
             limit_type: LimitType::Global,
         }
         .with_headers_for(user);
@@ -133,7 +136,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<Guild> {
         let chorus_request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .patch(format!(
                     "{}/guilds/{}",
                     user.belongs_to.read().unwrap().urls.api,
@@ -170,7 +174,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<()> {
         let chorus_request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .post(format!(
                     "{}/guilds/{}/mfa",
                     user.belongs_to.read().unwrap().urls.api,
@@ -204,7 +209,7 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<()> {
         let chorus_request = ChorusRequest {
-            request: Client::new().post(format!(
+            request: user.http_client().post(format!(
                 "{}/guilds/{}/pincode",
                 user.belongs_to.read().unwrap().urls.api,
                 guild_id
@@ -253,7 +258,7 @@ impl Guild {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().post(url.clone()),
+            request: user.http_client().post(url.clone()),
             limit_type: LimitType::Global,
         }
         .with_maybe_mfa(&user.mfa_token)
@@ -288,7 +293,7 @@ impl Guild {
     /// See <https://discord-userdoccers.vercel.app/resources/channel#get-guild-channels>
     pub async fn channels(&self, user: &mut ChorusUser) -> ChorusResult<Vec<Channel>> {
         let chorus_request = ChorusRequest {
-            request: Client::new().get(format!(
+            request: user.http_client().get(format!(
                 "{}/guilds/{}/channels",
                 user.belongs_to.read().unwrap().urls.api,
                 self.id
@@ -330,7 +335,7 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<GuildPreview> {
         let chorus_request = ChorusRequest {
-            request: Client::new().get(format!(
+            request: user.http_client().get(format!(
                 "{}/guilds/{}/preview",
                 user.belongs_to.read().unwrap().urls.api,
                 guild_id,
@@ -360,7 +365,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<Vec<GuildMemberUnusualDMActivity>> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .get(format!(
                     "{}/guilds/{}/members/unusual-dm-activity",
                     user.belongs_to.read().unwrap().urls.api,
@@ -388,7 +394,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<Vec<GuildMember>> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .get(format!(
                     "{}/guilds/{}/members",
                     user.belongs_to.read().unwrap().urls.api,
@@ -419,7 +426,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<Vec<GuildMember>> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .get(format!(
                     "{}/guilds/{}/members/search",
                     user.belongs_to.read().unwrap().urls.api,
@@ -457,7 +465,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<SearchGuildMembersReturn> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .post(format!(
                     "{}/guilds/{}/members-search",
                     user.belongs_to.read().unwrap().urls.api,
@@ -537,7 +546,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<Vec<SupplementalGuildMember>> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .post(format!(
                     "{}/guilds/{}/members/supplemental",
                     user.belongs_to.read().unwrap().urls.api,
@@ -569,7 +579,7 @@ impl Guild {
         );
 
         let mut request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -602,7 +612,7 @@ impl Guild {
         );
 
         let mut request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -631,7 +641,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -654,7 +664,8 @@ impl Guild {
     ) -> ChorusResult<()> {
         // FIXME: Return GuildBan instead of (). Requires <https://github.com/spacebarchat/server/issues/1096> to be resolved.
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .put(format!(
                     "{}/guilds/{}/bans/{}",
                     user.belongs_to.read().unwrap().urls.api,
@@ -686,7 +697,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<BulkGuildBanReturn> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .post(format!(
                     "{}/guilds/{}/bulk-ban",
                     user.belongs_to.read().unwrap().urls.api,
@@ -722,7 +734,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -756,7 +768,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url).query(&schema.to_query()),
+            request: user.http_client().get(url).query(&schema.to_query()),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -793,7 +805,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().post(url).json(&schema),
+            request: user.http_client().post(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -819,7 +831,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -848,7 +860,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().patch(url).json(&schema),
+            request: user.http_client().patch(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -872,7 +884,7 @@ impl Guild {
     pub async fn get_widget(instance: &Instance, guild_id: Snowflake) -> ChorusResult<GuildWidget> {
         let url = format!("{}/guilds/{}/widget.json", instance.urls.api, guild_id,);
 
-        let client = Client::new().get(url.clone());
+        let client = instance.client.get(url.clone());
 
         let response = match client.send().await {
             Ok(result) => result,
@@ -925,7 +937,7 @@ impl Guild {
     ) -> ChorusResult<Bytes> {
         let url = format!("{}/guilds/{}/widget.png", instance.urls.api, guild_id,);
 
-        let mut client = Client::new().get(url.clone());
+        let mut client = instance.client.get(url.clone());
 
         if let Some(style_some) = style {
             match serde_json::to_string(&style_some) {
@@ -991,7 +1003,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1025,7 +1037,8 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .patch(url)
                 .json(&GuildModifyVanityInviteSchema { code }),
             limit_type: LimitType::Guild(guild_id),
@@ -1061,7 +1074,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url).query(&query.to_query()),
+            request: user.http_client().get(url).query(&query.to_query()),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1090,7 +1103,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().patch(url).json(&schema),
+            request: user.http_client().patch(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -1122,7 +1135,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url).query(&query.to_query()),
+            request: user.http_client().get(url).query(&query.to_query()),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1151,7 +1164,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Global,
         }
         .with_headers_for(user);
@@ -1178,7 +1191,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().put(url),
+            request: user.http_client().put(url),
             limit_type: LimitType::Global,
         }
         .with_headers_for(user);
@@ -1205,7 +1218,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().put(url).json(&schema),
+            request: user.http_client().put(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1231,7 +1244,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().post(url),
+            request: user.http_client().post(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1257,7 +1270,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().post(url),
+            request: user.http_client().post(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1288,7 +1301,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(&url),
+            request: user.http_client().delete(&url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1362,7 +1375,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().post(url),
+            request: user.http_client().post(url),
             limit_type: LimitType::Global,
         }
         .with_headers_for(user);
@@ -1395,7 +1408,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().patch(url).json(&schema),
+            request: user.http_client().patch(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1428,7 +1441,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().patch(url).json(&schema),
+            request: user.http_client().patch(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1459,7 +1472,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().patch(url).json(&schema),
+            request: user.http_client().patch(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1485,7 +1498,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1512,7 +1525,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().patch(url).json(&schema),
+            request: user.http_client().patch(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -1541,7 +1554,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1578,7 +1591,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().put(url).json(&schema),
+            request: user.http_client().put(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -1609,7 +1622,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1641,7 +1654,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().post(url),
+            request: user.http_client().post(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1670,7 +1683,7 @@ impl Guild {
         );
 
         let request = ChorusRequest {
-            request: Client::new().post(url),
+            request: user.http_client().post(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -1693,7 +1706,8 @@ impl Channel {
         schema: ChannelCreateSchema,
     ) -> ChorusResult<Channel> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .post(format!(
                     "{}/guilds/{}/channels",
                     user.belongs_to.read().unwrap().urls.api,
@@ -0,0 +1,107 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+
+use crate::{
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{Integration, LimitType, Snowflake},
+};
+
+impl Integration {
+    /// Returns the integrations of a guild.
+    ///
+    /// If `include_applications` is set, the guild's bot and OAuth2 application integrations are
+    /// included as well.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/integration#get-guild-integrations>
+    pub async fn get_all(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        include_applications: bool,
+    ) -> ChorusResult<Vec<Integration>> {
+        let url = format!(
+            "{}/guilds/{}/integrations",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user
+                .http_client()
+                .get(url)
+                .query(&[("include_applications", include_applications.to_string())]),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<Integration>>(user)
+        .await
+    }
+
+    /// Removes an integration from a guild, along with its webhooks; for bot integrations, the
+    /// bot is kicked.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/integration#delete-guild-integration>
+    pub async fn delete(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        integration_id: Snowflake,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/integrations/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            integration_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().delete(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+
+    /// Makes the server sync a Twitch or YouTube integration with its account, updating the
+    /// subscriber roles.
+    ///
+    /// The sync happens in the background; its result arrives as an
+    /// [IntegrationUpdate](crate::types::IntegrationUpdate).
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/integration#sync-guild-integration>
+    pub async fn sync(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        integration_id: Snowflake,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/integrations/{}/sync",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            integration_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().post(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+}
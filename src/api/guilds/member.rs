@@ -11,7 +11,9 @@ use crate::{
     instance::ChorusUser,
     ratelimiter::ChorusRequest,
     types::{
-        self, AddGuildMemberReturn, AddGuildMemberSchema, AddRoleMembersSchema, Guild, GuildMember, LimitType, ModifyCurrentGuildMemberSchema, ModifyGuildMemberProfileSchema, ModifyGuildMemberSchema, Snowflake, UserProfileMetadata
+        self, AddGuildMemberReturn, AddGuildMemberSchema, AddRoleMembersSchema, Guild, GuildMember,
+        LimitType, ModifyCurrentGuildMemberSchema, ModifyGuildMemberProfileSchema,
+        ModifyGuildMemberSchema, Snowflake, UserProfileMetadata,
     },
 };
 
@@ -33,7 +35,7 @@ impl Guild {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -59,7 +61,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<AddGuildMemberReturn> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .put(format!(
                     "{}/guilds/{}/members/{}",
                     user.belongs_to.read().unwrap().urls.api,
@@ -122,7 +125,7 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<()> {
         let request = ChorusRequest {
-            request: Client::new().delete(format!(
+            request: user.http_client().delete(format!(
                 "{}/guilds/{}/members/{}",
                 user.belongs_to.read().unwrap().urls.api,
                 guild_id,
@@ -150,7 +153,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<GuildMember> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .patch(format!(
                     "{}/guilds/{}/members/{}",
                     user.belongs_to.read().unwrap().urls.api,
@@ -177,7 +181,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<GuildMember> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .patch(format!(
                     "{}/guilds/{}/members/@me",
                     user.belongs_to.read().unwrap().urls.api,
@@ -202,7 +207,8 @@ impl Guild {
         user: &mut ChorusUser,
     ) -> ChorusResult<UserProfileMetadata> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: user
+                .http_client()
                 .patch(format!(
                     "{}/guilds/{}/profile/@me",
                     user.belongs_to.read().unwrap().urls.api,
@@ -240,7 +246,7 @@ impl Guild {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().put(url),
+            request: user.http_client().put(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -271,7 +277,7 @@ impl Guild {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -308,20 +314,20 @@ impl Guild {
     pub async fn get_role_members(
         user: &mut ChorusUser,
         guild_id: Snowflake,
-		  role_id: Snowflake
+        role_id: Snowflake,
     ) -> ChorusResult<Vec<Snowflake>> {
         crate::types::RoleObject::get_members(user, guild_id, role_id).await
     }
 
-	 /// Adds multiple guild members to a role.
+    /// Adds multiple guild members to a role.
     ///
     /// Requires the [MANAGE_ROLES](crate::types::PermissionFlags::MANAGE_ROLES) permission.
     ///
     /// Returns a mapping of member IDs to guild member objects.
-	 ///
-	 /// # Notes
-	 /// This method is wrapper around
-	 /// [RoleObject::add_members](crate::types::RoleObject::add_members)
+    ///
+    /// # Notes
+    /// This method is wrapper around
+    /// [RoleObject::add_members](crate::types::RoleObject::add_members)
     ///
     /// # Reference
     /// See <https://docs.discord.sex/resources/guild#add-guild-role-members>
@@ -332,8 +338,9 @@ impl Guild {
         role_id: Snowflake,
         schema: AddRoleMembersSchema,
     ) -> ChorusResult<HashMap<Snowflake, GuildMember>> {
-		crate::types::RoleObject::add_members(user, audit_log_reason, guild_id, role_id, schema).await
-	 }
+        crate::types::RoleObject::add_members(user, audit_log_reason, guild_id, role_id, schema)
+            .await
+    }
 }
 
 impl types::GuildMember {
@@ -3,12 +3,28 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 #![allow(unused_imports)]
+pub use audit_logs::*;
+pub use auto_moderation::*;
+pub use emojis::*;
 pub use guilds::*;
+pub use integrations::*;
 pub use messages::*;
 pub use roles::*;
 pub use roles::*;
+pub use scheduled_events::*;
+pub use stickers::*;
+pub use templates::*;
+pub use voice_states::*;
 
+pub mod audit_logs;
+pub mod auto_moderation;
+pub mod emojis;
 pub mod guilds;
+pub mod integrations;
 pub mod member;
 pub mod messages;
 pub mod roles;
+pub mod scheduled_events;
+pub mod stickers;
+pub mod templates;
+pub mod voice_states;
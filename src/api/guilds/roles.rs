@@ -33,7 +33,7 @@ impl types::RoleObject {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -60,7 +60,7 @@ impl types::RoleObject {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -85,7 +85,7 @@ impl types::RoleObject {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -114,7 +114,7 @@ impl types::RoleObject {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().get(url),
+            request: user.http_client().get(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -195,7 +195,7 @@ impl types::RoleObject {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().patch(url).json(&schema),
+            request: user.http_client().patch(url).json(&schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
@@ -224,7 +224,7 @@ impl types::RoleObject {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().post(url).json(&role_create_schema),
+            request: user.http_client().post(url).json(&role_create_schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -252,7 +252,10 @@ impl types::RoleObject {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().patch(url).json(&role_position_update_schema),
+            request: user
+                .http_client()
+                .patch(url)
+                .json(&role_position_update_schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -282,7 +285,7 @@ impl types::RoleObject {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().patch(url).json(&role_create_schema),
+            request: user.http_client().patch(url).json(&role_create_schema),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_headers_for(user);
@@ -310,7 +313,7 @@ impl types::RoleObject {
         );
 
         let request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: user.http_client().delete(url),
             limit_type: LimitType::Guild(guild_id),
         }
         .with_maybe_audit_log_reason(audit_log_reason)
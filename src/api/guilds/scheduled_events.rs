@@ -0,0 +1,333 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use async_trait::async_trait;
+use reqwest::Client;
+
+use crate::{
+    api::{CursorDirection, PaginatedRequest, Paginator},
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{
+        GetGuildScheduledEventUsersQuery, GuildScheduledEvent, GuildScheduledEventCreateSchema,
+        GuildScheduledEventModifySchema, GuildScheduledEventStatus, GuildScheduledEventUser,
+        LimitType, Snowflake,
+    },
+};
+
+/// How many users the server returns per page if no limit is set
+const DEFAULT_SCHEDULED_EVENT_USERS_LIMIT: usize = 100;
+
+impl GuildScheduledEvent {
+    /// Returns the scheduled events of a guild.
+    ///
+    /// If `with_user_count` is set, [GuildScheduledEvent::user_count] is included.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-scheduled-event#list-guild-scheduled-events>
+    pub async fn get_all(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        with_user_count: bool,
+    ) -> ChorusResult<Vec<GuildScheduledEvent>> {
+        let url = format!(
+            "{}/guilds/{}/scheduled-events",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user
+                .http_client()
+                .get(url)
+                .query(&[("with_user_count", with_user_count.to_string())]),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<GuildScheduledEvent>>(user)
+        .await
+    }
+
+    /// Returns a single scheduled event of a guild.
+    ///
+    /// If `with_user_count` is set, [GuildScheduledEvent::user_count] is included.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-scheduled-event#get-guild-scheduled-event>
+    pub async fn get(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        event_id: Snowflake,
+        with_user_count: bool,
+    ) -> ChorusResult<GuildScheduledEvent> {
+        let url = format!(
+            "{}/guilds/{}/scheduled-events/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            event_id
+        );
+
+        ChorusRequest {
+            request: user
+                .http_client()
+                .get(url)
+                .query(&[("with_user_count", with_user_count.to_string())]),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<GuildScheduledEvent>(user)
+        .await
+    }
+
+    /// Creates a scheduled event in a guild.
+    ///
+    /// The schema is checked with [GuildScheduledEventCreateSchema::validate] before it is sent.
+    ///
+    /// Requires the [CREATE_EVENTS](crate::types::PermissionFlags::CREATE_EVENTS) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-scheduled-event#create-guild-scheduled-event>
+    pub async fn create(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        schema: GuildScheduledEventCreateSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<GuildScheduledEvent> {
+        schema.validate()?;
+
+        let url = format!(
+            "{}/guilds/{}/scheduled-events",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().post(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<GuildScheduledEvent>(user)
+        .await
+    }
+
+    /// Modifies a scheduled event of a guild, returning the updated event.
+    ///
+    /// If [GuildScheduledEventModifySchema::status] is set, the event is fetched first to check
+    /// that its current status can be changed to the new one; see
+    /// [GuildScheduledEventStatus::can_transition_to].
+    /// To avoid that, use [GuildScheduledEvent::modify_with_status] if the status is known.
+    ///
+    /// Requires the [MANAGE_EVENTS](crate::types::PermissionFlags::MANAGE_EVENTS) permission,
+    /// unless the current user created the event.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-scheduled-event#modify-guild-scheduled-event>
+    pub async fn modify(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        event_id: Snowflake,
+        schema: GuildScheduledEventModifySchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<GuildScheduledEvent> {
+        let current_status = match schema.status {
+            Some(_) => {
+                GuildScheduledEvent::get(user, guild_id, event_id, false)
+                    .await?
+                    .status
+            }
+            // Only the status transition depends on the event's current status
+            None => Default::default(),
+        };
+
+        GuildScheduledEvent::modify_with_status(
+            user,
+            guild_id,
+            event_id,
+            current_status,
+            schema,
+            audit_log_reason,
+        )
+        .await
+    }
+
+    /// Modifies a scheduled event whose current status is known, returning the updated event.
+    ///
+    /// The schema is checked with [GuildScheduledEventModifySchema::validate] against
+    /// `current_status` before it is sent.
+    ///
+    /// See [GuildScheduledEvent::modify].
+    pub async fn modify_with_status(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        event_id: Snowflake,
+        current_status: GuildScheduledEventStatus,
+        schema: GuildScheduledEventModifySchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<GuildScheduledEvent> {
+        schema.validate(current_status)?;
+
+        let url = format!(
+            "{}/guilds/{}/scheduled-events/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            event_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().patch(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<GuildScheduledEvent>(user)
+        .await
+    }
+
+    /// Deletes a scheduled event from a guild.
+    ///
+    /// Requires the [MANAGE_EVENTS](crate::types::PermissionFlags::MANAGE_EVENTS) permission,
+    /// unless the current user created the event.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-scheduled-event#delete-guild-scheduled-event>
+    pub async fn delete(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        event_id: Snowflake,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/scheduled-events/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            event_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().delete(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+
+    /// Returns a page of the users subscribed to a scheduled event, sorted by user id.
+    ///
+    /// To fetch more than one page, see [GuildScheduledEvent::users_paginator].
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-scheduled-event#get-guild-scheduled-event-users>
+    pub async fn get_users(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        event_id: Snowflake,
+        query: GetGuildScheduledEventUsersQuery,
+    ) -> ChorusResult<Vec<GuildScheduledEventUser>> {
+        let url = format!(
+            "{}/guilds/{}/scheduled-events/{}/users",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            event_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url).query(&query.to_query()),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<GuildScheduledEventUser>>(user)
+        .await
+    }
+
+    /// Returns a [GuildScheduledEventUsersPaginator], which fetches the users subscribed to a
+    /// scheduled event page by page.
+    ///
+    /// The paginator walks forwards from [GetGuildScheduledEventUsersQuery::after] (or the lowest
+    /// user id). If only [GetGuildScheduledEventUsersQuery::before] is set, it walks backwards
+    /// from there instead.
+    pub fn users_paginator(
+        user: &ChorusUser,
+        guild_id: Snowflake,
+        event_id: Snowflake,
+        query: GetGuildScheduledEventUsersQuery,
+    ) -> GuildScheduledEventUsersPaginator {
+        let direction = match query.before.is_some() && query.after.is_none() {
+            true => CursorDirection::Before,
+            false => CursorDirection::After,
+        };
+
+        Paginator::new(
+            user,
+            GuildScheduledEventUsersRequest {
+                guild_id,
+                event_id,
+                query,
+                direction,
+            },
+        )
+    }
+}
+
+/// Fetches the users subscribed to a scheduled event page by page, see
+/// [GuildScheduledEvent::users_paginator].
+///
+/// Use [Paginator::next_page] to get whole pages, or [Paginator::next_item] and
+/// [Paginator::into_stream] to go through the users one by one.
+pub type GuildScheduledEventUsersPaginator = Paginator<GuildScheduledEventUsersRequest>;
+
+/// A request for a page of the users subscribed to a scheduled event, see
+/// [GuildScheduledEventUsersPaginator].
+#[derive(Debug, Clone, Copy)]
+pub struct GuildScheduledEventUsersRequest {
+    guild_id: Snowflake,
+    event_id: Snowflake,
+    /// The query for the next page
+    query: GetGuildScheduledEventUsersQuery,
+    /// Which way we walk through the users
+    direction: CursorDirection,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PaginatedRequest for GuildScheduledEventUsersRequest {
+    type Page = Vec<GuildScheduledEventUser>;
+    type Item = GuildScheduledEventUser;
+
+    async fn fetch_page(
+        &self,
+        user: &mut ChorusUser,
+    ) -> ChorusResult<Vec<GuildScheduledEventUser>> {
+        GuildScheduledEvent::get_users(user, self.guild_id, self.event_id, self.query).await
+    }
+
+    fn advance(&mut self, page: &Vec<GuildScheduledEventUser>) -> bool {
+        let limit = self
+            .query
+            .limit
+            .map(usize::from)
+            .unwrap_or(DEFAULT_SCHEDULED_EVENT_USERS_LIMIT);
+        let ids = page.iter().map(|subscriber| subscriber.user.id);
+
+        let Some(next) = self.direction.next_cursor(ids, limit) else {
+            return false;
+        };
+        match self.direction {
+            CursorDirection::Before => self.query.before = Some(next),
+            CursorDirection::After => self.query.after = Some(next),
+        }
+        true
+    }
+
+    fn items(page: &Vec<GuildScheduledEventUser>) -> &[GuildScheduledEventUser] {
+        page
+    }
+
+    fn into_items(page: Vec<GuildScheduledEventUser>) -> Vec<GuildScheduledEventUser> {
+        page
+    }
+}
@@ -0,0 +1,228 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+use serde::Deserialize;
+
+use crate::{
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{
+        GuildCreateStickerSchema, GuildModifyStickerSchema, LimitType, Snowflake, Sticker,
+        StickerPack,
+    },
+};
+
+/// The response of the list sticker packs endpoint
+#[derive(Debug, Deserialize)]
+struct StickerPacks {
+    sticker_packs: Vec<StickerPack>,
+}
+
+impl Sticker {
+    /// Returns a sticker by its id.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/sticker#get-sticker>
+    pub async fn get(user: &mut ChorusUser, sticker_id: Snowflake) -> ChorusResult<Sticker> {
+        let url = format!(
+            "{}/stickers/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            sticker_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Global,
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Sticker>(user)
+        .await
+    }
+
+    /// Returns the stickers of a guild.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/sticker#list-guild-stickers>
+    pub async fn get_guild_stickers(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+    ) -> ChorusResult<Vec<Sticker>> {
+        let url = format!(
+            "{}/guilds/{}/stickers",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<Sticker>>(user)
+        .await
+    }
+
+    /// Returns a single sticker of a guild.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/sticker#get-guild-sticker>
+    pub async fn get_guild_sticker(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        sticker_id: Snowflake,
+    ) -> ChorusResult<Sticker> {
+        let url = format!(
+            "{}/guilds/{}/stickers/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            sticker_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Sticker>(user)
+        .await
+    }
+
+    /// Uploads a new sticker to a guild.
+    ///
+    /// The sticker's file is sent as multipart form data, with the mime type of
+    /// [GuildCreateStickerSchema::sticker_format_type].
+    ///
+    /// Requires the [MANAGE_GUILD_EXPRESSIONS](crate::types::PermissionFlags::MANAGE_GUILD_EXPRESSIONS)
+    /// permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/sticker#create-guild-sticker>
+    pub async fn create(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        schema: GuildCreateStickerSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<Sticker> {
+        let url = format!(
+            "{}/guilds/{}/stickers",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user
+                .http_client()
+                .post(url)
+                .multipart(schema.to_multipart()),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<Sticker>(user)
+        .await
+    }
+
+    /// Modifies a sticker of a guild, returning the updated sticker.
+    ///
+    /// Requires the [MANAGE_GUILD_EXPRESSIONS](crate::types::PermissionFlags::MANAGE_GUILD_EXPRESSIONS)
+    /// permission, unless the current user created the sticker.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/sticker#modify-guild-sticker>
+    pub async fn modify(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        sticker_id: Snowflake,
+        schema: GuildModifyStickerSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<Sticker> {
+        let url = format!(
+            "{}/guilds/{}/stickers/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            sticker_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().patch(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<Sticker>(user)
+        .await
+    }
+
+    /// Deletes a sticker from a guild.
+    ///
+    /// Requires the [MANAGE_GUILD_EXPRESSIONS](crate::types::PermissionFlags::MANAGE_GUILD_EXPRESSIONS)
+    /// permission, unless the current user created the sticker.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/sticker#delete-guild-sticker>
+    pub async fn delete(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        sticker_id: Snowflake,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/stickers/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            sticker_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().delete(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+}
+
+impl StickerPack {
+    /// Returns all standard sticker packs.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/sticker#list-sticker-packs>
+    pub async fn get_all(user: &mut ChorusUser) -> ChorusResult<Vec<StickerPack>> {
+        let url = format!("{}/sticker-packs", user.belongs_to.read().unwrap().urls.api);
+
+        let response = ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Global,
+        }
+        .with_headers_for(user)
+        .deserialize_response::<StickerPacks>(user)
+        .await?;
+
+        Ok(response.sticker_packs)
+    }
+
+    /// Returns a standard sticker pack by its id.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/sticker#get-sticker-pack>
+    pub async fn get(user: &mut ChorusUser, pack_id: Snowflake) -> ChorusResult<StickerPack> {
+        let url = format!(
+            "{}/sticker-packs/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            pack_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Global,
+        }
+        .with_headers_for(user)
+        .deserialize_response::<StickerPack>(user)
+        .await
+    }
+}
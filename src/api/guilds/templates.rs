@@ -0,0 +1,210 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+
+use crate::{
+    errors::{ChorusError, ChorusResult},
+    instance::{ChorusUser, Instance},
+    ratelimiter::ChorusRequest,
+    types::{
+        Guild, GuildCreateFromTemplateSchema, GuildTemplate, GuildTemplateCreateSchema,
+        GuildTemplateModifySchema, LimitType, Snowflake,
+    },
+};
+
+impl Instance {
+    /// Resolves a template code to its [GuildTemplate], including the
+    /// [serialized_source_guild](GuildTemplate::serialized_source_guild) that guilds created
+    /// from it are copied from.
+    ///
+    /// This endpoint is unauthenticated.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-template#get-guild-template>
+    pub async fn get_guild_template(&self, code: &str) -> ChorusResult<GuildTemplate> {
+        let url = format!("{}/guilds/templates/{}", self.urls.api, code);
+
+        let response = match self.client.get(&url).send().await {
+            Ok(result) => result,
+            Err(e) => {
+                return Err(ChorusError::RequestFailed {
+                    url,
+                    error: e.to_string(),
+                });
+            }
+        };
+
+        if !response.status().is_success() {
+            return Err(ChorusError::ReceivedErrorCode {
+                error_code: response.status().as_u16(),
+                error: response.text().await.unwrap_or_default(),
+            });
+        }
+
+        ChorusRequest::deserialize_body::<GuildTemplate>(response).await
+    }
+}
+
+impl GuildTemplate {
+    /// Returns the templates of a guild.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-template#get-guild-templates>
+    pub async fn get_all(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+    ) -> ChorusResult<Vec<GuildTemplate>> {
+        let url = format!(
+            "{}/guilds/{}/templates",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().get(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<GuildTemplate>>(user)
+        .await
+    }
+
+    /// Creates a template from the current state of a guild.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-template#create-guild-template>
+    pub async fn create(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        schema: GuildTemplateCreateSchema,
+    ) -> ChorusResult<GuildTemplate> {
+        let url = format!(
+            "{}/guilds/{}/templates",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().post(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<GuildTemplate>(user)
+        .await
+    }
+
+    /// Updates a template to match the current state of its guild, returning the updated
+    /// template.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-template#sync-guild-template>
+    pub async fn sync(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        code: &str,
+    ) -> ChorusResult<GuildTemplate> {
+        let url = format!(
+            "{}/guilds/{}/templates/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            code
+        );
+
+        ChorusRequest {
+            request: user.http_client().put(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<GuildTemplate>(user)
+        .await
+    }
+
+    /// Modifies the name or description of a template, returning the updated template.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-template#modify-guild-template>
+    pub async fn modify(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        code: &str,
+        schema: GuildTemplateModifySchema,
+    ) -> ChorusResult<GuildTemplate> {
+        let url = format!(
+            "{}/guilds/{}/templates/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            code
+        );
+
+        ChorusRequest {
+            request: user.http_client().patch(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<GuildTemplate>(user)
+        .await
+    }
+
+    /// Deletes a template, returning the deleted template.
+    ///
+    /// Requires the [MANAGE_GUILD](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-template#delete-guild-template>
+    pub async fn delete(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        code: &str,
+    ) -> ChorusResult<GuildTemplate> {
+        let url = format!(
+            "{}/guilds/{}/templates/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            code
+        );
+
+        ChorusRequest {
+            request: user.http_client().delete(url),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<GuildTemplate>(user)
+        .await
+    }
+
+    /// Creates a new guild from a template, with the current user as its owner.
+    ///
+    /// To preview what the guild will look like, see [Instance::get_guild_template].
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/guild-template#use-guild-template>
+    pub async fn create_guild(
+        user: &mut ChorusUser,
+        code: &str,
+        schema: GuildCreateFromTemplateSchema,
+    ) -> ChorusResult<Guild> {
+        let url = format!(
+            "{}/guilds/templates/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            code
+        );
+
+        ChorusRequest {
+            request: user.http_client().post(url).json(&schema),
+            limit_type: LimitType::Global,
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Guild>(user)
+        .await
+    }
+}
@@ -0,0 +1,75 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+
+use crate::{
+    errors::ChorusResult,
+    instance::ChorusUser,
+    ratelimiter::ChorusRequest,
+    types::{LimitType, Snowflake, VoiceState, VoiceStateUpdateSchema},
+};
+
+impl VoiceState {
+    /// Modifies the current user's voice state in a stage channel of a guild.
+    ///
+    /// Setting [VoiceStateUpdateSchema::request_to_speak_timestamp] to a time raises the user's
+    /// hand, setting it to `Some(None)` lowers it; unsuppressing oneself requires the
+    /// [MUTE_MEMBERS](crate::types::PermissionFlags::MUTE_MEMBERS) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/voice#modify-current-user-voice-state>
+    pub async fn modify_current(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        schema: VoiceStateUpdateSchema,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/voice-states/@me",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().patch(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+
+    /// Modifies another user's voice state in a stage channel of a guild, e.g. to invite them to
+    /// speak by unsuppressing them.
+    ///
+    /// [VoiceStateUpdateSchema::channel_id] has to be the stage channel the user is in; other
+    /// users' requests to speak can't be changed, so
+    /// [VoiceStateUpdateSchema::request_to_speak_timestamp] has to be [None].
+    ///
+    /// Requires the [MUTE_MEMBERS](crate::types::PermissionFlags::MUTE_MEMBERS) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/voice#modify-user-voice-state>
+    pub async fn modify(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+        user_id: Snowflake,
+        schema: VoiceStateUpdateSchema,
+    ) -> ChorusResult<()> {
+        let url = format!(
+            "{}/guilds/{}/voice-states/{}",
+            user.belongs_to.read().unwrap().urls.api,
+            guild_id,
+            user_id
+        );
+
+        ChorusRequest {
+            request: user.http_client().patch(url).json(&schema),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+}
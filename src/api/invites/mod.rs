@@ -9,9 +9,14 @@ use crate::errors::ChorusResult;
 use crate::instance::ChorusUser;
 use crate::ratelimiter::ChorusRequest;
 use crate::types::{
-    AcceptInviteSchema, CreateChannelInviteSchema, GuildInvite, Invite, LimitType, Snowflake,
+    AcceptInviteSchema, Channel, CreateChannelInviteSchema, GetInvitesSchema, Guild, GuildInvite,
+    Invite, LimitType, Snowflake,
 };
 
+pub use tracker::*;
+
+pub mod tracker;
+
 impl ChorusUser {
     /// Accepts an invite to a guild, group DM, or DM.
     ///
@@ -25,7 +30,8 @@ impl ChorusUser {
         session_id: Option<String>,
     ) -> ChorusResult<Invite> {
         let request = ChorusRequest {
-            request: Client::new()
+            request: self
+                .http_client()
                 .post(format!(
                     "{}/invites/{}",
                     self.belongs_to.read().unwrap().urls.api,
@@ -47,7 +53,8 @@ impl ChorusUser {
     /// See <https://discord-userdoccers.vercel.app/resources/invite#create-user-invite>
     pub async fn create_user_invite(&mut self, code: Option<&str>) -> ChorusResult<Invite> {
         ChorusRequest {
-            request: Client::new()
+            request: self
+                .http_client()
                 .post(format!(
                     "{}/users/@me/invites",
                     self.belongs_to.read().unwrap().urls.api
@@ -73,7 +80,8 @@ impl ChorusUser {
         channel_id: Snowflake,
     ) -> ChorusResult<GuildInvite> {
         ChorusRequest {
-            request: Client::new()
+            request: self
+                .http_client()
                 .post(format!(
                     "{}/channels/{}/invites",
                     self.belongs_to.read().unwrap().urls.api,
@@ -86,4 +94,151 @@ impl ChorusUser {
         .deserialize_response::<GuildInvite>(self)
         .await
     }
+
+    /// Resolves an invite code to its [Invite].
+    ///
+    /// Use [GetInvitesSchema] to include the approximate member counts of the guild and the
+    /// expiration date of the invite.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/invite#get-invite>
+    pub async fn get_invite(
+        &mut self,
+        invite_code: &str,
+        query: GetInvitesSchema,
+    ) -> ChorusResult<Invite> {
+        ChorusRequest {
+            request: self
+                .http_client()
+                .get(format!(
+                    "{}/invites/{}",
+                    self.belongs_to.read().unwrap().urls.api,
+                    invite_code
+                ))
+                .query(&query.to_query()),
+            limit_type: LimitType::Global,
+        }
+        .with_headers_for(self)
+        .deserialize_response::<Invite>(self)
+        .await
+    }
+
+    /// Deletes (revokes) an invite, returning the deleted invite.
+    ///
+    /// # Guild Channels
+    /// For guild invites, the endpoint requires the
+    /// [`MANAGE_CHANNELS`](crate::types::PermissionFlags::MANAGE_CHANNELS) permission on the
+    /// channel or the [`MANAGE_GUILD`](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// Fires an [InviteDelete](crate::types::InviteDelete) gateway event.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/invite#delete-invite>
+    pub async fn delete_invite(
+        &mut self,
+        invite_code: &str,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<Invite> {
+        ChorusRequest {
+            request: self.http_client().delete(format!(
+                "{}/invites/{}",
+                self.belongs_to.read().unwrap().urls.api,
+                invite_code
+            )),
+            limit_type: LimitType::Global,
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(self)
+        .deserialize_response::<Invite>(self)
+        .await
+    }
+
+    /// Returns the friend invites of the current user.
+    ///
+    /// Note: Spacebar does not yet implement this endpoint.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/invite#get-user-invites>
+    pub async fn get_user_invites(&mut self) -> ChorusResult<Vec<Invite>> {
+        ChorusRequest {
+            request: self.http_client().get(format!(
+                "{}/users/@me/invites",
+                self.belongs_to.read().unwrap().urls.api
+            )),
+            limit_type: LimitType::Global,
+        }
+        .with_headers_for(self)
+        .deserialize_response::<Vec<Invite>>(self)
+        .await
+    }
+
+    /// Revokes all friend invites of the current user, returning the revoked invites.
+    ///
+    /// Note: Spacebar does not yet implement this endpoint.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/invite#revoke-user-invites>
+    pub async fn revoke_user_invites(&mut self) -> ChorusResult<Vec<Invite>> {
+        ChorusRequest {
+            request: self.http_client().delete(format!(
+                "{}/users/@me/invites",
+                self.belongs_to.read().unwrap().urls.api
+            )),
+            limit_type: LimitType::Global,
+        }
+        .with_headers_for(self)
+        .deserialize_response::<Vec<Invite>>(self)
+        .await
+    }
+}
+
+impl Guild {
+    /// Returns the invites of a guild, including their use counts.
+    ///
+    /// Requires the [`MANAGE_GUILD`](crate::types::PermissionFlags::MANAGE_GUILD) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/invite#get-guild-invites>
+    pub async fn get_invites(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+    ) -> ChorusResult<Vec<GuildInvite>> {
+        ChorusRequest {
+            request: user.http_client().get(format!(
+                "{}/guilds/{}/invites",
+                user.belongs_to.read().unwrap().urls.api,
+                guild_id
+            )),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<GuildInvite>>(user)
+        .await
+    }
+}
+
+impl Channel {
+    /// Returns the invites of a guild channel or group DM, including their use counts.
+    ///
+    /// For guild channels, the endpoint requires the
+    /// [`MANAGE_CHANNELS`](crate::types::PermissionFlags::MANAGE_CHANNELS) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/invite#get-channel-invites>
+    pub async fn get_invites(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+    ) -> ChorusResult<Vec<GuildInvite>> {
+        ChorusRequest {
+            request: user.http_client().get(format!(
+                "{}/channels/{}/invites",
+                user.belongs_to.read().unwrap().urls.api,
+                channel_id
+            )),
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<GuildInvite>>(user)
+        .await
+    }
 }
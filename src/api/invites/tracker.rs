@@ -0,0 +1,173 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Attributes guild joins to invites, by comparing invite use counts.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+
+use crate::errors::ChorusResult;
+use crate::instance::ChorusUser;
+use crate::types::{Guild, GuildInvite, GuildMemberAdd, InviteCreate, InviteDelete, Snowflake};
+
+/// Tracks the use counts of a guild's invites, to find out which invite a new member joined with.
+///
+/// The server does not say which invite was used, so the tracker keeps a snapshot of the guild's
+/// invites. When a member joins, the invites are fetched again and compared to the snapshot;
+/// the invite whose use count went up is the one that was used, see
+/// [InviteTracker::attribute_join].
+///
+/// To keep the snapshot current between joins, pass the gateway's
+/// [InviteCreate] and [InviteDelete] events to [InviteTracker::invite_created] and
+/// [InviteTracker::invite_deleted].
+///
+/// Listing the invites requires the [`MANAGE_GUILD`](crate::types::PermissionFlags::MANAGE_GUILD)
+/// permission.
+#[derive(Debug, Clone, Default)]
+pub struct InviteTracker {
+    guild_id: Snowflake,
+    invites: HashMap<String, GuildInvite>,
+}
+
+/// The result of attributing a join with an [InviteTracker].
+#[derive(Debug, Clone, PartialEq)]
+pub enum InviteAttribution {
+    /// Exactly one invite was used since the last snapshot
+    Invite(GuildInvite),
+    /// Several invites were used since the last snapshot, e.g. because several members joined
+    /// before the invites were fetched again
+    Ambiguous(Vec<GuildInvite>),
+    /// None of the tracked invites were used, e.g. because the member joined through the vanity
+    /// url or guild discovery
+    Unknown,
+}
+
+impl InviteTracker {
+    /// Fetches the invites of a guild and starts tracking them.
+    pub async fn new(user: &mut ChorusUser, guild_id: Snowflake) -> ChorusResult<InviteTracker> {
+        let invites = Guild::get_invites(user, guild_id).await?;
+        Ok(InviteTracker::from_invites(guild_id, invites))
+    }
+
+    /// Starts tracking an already fetched list of invites.
+    pub fn from_invites(
+        guild_id: Snowflake,
+        invites: impl IntoIterator<Item = GuildInvite>,
+    ) -> InviteTracker {
+        InviteTracker {
+            guild_id,
+            invites: invites
+                .into_iter()
+                .map(|invite| (invite.code.clone(), invite))
+                .collect(),
+        }
+    }
+
+    /// Returns the id of the tracked guild
+    pub fn guild_id(&self) -> Snowflake {
+        self.guild_id
+    }
+
+    /// Returns the invites in the current snapshot
+    pub fn invites(&self) -> impl Iterator<Item = &GuildInvite> {
+        self.invites.values()
+    }
+
+    /// Adds a newly created invite to the snapshot.
+    ///
+    /// Invites of other guilds are ignored.
+    pub fn invite_created(&mut self, event: &InviteCreate) {
+        if event.invite.guild_id != self.guild_id {
+            return;
+        }
+        self.invites
+            .insert(event.invite.code.clone(), event.invite.clone());
+    }
+
+    /// Removes a deleted invite from the snapshot.
+    ///
+    /// Invites which were one use away from their limit are kept until the next comparison, since
+    /// the server deletes them when the last use joins; that way the join can still be attributed
+    /// to them.
+    ///
+    /// Invites of other guilds are ignored.
+    pub fn invite_deleted(&mut self, event: &InviteDelete) {
+        if event.guild_id != Some(self.guild_id) {
+            return;
+        }
+        if !self
+            .invites
+            .get(&event.code)
+            .is_some_and(is_one_use_from_limit)
+        {
+            self.invites.remove(&event.code);
+        }
+    }
+
+    /// Compares the current invites of the guild to the snapshot, returning the invites which
+    /// were used since, and replaces the snapshot.
+    ///
+    /// Invites which disappeared while one use away from their limit (and had not expired) count
+    /// as used.
+    pub fn update(&mut self, invites: impl IntoIterator<Item = GuildInvite>) -> InviteAttribution {
+        let mut current: HashMap<String, GuildInvite> = invites
+            .into_iter()
+            .map(|invite| (invite.code.clone(), invite))
+            .collect();
+
+        let mut used = Vec::new();
+        for (code, previous) in self.invites.iter() {
+            match current.get(code) {
+                Some(invite) if invite.uses.unwrap_or(0) > previous.uses.unwrap_or(0) => {
+                    used.push(invite.clone())
+                }
+                Some(_) => (),
+                None => {
+                    let expired = previous
+                        .expires_at
+                        .is_some_and(|expires_at| expires_at <= Utc::now());
+                    if !expired && is_one_use_from_limit(previous) {
+                        let mut invite = previous.clone();
+                        invite.uses = Some(invite.uses.unwrap_or(0) + 1);
+                        used.push(invite);
+                    }
+                }
+            }
+        }
+        std::mem::swap(&mut self.invites, &mut current);
+
+        match used.len() {
+            0 => InviteAttribution::Unknown,
+            1 => InviteAttribution::Invite(used.remove(0)),
+            _ => {
+                used.sort_by(|a, b| a.code.cmp(&b.code));
+                InviteAttribution::Ambiguous(used)
+            }
+        }
+    }
+
+    /// Finds out which invite a new member of the guild joined with.
+    ///
+    /// Fetches the guild's invites and compares them to the snapshot with
+    /// [InviteTracker::update]. Joins to other guilds are [InviteAttribution::Unknown].
+    pub async fn attribute_join(
+        &mut self,
+        user: &mut ChorusUser,
+        event: &GuildMemberAdd,
+    ) -> ChorusResult<InviteAttribution> {
+        if event.guild_id != self.guild_id {
+            return Ok(InviteAttribution::Unknown);
+        }
+        let invites = Guild::get_invites(user, self.guild_id).await?;
+        Ok(self.update(invites))
+    }
+}
+
+/// Whether the next use of the invite reaches its use limit
+fn is_one_use_from_limit(invite: &GuildInvite) -> bool {
+    invite
+        .max_uses
+        .is_some_and(|max_uses| max_uses > 0 && invite.uses.unwrap_or(0) + 1 >= max_uses)
+}
@@ -5,17 +5,23 @@
 //! All of the API's endpoints.
 
 #![allow(unused_imports)]
+pub use applications::*;
 pub use channels::messages::*;
 pub use guilds::*;
 pub use invites::*;
+pub use pagination::*;
 pub use policies::instance::instance::*;
 pub use users::*;
+pub use webhooks::*;
 pub use instance::*;
 
+pub mod applications;
 pub mod auth;
 pub mod channels;
 pub mod guilds;
 pub mod invites;
+pub mod pagination;
 pub mod policies;
 pub mod users;
+pub mod webhooks;
 pub mod instance;
@@ -0,0 +1,142 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Fetching paginated endpoints page by page.
+
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use futures_util::Stream;
+
+use crate::{errors::ChorusResult, instance::ChorusUser, types::Snowflake};
+
+/// A request to an endpoint which returns its results page by page, along with the cursor of
+/// the page it requests.
+///
+/// Used by [Paginator], which fetches the requested page and then moves the cursor past it.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait PaginatedRequest: Clone {
+    /// What the endpoint returns for a single page
+    type Page;
+    /// The results a page consists of
+    type Item;
+
+    /// Fetches the page the cursor currently points to.
+    async fn fetch_page(&self, user: &mut ChorusUser) -> ChorusResult<Self::Page>;
+
+    /// Moves the cursor past `page`.
+    ///
+    /// Returns false if `page` was the last page.
+    fn advance(&mut self, page: &Self::Page) -> bool;
+
+    /// Returns the results of a page.
+    fn items(page: &Self::Page) -> &[Self::Item];
+
+    /// Turns a page into its results.
+    fn into_items(page: Self::Page) -> Vec<Self::Item>;
+}
+
+/// Which way a cursor moves through results sorted by their ids
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CursorDirection {
+    /// Towards lower ids, using `before`
+    Before,
+    /// Towards higher ids, using `after`
+    After,
+}
+
+impl CursorDirection {
+    /// Returns the id the page after a page with the given result ids starts at, or [None] if
+    /// there is no next page.
+    ///
+    /// Pages with less than `limit` results are the last ones.
+    pub(crate) fn next_cursor(
+        self,
+        ids: impl ExactSizeIterator<Item = Snowflake>,
+        limit: usize,
+    ) -> Option<Snowflake> {
+        if ids.len() < limit {
+            return None;
+        }
+
+        match self {
+            CursorDirection::Before => ids.min(),
+            CursorDirection::After => ids.max(),
+        }
+    }
+}
+
+/// Fetches a paginated endpoint page by page.
+///
+/// Use [Paginator::next_page] to get whole pages, or [Paginator::next_item] and
+/// [Paginator::into_stream] to go through the results one by one.
+#[derive(Debug, Clone)]
+pub struct Paginator<R: PaginatedRequest> {
+    user: ChorusUser,
+    /// The request for the next page
+    request: R,
+    /// Results of the last page which were not returned by [Paginator::next_item] yet
+    items: VecDeque<R::Item>,
+    /// Whether the last page has been fetched
+    exhausted: bool,
+}
+
+impl<R: PaginatedRequest> Paginator<R> {
+    /// Creates a paginator, which starts at the page `request` points to.
+    pub fn new(user: &ChorusUser, request: R) -> Paginator<R> {
+        Paginator {
+            user: user.clone(),
+            request,
+            items: VecDeque::new(),
+            exhausted: false,
+        }
+    }
+
+    /// Fetches the next page, or returns [None] if there are no more results.
+    ///
+    /// Results buffered by [Paginator::next_item] are skipped.
+    pub async fn next_page(&mut self) -> ChorusResult<Option<R::Page>> {
+        self.items.clear();
+        if self.exhausted {
+            return Ok(None);
+        }
+
+        let page = self.request.fetch_page(&mut self.user).await?;
+        if !self.request.advance(&page) {
+            self.exhausted = true;
+        }
+
+        if R::items(&page).is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(page))
+    }
+
+    /// Returns the next result, fetching the next page if needed, or [None] if there are no more
+    /// results.
+    pub async fn next_item(&mut self) -> ChorusResult<Option<R::Item>> {
+        if self.items.is_empty() {
+            let Some(page) = self.next_page().await? else {
+                return Ok(None);
+            };
+            self.items = R::into_items(page).into();
+        }
+        Ok(self.items.pop_front())
+    }
+
+    /// Turns the paginator into a [Stream] of results.
+    ///
+    /// The stream ends after the last result or the first error.
+    pub fn into_stream(self) -> impl Stream<Item = ChorusResult<R::Item>> {
+        futures_util::stream::unfold(Some(self), |paginator| async move {
+            let mut paginator = paginator?;
+            match paginator.next_item().await {
+                Ok(Some(item)) => Some((Ok(item), Some(paginator))),
+                Ok(None) => None,
+                Err(error) => Some((Err(error), None)),
+            }
+        })
+    }
+}
@@ -23,7 +23,7 @@ impl ChorusUser {
             self.belongs_to.read().unwrap().urls.api
         );
         ChorusRequest {
-            request: Client::new().get(url),
+            request: self.http_client().get(url),
             limit_type: LimitType::Global,
         }
         .with_headers_for(self)
@@ -47,7 +47,10 @@ impl ChorusUser {
             self.belongs_to.read().unwrap().urls.api
         );
         ChorusRequest {
-            request: Client::new().post(url).json(&create_private_channel_schema),
+            request: self
+                .http_client()
+                .post(url)
+                .json(&create_private_channel_schema),
             limit_type: LimitType::Global,
         }
         .with_headers_for(self)
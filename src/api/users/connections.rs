@@ -39,7 +39,8 @@ impl ChorusUser {
             .expect("Failed to serialize connection type!")
             .replace('"', "");
 
-        let request = Client::new()
+        let request = self
+            .http_client()
             .get(format!(
                 "{}/connections/{}/authorize",
                 self.belongs_to.read().unwrap().urls.api,
@@ -79,7 +80,8 @@ impl ChorusUser {
             .expect("Failed to serialize connection type!")
             .replace('"', "");
 
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/connections/{}/callback",
                 self.belongs_to.read().unwrap().urls.api,
@@ -109,7 +111,8 @@ impl ChorusUser {
         connection_account_id: &String,
         json_schema: CreateContactSyncConnectionSchema,
     ) -> ChorusResult<Connection> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .put(format!(
                 "{}/users/@me/connections/contacts/{}",
                 self.belongs_to.read().unwrap().urls.api,
@@ -180,7 +183,7 @@ impl ChorusUser {
         &mut self,
         domain: &String,
     ) -> ChorusResult<CreateDomainConnectionReturn> {
-        let request = Client::new().post(format!(
+        let request = self.http_client().post(format!(
             "{}/users/@me/connections/domain/{}",
             self.belongs_to.read().unwrap().urls.api,
             domain
@@ -227,7 +230,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#get-user-connections>
     pub async fn get_connections(&mut self) -> ChorusResult<Vec<Connection>> {
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/connections",
             self.belongs_to.read().unwrap().urls.api,
         ));
@@ -254,7 +257,7 @@ impl ChorusUser {
             .expect("Failed to serialize connection type!")
             .replace('"', "");
 
-        let request = Client::new().post(format!(
+        let request = self.http_client().post(format!(
             "{}/users/@me/connections/{}/{}/refresh",
             self.belongs_to.read().unwrap().urls.api,
             connection_type_string,
@@ -287,7 +290,8 @@ impl ChorusUser {
             .expect("Failed to serialize connection type!")
             .replace('"', "");
 
-        let request = Client::new()
+        let request = self
+            .http_client()
             .patch(format!(
                 "{}/users/@me/connections/{}/{}",
                 self.belongs_to.read().unwrap().urls.api,
@@ -318,7 +322,7 @@ impl ChorusUser {
             .expect("Failed to serialize connection type!")
             .replace('"', "");
 
-        let request = Client::new().delete(format!(
+        let request = self.http_client().delete(format!(
             "{}/users/@me/connections/{}/{}",
             self.belongs_to.read().unwrap().urls.api,
             connection_type_string,
@@ -349,7 +353,7 @@ impl ChorusUser {
             .expect("Failed to serialize connection type!")
             .replace('"', "");
 
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/connections/{}/{}/access-token",
             self.belongs_to.read().unwrap().urls.api,
             connection_type_string,
@@ -379,7 +383,7 @@ impl ChorusUser {
         &mut self,
         connection_account_id: &String,
     ) -> ChorusResult<Vec<ConnectionSubreddit>> {
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/connections/reddit/{}/subreddits",
             self.belongs_to.read().unwrap().urls.api,
             connection_account_id
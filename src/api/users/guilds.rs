@@ -26,7 +26,8 @@ impl ChorusUser {
         lurking: Option<bool>,
     ) -> ChorusResult<()> {
         ChorusRequest {
-            request: Client::new()
+            request: self
+                .http_client()
                 .delete(format!(
                     "{}/users/@me/guilds/{}",
                     self.belongs_to.read().unwrap().urls.api,
@@ -64,7 +65,7 @@ impl ChorusUser {
             self.belongs_to.read().unwrap().urls.api,
         );
         let chorus_request = ChorusRequest {
-            request: Client::new().get(url).query(&query_parameters),
+            request: self.http_client().get(url).query(&query_parameters),
 
             limit_type: LimitType::Global,
         }
@@ -87,7 +88,7 @@ impl ChorusUser {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().get(url),
+            request: self.http_client().get(url),
             limit_type: LimitType::Global,
         }
         .with_headers_for(self);
@@ -31,7 +31,8 @@ impl ChorusUser {
         &mut self,
         schema: EnableTotpMfaSchema,
     ) -> ChorusResult<EnableTotpMfaResponse> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/mfa/totp/enable",
                 self.belongs_to.read().unwrap().urls.api
@@ -65,7 +66,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#disable-totp-mfa>
     pub async fn disable_totp_mfa(&mut self) -> ChorusResult<Token> {
-        let request = Client::new().post(format!(
+        let request = self.http_client().post(format!(
             "{}/users/@me/mfa/totp/disable",
             self.belongs_to.read().unwrap().urls.api
         ));
@@ -96,7 +97,8 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#enable-sms-mfa>
     pub async fn enable_sms_mfa(&mut self, schema: SmsMfaRouteSchema) -> ChorusResult<()> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/mfa/sms/enable",
                 self.belongs_to.read().unwrap().urls.api
@@ -123,7 +125,8 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#disable-sms-mfa>
     pub async fn disable_sms_mfa(&mut self, schema: SmsMfaRouteSchema) -> ChorusResult<()> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/mfa/sms/disable",
                 self.belongs_to.read().unwrap().urls.api
@@ -146,7 +149,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#get-webauthn-authenticators>
     pub async fn get_webauthn_authenticators(&mut self) -> ChorusResult<Vec<MfaAuthenticator>> {
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/mfa/webauthn/credentials",
             self.belongs_to.read().unwrap().urls.api
         ));
@@ -179,7 +182,7 @@ impl ChorusUser {
     pub async fn begin_webauthn_authenticator_creation(
         &mut self,
     ) -> ChorusResult<BeginWebAuthnAuthenticatorCreationReturn> {
-        let request = Client::new().post(format!(
+        let request = self.http_client().post(format!(
             "{}/users/@me/mfa/webauthn/credentials",
             self.belongs_to.read().unwrap().urls.api
         ));
@@ -217,7 +220,8 @@ impl ChorusUser {
         &mut self,
         schema: FinishWebAuthnAuthenticatorCreationSchema,
     ) -> ChorusResult<FinishWebAuthnAuthenticatorCreationReturn> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/mfa/webauthn/credentials",
                 self.belongs_to.read().unwrap().urls.api
@@ -251,7 +255,8 @@ impl ChorusUser {
         authenticator_id: Snowflake,
         schema: ModifyWebAuthnAuthenticatorSchema,
     ) -> ChorusResult<MfaAuthenticator> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .patch(format!(
                 "{}/users/@me/mfa/webauthn/credentials/{}",
                 self.belongs_to.read().unwrap().urls.api,
@@ -288,7 +293,7 @@ impl ChorusUser {
         &mut self,
         authenticator_id: Snowflake,
     ) -> ChorusResult<()> {
-        let request = Client::new().delete(format!(
+        let request = self.http_client().delete(format!(
             "{}/users/@me/mfa/webauthn/credentials/{}",
             self.belongs_to.read().unwrap().urls.api,
             authenticator_id
@@ -318,7 +323,8 @@ impl ChorusUser {
         &mut self,
         schema: SendBackupCodesChallengeSchema,
     ) -> ChorusResult<SendBackupCodesChallengeReturn> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/auth/verify/view-backup-codes-challenge",
                 self.belongs_to.read().unwrap().urls.api,
@@ -354,7 +360,8 @@ impl ChorusUser {
         &mut self,
         schema: GetBackupCodesSchema,
     ) -> ChorusResult<Vec<MfaBackupCode>> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/mfa/codes-verification",
                 self.belongs_to.read().unwrap().urls.api,
@@ -30,7 +30,7 @@ impl ChorusUser {
             user_id
         );
         let chorus_request = ChorusRequest {
-            request: Client::new().get(url),
+            request: self.http_client().get(url),
             limit_type: LimitType::Global,
         }
         .with_headers_for(self);
@@ -49,7 +49,7 @@ impl ChorusUser {
             self.belongs_to.read().unwrap().urls.api
         );
         let chorus_request = ChorusRequest {
-            request: Client::new().get(url),
+            request: self.http_client().get(url),
             limit_type: LimitType::Global,
         }
         .with_headers_for(self);
@@ -71,7 +71,7 @@ impl ChorusUser {
             self.belongs_to.read().unwrap().urls.api
         );
         let chorus_request = ChorusRequest {
-            request: Client::new().post(url).json(&schema),
+            request: self.http_client().post(url).json(&schema),
             limit_type: LimitType::Global,
         }
         .with_headers_for(self);
@@ -90,7 +90,8 @@ impl ChorusUser {
         match relationship_type {
             RelationshipType::None => {
                 let chorus_request = ChorusRequest {
-                    request: Client::new()
+                    request: self
+                        .http_client()
                         .delete(format!("{}/users/@me/relationships/{}", api_url, user_id)),
                     limit_type: LimitType::Global,
                 }
@@ -104,7 +105,8 @@ impl ChorusUser {
                     friend_token: None,
                 };
                 let chorus_request = ChorusRequest {
-                    request: Client::new()
+                    request: self
+                        .http_client()
                         .put(format!("{}/users/@me/relationships/{}", api_url, user_id))
                         .json(&schema),
                     limit_type: LimitType::Global,
@@ -119,7 +121,8 @@ impl ChorusUser {
                     friend_token: None,
                 };
                 let chorus_request = ChorusRequest {
-                    request: Client::new()
+                    request: self
+                        .http_client()
                         .put(format!("{}/users/@me/relationships/{}", api_url, user_id))
                         .json(&schema),
                     limit_type: LimitType::Global,
@@ -142,7 +145,7 @@ impl ChorusUser {
             user_id
         );
         let chorus_request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: self.http_client().delete(url),
             limit_type: LimitType::Global,
         }
         .with_headers_for(self);
@@ -168,7 +171,7 @@ impl ChorusUser {
             self.belongs_to.read().unwrap().urls.api,
         );
         let chorus_request = ChorusRequest {
-            request: Client::new().delete(url).query(&query_parameters),
+            request: self.http_client().delete(url).query(&query_parameters),
             limit_type: LimitType::Global,
         }
         .with_headers_for(self);
@@ -190,7 +193,7 @@ impl ChorusUser {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().put(url),
+            request: self.http_client().put(url),
             limit_type: LimitType::Global,
         }
         .with_headers_for(self);
@@ -213,7 +216,7 @@ impl ChorusUser {
         );
 
         let chorus_request = ChorusRequest {
-            request: Client::new().delete(url),
+            request: self.http_client().delete(url),
             limit_type: LimitType::Global,
         }
         .with_headers_for(self);
@@ -104,7 +104,8 @@ impl ChorusUser {
             return Err(ChorusError::PasswordRequired);
         }
 
-        let request = Client::new()
+        let request = self
+            .http_client()
             .patch(format!(
                 "{}/users/@me",
                 self.belongs_to.read().unwrap().urls.api
@@ -133,7 +134,8 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#disable-user>
     pub async fn disable(&mut self, schema: DeleteDisableUserSchema) -> ChorusResult<()> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/disable",
                 self.belongs_to.read().unwrap().urls.api
@@ -160,7 +162,8 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#delete-user>
     pub async fn delete(&mut self, schema: DeleteDisableUserSchema) -> ChorusResult<()> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/delete",
                 self.belongs_to.read().unwrap().urls.api
@@ -225,7 +228,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#modify-user-email>
     pub async fn initiate_email_change(&mut self) -> ChorusResult<()> {
-        let request = Client::new().put(format!(
+        let request = self.http_client().put(format!(
             "{}/users/@me/email",
             self.belongs_to.read().unwrap().urls.api
         ));
@@ -254,7 +257,8 @@ impl ChorusUser {
         &mut self,
         schema: VerifyUserEmailChangeSchema,
     ) -> ChorusResult<VerifyUserEmailChangeResponse> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/email/verify-code",
                 self.belongs_to.read().unwrap().urls.api
@@ -285,7 +289,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#get-pomelo-suggestions>
     pub async fn get_pomelo_suggestions(&mut self) -> ChorusResult<String> {
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/pomelo-suggestions",
             self.belongs_to.read().unwrap().urls.api
         ));
@@ -311,7 +315,8 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#get-pomelo-eligibility>
     pub async fn get_pomelo_eligibility(&mut self, username: &String) -> ChorusResult<bool> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/pomelo-attempt",
                 self.belongs_to.read().unwrap().urls.api
@@ -351,7 +356,8 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#create-pomelo-migration>
     pub async fn create_pomelo_migration(&mut self, username: &String) -> ChorusResult<()> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/pomelo",
                 self.belongs_to.read().unwrap().urls.api
@@ -389,7 +395,8 @@ impl ChorusUser {
         &mut self,
         query_parameters: GetRecentMentionsSchema,
     ) -> ChorusResult<Vec<crate::types::Message>> {
-        let request = Client::new()
+        let request = self
+            .http_client()
             .get(format!(
                 "{}/users/@me/mentions",
                 self.belongs_to.read().unwrap().urls.api
@@ -417,7 +424,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#delete-recent-mention>
     pub async fn delete_recent_mention(&mut self, message_id: Snowflake) -> ChorusResult<()> {
-        let request = Client::new().delete(format!(
+        let request = self.http_client().delete(format!(
             "{}/users/@me/mentions/{}",
             self.belongs_to.read().unwrap().urls.api,
             message_id
@@ -442,7 +449,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#get-user-harvest>
     pub async fn get_harvest(&mut self) -> ChorusResult<Option<Harvest>> {
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/harvest",
             self.belongs_to.read().unwrap().urls.api,
         ));
@@ -513,7 +520,8 @@ impl ChorusUser {
             }
         };
 
-        let request = Client::new()
+        let request = self
+            .http_client()
             .post(format!(
                 "{}/users/@me/harvest",
                 self.belongs_to.read().unwrap().urls.api,
@@ -537,7 +545,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#get-user-notes>
     pub async fn get_user_notes(&mut self) -> ChorusResult<HashMap<Snowflake, String>> {
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/notes",
             self.belongs_to.read().unwrap().urls.api,
         ));
@@ -589,7 +597,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#get-user-affinities>
     pub async fn get_user_affinities(&mut self) -> ChorusResult<UserAffinities> {
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/affinities/users",
             self.belongs_to.read().unwrap().urls.api,
         ));
@@ -608,7 +616,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#get-guild-affinities>
     pub async fn get_guild_affinities(&mut self) -> ChorusResult<GuildAffinities> {
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/affinities/guilds",
             self.belongs_to.read().unwrap().urls.api,
         ));
@@ -633,7 +641,7 @@ impl ChorusUser {
     /// # Reference
     /// See <https://docs.discord.sex/resources/user#get-user-premium-usage>
     pub async fn get_premium_usage(&mut self) -> ChorusResult<PremiumUsage> {
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/premium-usage",
             self.belongs_to.read().unwrap().urls.api,
         ));
@@ -655,7 +663,7 @@ impl ChorusUser {
     /// # Notes
     /// As of 2024/08/18, Spacebar does not yet implement this endpoint.
     pub async fn get_burst_credits(&mut self) -> ChorusResult<BurstCreditsInfo> {
-        let request = Client::new().get(format!(
+        let request = self.http_client().get(format!(
             "{}/users/@me/burst-credits",
             self.belongs_to.read().unwrap().urls.api,
         ));
@@ -678,7 +686,7 @@ impl User {
     pub async fn get_current(user: &mut ChorusUser) -> ChorusResult<User> {
         let url_api = user.belongs_to.read().unwrap().urls.api.clone();
         let url = format!("{}/users/@me", url_api);
-        let request = reqwest::Client::new().get(url);
+        let request = user.http_client().get(url);
         let chorus_request = ChorusRequest {
             request,
             limit_type: LimitType::Global,
@@ -694,7 +702,7 @@ impl User {
     pub async fn get(user: &mut ChorusUser, id: Snowflake) -> ChorusResult<PublicUser> {
         let url_api = user.belongs_to.read().unwrap().urls.api.clone();
         let url = format!("{}/users/{}", url_api, id);
-        let request = reqwest::Client::new().get(url);
+        let request = user.http_client().get(url);
         let chorus_request = ChorusRequest {
             request,
             limit_type: LimitType::Global,
@@ -730,7 +738,7 @@ impl User {
     ) -> ChorusResult<PublicUser> {
         let url_api = user.belongs_to.read().unwrap().urls.api.clone();
         let url = format!("{}/users/username/{username}", url_api);
-        let mut request = reqwest::Client::new().get(url);
+        let mut request = user.http_client().get(url);
 
         if let Some(some_discriminator) = discriminator {
             request = request.query(&[("discriminator", some_discriminator)]);
@@ -752,8 +760,9 @@ impl User {
     /// See <https://luna.gitlab.io/discord-unofficial-docs/docs/user_settings.html#get-usersmesettings>
     pub async fn get_settings(user: &mut ChorusUser) -> ChorusResult<UserSettings> {
         let url_api = user.belongs_to.read().unwrap().urls.api.clone();
-        let request: reqwest::RequestBuilder =
-            Client::new().get(format!("{}/users/@me/settings", url_api));
+        let request: reqwest::RequestBuilder = user
+            .http_client()
+            .get(format!("{}/users/@me/settings", url_api));
         let chorus_request = ChorusRequest {
             request,
             limit_type: LimitType::Global,
@@ -782,7 +791,8 @@ impl User {
         query_parameters: GetUserProfileSchema,
     ) -> ChorusResult<UserProfile> {
         let url_api = user.belongs_to.read().unwrap().urls.api.clone();
-        let request: reqwest::RequestBuilder = Client::new()
+        let request: reqwest::RequestBuilder = user
+            .http_client()
             .get(format!("{}/users/{}/profile", url_api, id))
             .query(&query_parameters);
 
@@ -807,7 +817,8 @@ impl User {
         schema: UserModifyProfileSchema,
     ) -> ChorusResult<UserProfileMetadata> {
         let url_api = user.belongs_to.read().unwrap().urls.api.clone();
-        let request: reqwest::RequestBuilder = Client::new()
+        let request: reqwest::RequestBuilder = user
+            .http_client()
             .patch(format!("{}/users/@me/profile", url_api))
             .json(&schema);
         let chorus_request = ChorusRequest {
@@ -831,7 +842,7 @@ impl User {
         user: &mut ChorusUser,
         target_user_id: Snowflake,
     ) -> ChorusResult<UserNote> {
-        let request = Client::new().get(format!(
+        let request = user.http_client().get(format!(
             "{}/users/@me/notes/{}",
             user.belongs_to.read().unwrap().urls.api,
             target_user_id
@@ -859,7 +870,8 @@ impl User {
     ) -> ChorusResult<()> {
         let schema = ModifyUserNoteSchema { note };
 
-        let request = Client::new()
+        let request = user
+            .http_client()
             .put(format!(
                 "{}/users/@me/notes/{}",
                 user.belongs_to.read().unwrap().urls.api,
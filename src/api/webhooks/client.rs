@@ -0,0 +1,389 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use http::header::CONTENT_DISPOSITION;
+use http::HeaderMap;
+use reqwest::{multipart, Client, RequestBuilder, Response};
+use serde::Serialize;
+
+use crate::errors::{ChorusError, ChorusResult};
+use crate::ratelimiter::{ChorusRequest, RatelimitBehaviour, RetryPolicy, RouteRatelimits};
+use crate::types::{
+    LimitType, Message, ModifyWebhookSchema, PartialDiscordFileAttachment, Snowflake, Webhook,
+    WebhookExecuteSchema, WebhookMessageModifySchema,
+};
+
+/// The message id which refers to the original response to an interaction
+const ORIGINAL_MESSAGE: &str = "@original";
+
+/// A client for the routes of a single webhook which are authenticated with its token.
+///
+/// Unlike the other routes, these don't need a logged in [ChorusUser](crate::instance::ChorusUser);
+/// knowing the webhook's id and token (or its URL) is enough.
+///
+/// Rate limits are respected as far as the server tells us about them in its response headers.
+///
+/// # Example
+/// ```no_run
+/// # async fn run() -> chorus::errors::ChorusResult<()> {
+/// use chorus::api::WebhookClient;
+/// use chorus::types::WebhookExecuteSchema;
+///
+/// let webhook = WebhookClient::from_url("https://discord.com/api/webhooks/1234/abcd")?;
+///
+/// let schema = WebhookExecuteSchema {
+///     content: Some("The build passed!".to_string()),
+///     ..Default::default()
+/// };
+/// webhook.execute(schema, None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebhookClient {
+    /// The api url of the instance, e. g. `https://discord.com/api`
+    pub api_url: String,
+    pub id: Snowflake,
+    pub token: String,
+    /// What to do when a request would exceed a rate limit
+    ///
+    /// Default: [RatelimitBehaviour::ReturnError]
+    pub ratelimit_behaviour: RatelimitBehaviour,
+    /// How requests are retried after transient failures
+    ///
+    /// Default: no retries, see [RetryPolicy]
+    pub retry_policy: RetryPolicy,
+    client: Client,
+    route_ratelimits: RouteRatelimits,
+}
+
+impl WebhookClient {
+    /// Creates a client for a webhook, given the instance's api url and the webhook's id and token.
+    pub fn new(api_url: &str, id: Snowflake, token: &str) -> WebhookClient {
+        WebhookClient {
+            api_url: api_url.trim_end_matches('/').to_string(),
+            id,
+            token: token.to_string(),
+            ratelimit_behaviour: RatelimitBehaviour::default(),
+            retry_policy: RetryPolicy::default(),
+            client: Client::new(),
+            route_ratelimits: RouteRatelimits::default(),
+        }
+    }
+
+    /// Sends the webhook's requests with the given HTTP client, e. g. an [Instance]'s
+    /// [client](crate::instance::Instance::client), instead of a new one.
+    ///
+    /// [Instance]: crate::instance::Instance
+    pub fn with_client(mut self, client: Client) -> WebhookClient {
+        self.client = client;
+        self
+    }
+
+    /// Retries the webhook's requests after transient failures according to the given policy,
+    /// e. g. an [Instance]'s [retry_policy](crate::instance::Instance::retry_policy).
+    ///
+    /// [Instance]: crate::instance::Instance
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> WebhookClient {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Creates a client from a webhook url, such as
+    /// `https://discord.com/api/webhooks/{webhook.id}/{webhook.token}`.
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the url is not a webhook url.
+    pub fn from_url(url: &str) -> ChorusResult<WebhookClient> {
+        let invalid = || ChorusError::InvalidArguments {
+            error: format!("{} is not a webhook url", url),
+        };
+
+        let url = url.split(['?', '#']).next().unwrap_or_default();
+        let (api_url, path) = url.rsplit_once("/webhooks/").ok_or_else(invalid)?;
+        let mut segments = path.trim_end_matches('/').split('/');
+
+        let id = segments
+            .next()
+            .and_then(|id| id.parse::<u64>().ok())
+            .ok_or_else(invalid)?;
+        let token = segments
+            .next()
+            .filter(|token| !token.is_empty())
+            .ok_or_else(invalid)?;
+        if segments.next().is_some() || api_url.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(WebhookClient::new(api_url, id.into(), token))
+    }
+
+    /// Returns the url of the webhook, including its token
+    pub fn url(&self) -> String {
+        format!("{}/webhooks/{}/{}", self.api_url, self.id, self.token)
+    }
+
+    /// Returns the rate limit buckets learned from the server's responses.
+    pub fn route_ratelimits(&self) -> &RouteRatelimits {
+        &self.route_ratelimits
+    }
+
+    /// Fetches the webhook.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#get-webhook-with-token>
+    pub async fn get(&self) -> ChorusResult<Webhook> {
+        let response = self.send(self.client.get(self.url())).await?;
+        ChorusRequest::deserialize_body::<Webhook>(response).await
+    }
+
+    /// Modifies the webhook, returning the updated webhook.
+    ///
+    /// The webhook can't be moved to another channel this way, so
+    /// [ModifyWebhookSchema::channel_id] must be [None].
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#modify-webhook-with-token>
+    pub async fn modify(&self, schema: ModifyWebhookSchema) -> ChorusResult<Webhook> {
+        let response = self
+            .send(self.client.patch(self.url()).json(&schema))
+            .await?;
+        ChorusRequest::deserialize_body::<Webhook>(response).await
+    }
+
+    /// Deletes the webhook.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#delete-webhook-with-token>
+    pub async fn delete(&self) -> ChorusResult<()> {
+        self.send(self.client.delete(self.url())).await?;
+        Ok(())
+    }
+
+    /// Posts a message with the webhook, returning the created message.
+    ///
+    /// If the webhook's channel is a forum channel, either `thread_id` or
+    /// [WebhookExecuteSchema::thread_name] has to be set.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#execute-webhook>
+    pub async fn execute(
+        &self,
+        schema: WebhookExecuteSchema,
+        thread_id: Option<Snowflake>,
+    ) -> ChorusResult<Message> {
+        let response = self.send_execute(schema, thread_id, true).await?;
+        ChorusRequest::deserialize_body::<Message>(response).await
+    }
+
+    /// Posts a message with the webhook, without waiting for the server to confirm the message
+    /// was created.
+    ///
+    /// See [WebhookClient::execute].
+    pub async fn execute_without_waiting(
+        &self,
+        schema: WebhookExecuteSchema,
+        thread_id: Option<Snowflake>,
+    ) -> ChorusResult<()> {
+        self.send_execute(schema, thread_id, false).await?;
+        Ok(())
+    }
+
+    async fn send_execute(
+        &self,
+        mut schema: WebhookExecuteSchema,
+        thread_id: Option<Snowflake>,
+        wait: bool,
+    ) -> ChorusResult<Response> {
+        let files = take_files(&mut schema.attachments);
+        let request = self
+            .client
+            .post(self.url())
+            .query(&[("wait", wait.to_string())]);
+        let request = with_thread_id(request, thread_id);
+        self.send(with_payload(request, &schema, files)?).await
+    }
+
+    /// Fetches a message previously sent by the webhook.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#get-webhook-message>
+    pub async fn get_message(
+        &self,
+        message_id: Snowflake,
+        thread_id: Option<Snowflake>,
+    ) -> ChorusResult<Message> {
+        let request = self.client.get(self.message_url(message_id));
+        let response = self.send(with_thread_id(request, thread_id)).await?;
+        ChorusRequest::deserialize_body::<Message>(response).await
+    }
+
+    /// Edits a message previously sent by the webhook, returning the updated message.
+    ///
+    /// Attachments with content are uploaded as new files, attachments without content refer to
+    /// already uploaded attachments which should be kept.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#edit-webhook-message>
+    pub async fn edit_message(
+        &self,
+        message_id: Snowflake,
+        mut schema: WebhookMessageModifySchema,
+        thread_id: Option<Snowflake>,
+    ) -> ChorusResult<Message> {
+        let files = take_files(&mut schema.attachments);
+        let request = self.client.patch(self.message_url(message_id));
+        let request = with_thread_id(request, thread_id);
+        let response = self.send(with_payload(request, &schema, files)?).await?;
+        ChorusRequest::deserialize_body::<Message>(response).await
+    }
+
+    /// Deletes a message previously sent by the webhook.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#delete-webhook-message>
+    pub async fn delete_message(
+        &self,
+        message_id: Snowflake,
+        thread_id: Option<Snowflake>,
+    ) -> ChorusResult<()> {
+        let request = self.client.delete(self.message_url(message_id));
+        self.send(with_thread_id(request, thread_id)).await?;
+        Ok(())
+    }
+
+    /// Fetches the original response to an interaction.
+    ///
+    /// Only works for the webhook of an interaction, see
+    /// [Interaction::followup](crate::types::Interaction::followup).
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/receiving-and-responding#get-original-interaction-response>
+    pub async fn get_original_message(&self) -> ChorusResult<Message> {
+        let request = self.client.get(self.message_url(ORIGINAL_MESSAGE));
+        let response = self.send(request).await?;
+        ChorusRequest::deserialize_body::<Message>(response).await
+    }
+
+    /// Edits the original response to an interaction, returning the updated message.
+    ///
+    /// This is also how the response to a deferred interaction is sent.
+    ///
+    /// Only works for the webhook of an interaction, see
+    /// [Interaction::followup](crate::types::Interaction::followup).
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/receiving-and-responding#edit-original-interaction-response>
+    pub async fn edit_original_message(
+        &self,
+        mut schema: WebhookMessageModifySchema,
+    ) -> ChorusResult<Message> {
+        let files = take_files(&mut schema.attachments);
+        let request = self.client.patch(self.message_url(ORIGINAL_MESSAGE));
+        let response = self.send(with_payload(request, &schema, files)?).await?;
+        ChorusRequest::deserialize_body::<Message>(response).await
+    }
+
+    /// Deletes the original response to an interaction.
+    ///
+    /// Only works for the webhook of an interaction, see
+    /// [Interaction::followup](crate::types::Interaction::followup).
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/interactions/receiving-and-responding#delete-original-interaction-response>
+    pub async fn delete_original_message(&self) -> ChorusResult<()> {
+        let request = self.client.delete(self.message_url(ORIGINAL_MESSAGE));
+        self.send(request).await?;
+        Ok(())
+    }
+
+    fn message_url(&self, message_id: impl std::fmt::Display) -> String {
+        format!("{}/messages/{}", self.url(), message_id)
+    }
+
+    async fn send(&self, request: RequestBuilder) -> ChorusResult<Response> {
+        ChorusRequest {
+            request,
+            limit_type: LimitType::Webhook(self.id),
+        }
+        .send_anonymous_request(
+            &self.client,
+            &self.route_ratelimits,
+            self.ratelimit_behaviour,
+            &self.retry_policy,
+        )
+        .await
+    }
+}
+
+/// A file to upload with a message: its name and content
+pub(crate) type WebhookFile = (String, Vec<u8>);
+
+/// Takes the content out of all attachments which have some, numbering them in the order they
+/// will be uploaded in.
+///
+/// Attachments without content are left untouched, since they refer to already uploaded files.
+#[allow(clippy::useless_conversion)]
+pub(crate) fn take_files(
+    attachments: &mut Option<Vec<PartialDiscordFileAttachment>>,
+) -> Vec<WebhookFile> {
+    let mut files = Vec::new();
+    for attachment in attachments.iter_mut().flatten() {
+        if attachment.content.is_empty() {
+            continue;
+        }
+        attachment.id = Some((files.len() as u64).into());
+        files.push((
+            attachment.filename.clone(),
+            std::mem::take(&mut attachment.content),
+        ));
+    }
+    files
+}
+
+fn with_thread_id(request: RequestBuilder, thread_id: Option<Snowflake>) -> RequestBuilder {
+    match thread_id {
+        Some(thread_id) => request.query(&[("thread_id", thread_id.to_string())]),
+        None => request,
+    }
+}
+
+/// Adds the payload to the request, as multipart form data if there are files to upload.
+pub(crate) fn with_payload<T: Serialize>(
+    request: RequestBuilder,
+    payload: &T,
+    files: Vec<WebhookFile>,
+) -> ChorusResult<RequestBuilder> {
+    if files.is_empty() {
+        return Ok(request.json(payload));
+    }
+
+    let payload_json = serde_json::to_string(payload).map_err(|e| ChorusError::FormCreation {
+        error: e.to_string(),
+    })?;
+    let mut form = multipart::Form::new().part("payload_json", multipart::Part::text(payload_json));
+
+    for (index, (filename, content)) in files.into_iter().enumerate() {
+        let part_name = format!("files[{}]", index);
+        let content_disposition = format!(
+            "form-data; name=\"{}\"; filename=\"{}\"",
+            part_name, &filename
+        );
+        let mut header_map = HeaderMap::new();
+        header_map.insert(
+            CONTENT_DISPOSITION,
+            content_disposition
+                .parse()
+                .map_err(|_| ChorusError::FormCreation {
+                    error: format!("Invalid filename: {}", filename),
+                })?,
+        );
+
+        let part = multipart::Part::bytes(content)
+            .file_name(filename)
+            .headers(header_map);
+        form = form.part(part_name, part);
+    }
+
+    Ok(request.multipart(form))
+}
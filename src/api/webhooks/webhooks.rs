@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use reqwest::Client;
+
+use crate::errors::ChorusResult;
+use crate::instance::ChorusUser;
+use crate::ratelimiter::ChorusRequest;
+use crate::types::{CreateWebhookSchema, LimitType, ModifyWebhookSchema, Snowflake, Webhook};
+
+impl Webhook {
+    /// Creates a new incoming webhook in a channel.
+    ///
+    /// Requires the [MANAGE_WEBHOOKS](crate::types::PermissionFlags::MANAGE_WEBHOOKS) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#create-webhook>
+    pub async fn create(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+        schema: CreateWebhookSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<Webhook> {
+        ChorusRequest {
+            request: user
+                .http_client()
+                .post(format!(
+                    "{}/channels/{}/webhooks",
+                    user.belongs_to.read().unwrap().urls.api,
+                    channel_id
+                ))
+                .json(&schema),
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<Webhook>(user)
+        .await
+    }
+
+    /// Fetches a webhook by its id.
+    ///
+    /// Requires the [MANAGE_WEBHOOKS](crate::types::PermissionFlags::MANAGE_WEBHOOKS) permission,
+    /// unless the current user owns the webhook.
+    ///
+    /// To fetch a webhook with its token instead, see [WebhookClient::get](crate::api::WebhookClient::get).
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#get-webhook>
+    pub async fn get(user: &mut ChorusUser, webhook_id: Snowflake) -> ChorusResult<Webhook> {
+        ChorusRequest {
+            request: user.http_client().get(format!(
+                "{}/webhooks/{}",
+                user.belongs_to.read().unwrap().urls.api,
+                webhook_id
+            )),
+            limit_type: LimitType::Webhook(webhook_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Webhook>(user)
+        .await
+    }
+
+    /// Returns the webhooks of a channel.
+    ///
+    /// Requires the [MANAGE_WEBHOOKS](crate::types::PermissionFlags::MANAGE_WEBHOOKS) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#get-channel-webhooks>
+    pub async fn get_channel_webhooks(
+        user: &mut ChorusUser,
+        channel_id: Snowflake,
+    ) -> ChorusResult<Vec<Webhook>> {
+        ChorusRequest {
+            request: user.http_client().get(format!(
+                "{}/channels/{}/webhooks",
+                user.belongs_to.read().unwrap().urls.api,
+                channel_id
+            )),
+            limit_type: LimitType::Channel(channel_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<Webhook>>(user)
+        .await
+    }
+
+    /// Returns the webhooks of a guild.
+    ///
+    /// Requires the [MANAGE_WEBHOOKS](crate::types::PermissionFlags::MANAGE_WEBHOOKS) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#get-guild-webhooks>
+    pub async fn get_guild_webhooks(
+        user: &mut ChorusUser,
+        guild_id: Snowflake,
+    ) -> ChorusResult<Vec<Webhook>> {
+        ChorusRequest {
+            request: user.http_client().get(format!(
+                "{}/guilds/{}/webhooks",
+                user.belongs_to.read().unwrap().urls.api,
+                guild_id
+            )),
+            limit_type: LimitType::Guild(guild_id),
+        }
+        .with_headers_for(user)
+        .deserialize_response::<Vec<Webhook>>(user)
+        .await
+    }
+
+    /// Modifies a webhook, returning the updated webhook.
+    ///
+    /// Requires the [MANAGE_WEBHOOKS](crate::types::PermissionFlags::MANAGE_WEBHOOKS) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#modify-webhook>
+    pub async fn modify(
+        user: &mut ChorusUser,
+        webhook_id: Snowflake,
+        schema: ModifyWebhookSchema,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<Webhook> {
+        ChorusRequest {
+            request: user
+                .http_client()
+                .patch(format!(
+                    "{}/webhooks/{}",
+                    user.belongs_to.read().unwrap().urls.api,
+                    webhook_id
+                ))
+                .json(&schema),
+            limit_type: LimitType::Webhook(webhook_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .deserialize_response::<Webhook>(user)
+        .await
+    }
+
+    /// Deletes a webhook.
+    ///
+    /// Requires the [MANAGE_WEBHOOKS](crate::types::PermissionFlags::MANAGE_WEBHOOKS) permission.
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/webhook#delete-webhook>
+    pub async fn delete(
+        user: &mut ChorusUser,
+        webhook_id: Snowflake,
+        audit_log_reason: Option<String>,
+    ) -> ChorusResult<()> {
+        ChorusRequest {
+            request: user.http_client().delete(format!(
+                "{}/webhooks/{}",
+                user.belongs_to.read().unwrap().urls.api,
+                webhook_id
+            )),
+            limit_type: LimitType::Webhook(webhook_id),
+        }
+        .with_maybe_audit_log_reason(audit_log_reason)
+        .with_headers_for(user)
+        .handle_request_as_result(user)
+        .await
+    }
+}
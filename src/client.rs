@@ -0,0 +1,317 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Managing accounts on many instances at once.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
+
+use futures_util::stream::{BoxStream, SelectAll};
+use futures_util::{Stream, StreamExt};
+
+use crate::api::auth::SessionSnapshot;
+use crate::errors::{ChorusError, ChorusResult};
+use crate::gateway::{GatewayEvent, GatewayOptions};
+use crate::instance::{ChorusUser, Instance};
+use crate::types::{LoginSchema, Shared, Snowflake};
+use crate::UrlBundle;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Identifies an account in a [ChorusClient]: the root url of its instance and the user's id.
+///
+/// Ids are only unique within one instance, so both are needed.
+pub struct AccountKey {
+    /// The root url of the instance, see [UrlBundle::root]
+    pub instance: String,
+    pub user_id: Snowflake,
+}
+
+impl fmt::Display for AccountKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.user_id, self.instance)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A gateway event received by one of the accounts of a [ChorusClient]
+pub struct AccountEvent {
+    /// The account which received the event
+    pub account: AccountKey,
+    pub event: GatewayEvent,
+}
+
+/// The gateway events of all accounts of a [ChorusClient], merged into one
+/// [Stream](futures_util::Stream), see [ChorusClient::event_stream]
+pub struct AccountEventStream {
+    streams: SelectAll<BoxStream<'static, AccountEvent>>,
+}
+
+impl fmt::Debug for AccountEventStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccountEventStream")
+            .field("accounts", &self.streams.len())
+            .finish()
+    }
+}
+
+impl Stream for AccountEventStream {
+    type Item = AccountEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.streams.poll_next_unpin(cx)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// A registry of many [Instance]s and the accounts logged in on them.
+///
+/// Each instance is only kept once, and shared between all of its accounts; accounts are keyed
+/// by their [AccountKey].
+///
+/// # Example
+/// ```no_run
+/// # async fn example() -> chorus::errors::ChorusResult<()> {
+/// use chorus::client::ChorusClient;
+/// use chorus::types::LoginSchema;
+///
+/// let mut client = ChorusClient::new();
+/// for root in ["https://spacebar.chat", "https://example.com"] {
+///     client.get_or_add_instance(root, None).await?;
+///     client
+///         .login_account(
+///             root,
+///             LoginSchema {
+///                 login: "user@example.com".to_string(),
+///                 password: "Correct-Horse-Battery-Staple".to_string(),
+///                 ..Default::default()
+///             },
+///         )
+///         .await?;
+/// }
+///
+/// for (key, _) in client.accounts() {
+///     println!("Logged in as {}", key);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ChorusClient {
+    instances: HashMap<String, Shared<Instance>>,
+    accounts: BTreeMap<AccountKey, ChorusUser>,
+}
+
+impl ChorusClient {
+    /// Creates a client without any instances or accounts.
+    pub fn new() -> ChorusClient {
+        ChorusClient::default()
+    }
+
+    /// Adds an instance, keyed by its root url, and returns the shared instance.
+    ///
+    /// If an instance with the same root url is already known, it is kept and returned instead.
+    pub fn add_instance(&mut self, instance: Instance) -> Shared<Instance> {
+        self.instances
+            .entry(instance.urls.root.clone())
+            .or_insert_with(|| Arc::new(RwLock::new(instance)))
+            .clone()
+    }
+
+    /// Returns the instance with the given root url, or connects to it with [Instance::new] and
+    /// adds it if it is not known yet.
+    pub async fn get_or_add_instance(
+        &mut self,
+        root_url: &str,
+        options: Option<GatewayOptions>,
+    ) -> ChorusResult<Shared<Instance>> {
+        if let Some(instance) = self.instance(root_url) {
+            return Ok(instance);
+        }
+
+        let instance = Instance::new(root_url, options).await?;
+        Ok(self.add_instance(instance))
+    }
+
+    /// Returns the instance with the given root url, if it is known
+    pub fn instance(&self, root_url: &str) -> Option<Shared<Instance>> {
+        self.instances.get(&UrlBundle::parse_url(root_url)).cloned()
+    }
+
+    /// Returns all known instances
+    pub fn instances(&self) -> impl Iterator<Item = &Shared<Instance>> {
+        self.instances.values()
+    }
+
+    /// Removes an instance and all of its accounts, returning the accounts.
+    pub fn remove_instance(&mut self, root_url: &str) -> Vec<ChorusUser> {
+        let root_url = UrlBundle::parse_url(root_url);
+        self.instances.remove(&root_url);
+
+        let keys: Vec<AccountKey> = self
+            .accounts
+            .keys()
+            .filter(|key| key.instance == root_url)
+            .cloned()
+            .collect();
+        keys.iter()
+            .filter_map(|key| self.accounts.remove(key))
+            .collect()
+    }
+
+    /// Adds an account, replacing any account with the same [AccountKey], and returns its key.
+    ///
+    /// The account's instance is added as well; if it is already known, the account is switched
+    /// over to the known instance.
+    pub fn add_account(&mut self, mut user: ChorusUser) -> AccountKey {
+        let instance = user.belongs_to.read().unwrap().clone();
+        user.belongs_to = self.add_instance(instance);
+
+        let key = AccountKey {
+            instance: user.belongs_to.read().unwrap().urls.root.clone(),
+            user_id: user.object.read().unwrap().id,
+        };
+        self.accounts.insert(key.clone(), user);
+        key
+    }
+
+    /// Logs into an account on a known instance and adds it, see [Instance::login_account].
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the instance is not known, see
+    /// [ChorusClient::get_or_add_instance].
+    pub async fn login_account(
+        &mut self,
+        root_url: &str,
+        login_schema: LoginSchema,
+    ) -> ChorusResult<AccountKey> {
+        let mut instance = self.known_instance(root_url)?;
+        let user = instance.login_account(login_schema).await?;
+        Ok(self.add_account(user))
+    }
+
+    /// Logs into an account on a known instance using a token and adds it, see
+    /// [Instance::login_with_token].
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the instance is not known, see
+    /// [ChorusClient::get_or_add_instance].
+    pub async fn login_with_token(
+        &mut self,
+        root_url: &str,
+        token: &str,
+    ) -> ChorusResult<AccountKey> {
+        let mut instance = self.known_instance(root_url)?;
+        let user = instance.login_with_token(token).await?;
+        Ok(self.add_account(user))
+    }
+
+    /// Restores a saved session and adds the account, see [Instance::restore_session].
+    ///
+    /// If the snapshot's instance is not known yet, it is added as well.
+    pub async fn restore_session(&mut self, snapshot: SessionSnapshot) -> ChorusResult<AccountKey> {
+        let mut instance = match self.instance(&snapshot.urls.root) {
+            Some(instance) => instance.read().unwrap().clone(),
+            None => Instance::from_url_bundle(snapshot.urls.clone(), None).await?,
+        };
+        let user = instance.restore_session(snapshot).await?;
+        Ok(self.add_account(user))
+    }
+
+    /// Returns a copy of a known instance, to log in with
+    fn known_instance(&self, root_url: &str) -> ChorusResult<Instance> {
+        self.instance(root_url)
+            .map(|instance| instance.read().unwrap().clone())
+            .ok_or_else(|| ChorusError::InvalidArguments {
+                error: format!("Unknown instance {}", root_url),
+            })
+    }
+
+    /// Removes an account, returning it.
+    ///
+    /// The account's gateway connection is left open; close it with
+    /// [ChorusUser::disconnect_gateway] if it is no longer needed.
+    pub fn remove_account(&mut self, key: &AccountKey) -> Option<ChorusUser> {
+        self.accounts.remove(key)
+    }
+
+    /// Returns an account
+    pub fn account(&self, key: &AccountKey) -> Option<&ChorusUser> {
+        self.accounts.get(key)
+    }
+
+    /// Returns an account, to make requests with
+    pub fn account_mut(&mut self, key: &AccountKey) -> Option<&mut ChorusUser> {
+        self.accounts.get_mut(key)
+    }
+
+    /// Returns all accounts, ordered by instance and user id
+    pub fn accounts(&self) -> impl Iterator<Item = (&AccountKey, &ChorusUser)> {
+        self.accounts.iter()
+    }
+
+    /// Returns the accounts on the instance with the given root url
+    pub fn accounts_on<'a>(
+        &'a self,
+        root_url: &str,
+    ) -> impl Iterator<Item = (&'a AccountKey, &'a ChorusUser)> {
+        let root_url = UrlBundle::parse_url(root_url);
+        self.accounts
+            .iter()
+            .filter(move |(key, _)| key.instance == root_url)
+    }
+
+    /// Returns the accounts on an instance which can see a guild, according to their
+    /// [gateway caches](crate::gateway::GatewayHandle::cache).
+    ///
+    /// Accounts which are not connected to the gateway are not included.
+    pub fn accounts_in_guild(&self, root_url: &str, guild_id: Snowflake) -> Vec<&AccountKey> {
+        self.accounts_on(root_url)
+            .filter(|(_, user)| {
+                user.gateway
+                    .as_ref()
+                    .is_some_and(|gateway| gateway.cache().guild_ids().contains(&guild_id))
+            })
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Returns the accounts on an instance which can see a channel, private channel or thread,
+    /// according to their [gateway caches](crate::gateway::GatewayHandle::cache).
+    ///
+    /// Accounts which are not connected to the gateway are not included.
+    pub fn accounts_in_channel(&self, root_url: &str, channel_id: Snowflake) -> Vec<&AccountKey> {
+        self.accounts_on(root_url)
+            .filter(|(_, user)| {
+                user.gateway
+                    .as_ref()
+                    .is_some_and(|gateway| gateway.cache().channel(channel_id).is_some())
+            })
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Returns a [Stream](futures_util::Stream) of the gateway events of all accounts, tagged
+    /// with the account which received them.
+    ///
+    /// Only accounts which are connected to the gateway when this is called are included; the
+    /// stream ends once all of their connections are closed, or right away if there are none.
+    pub async fn event_stream(&self) -> AccountEventStream {
+        let mut streams = SelectAll::new();
+
+        for (key, user) in self.accounts.iter() {
+            let Some(gateway) = &user.gateway else {
+                continue;
+            };
+
+            let account = key.clone();
+            let stream = gateway.event_stream().await.map(move |event| AccountEvent {
+                account: account.clone(),
+                event,
+            });
+            streams.push(stream.boxed());
+        }
+
+        AccountEventStream { streams }
+    }
+}
@@ -55,7 +55,15 @@ custom_error! {
      /// After verifying, the same request can be retried.
     MfaRequired {error: MfaRequiredSchema} = "Mfa verification is required to perform this action",
     /// The user's account is suspended
-    SuspendUser { token: String }  = "Your account has been suspended"
+    SuspendUser { token: String }  = "Your account has been suspended",
+    /// The action needs a gateway connection, but the user is not connected; see
+    /// [crate::instance::ChorusUser::connect_gateway].
+    GatewayNotConnected = "The user is not connected to the gateway",
+    /// The connection to the gateway could not be established.
+    GatewayConnectionFailed{error: String} = "Could not connect to the gateway: {error}",
+    /// A saved [SessionSnapshot](crate::api::auth::SessionSnapshot) could not be read, e. g.
+    /// because it was decrypted with the wrong key.
+    InvalidSessionSnapshot{error: String} = "Could not read the saved session: {error}"
 }
 
 impl From<reqwest::Error> for ChorusError {
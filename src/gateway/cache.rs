@@ -0,0 +1,1037 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! A client-side cache of the entities received over the gateway.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use async_trait::async_trait;
+use pubserve::Subscriber;
+
+use super::events::Events;
+use super::*;
+use crate::types::{
+    self, AutoModerationRule, Channel, Emoji, Guild, GuildCreateDataOption, GuildMember,
+    IntoShared, PresenceUpdate, PublicUser, RoleObject, ThreadMember, User, UserStatus, VoiceState,
+};
+
+/// A map which only keeps as many entries as its [CacheRetention] allows
+#[derive(Debug, Default)]
+struct RetainedMap<K, V> {
+    retention: CacheRetention,
+    entries: HashMap<K, V>,
+    /// Keys ordered from least to most recently inserted, only tracked for
+    /// [CacheRetention::Limited]
+    order: VecDeque<K>,
+}
+
+impl<K: Eq + Hash + Clone, V> RetainedMap<K, V> {
+    fn new(retention: CacheRetention) -> Self {
+        Self {
+            retention,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        match self.retention {
+            CacheRetention::None => {}
+            CacheRetention::Unlimited => {
+                self.entries.insert(key, value);
+            }
+            CacheRetention::Limited(limit) => {
+                if self.entries.insert(key.clone(), value).is_some() {
+                    self.order.retain(|existing| existing != &key);
+                }
+                self.order.push_back(key);
+
+                while self.entries.len() > limit {
+                    let Some(oldest) = self.order.pop_front() else {
+                        break;
+                    };
+                    self.entries.remove(&oldest);
+                }
+            }
+        }
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.entries.get_mut(key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let removed = self.entries.remove(key);
+        if removed.is_some() && matches!(self.retention, CacheRetention::Limited(_)) {
+            self.order.retain(|existing| existing != key);
+        }
+        removed
+    }
+
+    fn retain(&mut self, mut keep: impl FnMut(&K, &V) -> bool) {
+        self.entries.retain(|key, value| keep(key, value));
+        if matches!(self.retention, CacheRetention::Limited(_)) {
+            let entries = &self.entries;
+            self.order.retain(|key| entries.contains_key(key));
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter()
+    }
+
+    fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.values()
+    }
+
+    fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.entries.values_mut()
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+struct CacheStore {
+    /// The user this session belongs to
+    current_user: Option<User>,
+    /// The order of guilds in the last [types::GatewayReady], which
+    /// [types::GatewayReadySupplemental]'s merged fields refer to
+    ready_guilds: Vec<Snowflake>,
+    /// Guilds, without their channels, roles, emojis and voice states, which are stored separately
+    guilds: RetainedMap<Snowflake, Guild>,
+    channels: RetainedMap<Snowflake, Channel>,
+    /// Roles with the id of their guild
+    roles: RetainedMap<Snowflake, (Snowflake, RoleObject)>,
+    /// Members, keyed by guild and user id
+    members: RetainedMap<(Snowflake, Snowflake), GuildMember>,
+    users: RetainedMap<Snowflake, PublicUser>,
+    /// Emojis with the id of their guild
+    emojis: RetainedMap<Snowflake, (Snowflake, Emoji)>,
+    /// Presences, keyed by guild (or [None] for friends) and user id
+    presences: RetainedMap<(Option<Snowflake>, Snowflake), PresenceUpdate>,
+    /// Voice states, keyed by guild (or [None] for private calls) and user id
+    voice_states: RetainedMap<(Option<Snowflake>, Snowflake), VoiceState>,
+    auto_moderation_rules: RetainedMap<Snowflake, AutoModerationRule>,
+    /// Thread members, keyed by thread and user id
+    thread_members: RetainedMap<(Snowflake, Snowflake), ThreadMember>,
+}
+
+impl CacheStore {
+    fn new(options: GatewayCacheOptions) -> Self {
+        Self {
+            current_user: None,
+            ready_guilds: Vec::new(),
+            guilds: RetainedMap::new(options.guilds),
+            channels: RetainedMap::new(options.channels),
+            roles: RetainedMap::new(options.roles),
+            members: RetainedMap::new(options.members),
+            users: RetainedMap::new(options.users),
+            emojis: RetainedMap::new(options.emojis),
+            presences: RetainedMap::new(options.presences),
+            voice_states: RetainedMap::new(options.voice_states),
+            auto_moderation_rules: RetainedMap::new(options.auto_moderation_rules),
+            thread_members: RetainedMap::new(options.thread_members),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.current_user = None;
+        self.ready_guilds.clear();
+        self.guilds.clear();
+        self.channels.clear();
+        self.roles.clear();
+        self.members.clear();
+        self.users.clear();
+        self.emojis.clear();
+        self.presences.clear();
+        self.voice_states.clear();
+        self.auto_moderation_rules.clear();
+        self.thread_members.clear();
+    }
+
+    /// Caches a guild, splitting off its channels, roles, emojis and voice states
+    fn insert_guild(&mut self, mut guild: Guild) {
+        for channel in std::mem::take(&mut guild.channels) {
+            let mut channel = channel.read().unwrap().clone();
+            channel.guild_id = Some(guild.id);
+            self.insert_channel(channel);
+        }
+
+        for role in std::mem::take(&mut guild.roles) {
+            let role = role.read().unwrap().clone();
+            self.roles.insert(role.id, (guild.id, role));
+        }
+
+        self.replace_emojis(
+            guild.id,
+            std::mem::take(&mut guild.emojis)
+                .into_iter()
+                .map(|emoji| emoji.read().unwrap().clone())
+                .collect(),
+        );
+
+        for voice_state in std::mem::take(&mut guild.voice_states) {
+            let mut voice_state = voice_state.read().unwrap().clone();
+            voice_state.guild_id = Some(guild.id);
+            self.insert_voice_state(voice_state);
+        }
+
+        self.guilds.insert(guild.id, guild);
+    }
+
+    /// Removes a guild and everything which belongs to it
+    fn remove_guild(&mut self, guild_id: Snowflake) {
+        self.guilds.remove(&guild_id);
+        self.channels
+            .retain(|_, channel| channel.guild_id != Some(guild_id));
+        self.roles.retain(|_, (guild, _)| *guild != guild_id);
+        self.members.retain(|(guild, _), _| *guild != guild_id);
+        self.emojis.retain(|_, (guild, _)| *guild != guild_id);
+        self.presences
+            .retain(|(guild, _), _| *guild != Some(guild_id));
+        self.voice_states
+            .retain(|(guild, _), _| *guild != Some(guild_id));
+        self.auto_moderation_rules
+            .retain(|_, rule| rule.guild_id != guild_id);
+        let channels = &self.channels;
+        self.thread_members
+            .retain(|(thread, _), _| channels.get(thread).is_some());
+    }
+
+    fn insert_channel(&mut self, channel: Channel) {
+        self.channels.insert(channel.id, channel);
+    }
+
+    /// Caches a thread along with the current user's thread member, if it is included
+    fn insert_thread(&mut self, mut thread: Channel) {
+        if let Some(member) = thread.member.clone() {
+            let user_id = member
+                .user_id
+                .or(self.current_user.as_ref().map(|user| user.id));
+            if let Some(user_id) = user_id {
+                self.insert_thread_member(thread.id, user_id, member);
+            }
+        }
+        // The thread member is only meaningful for the current user, and stored separately
+        thread.member = None;
+        self.insert_channel(thread);
+    }
+
+    fn insert_thread_member(
+        &mut self,
+        thread_id: Snowflake,
+        user_id: Snowflake,
+        mut member: ThreadMember,
+    ) {
+        member.id = Some(thread_id);
+        member.user_id = Some(user_id);
+        self.thread_members.insert((thread_id, user_id), member);
+    }
+
+    /// Removes a thread and its members
+    fn remove_thread(&mut self, thread_id: Snowflake) {
+        self.channels.remove(&thread_id);
+        self.thread_members
+            .retain(|(thread, _), _| *thread != thread_id);
+    }
+
+    fn insert_member(&mut self, guild_id: Snowflake, member: GuildMember) {
+        // Members without a user object can't be keyed
+        let Some(user) = member
+            .user
+            .as_ref()
+            .map(|user| user.read().unwrap().clone())
+        else {
+            return;
+        };
+
+        self.members.insert((guild_id, user.id), member);
+        self.insert_user(user);
+    }
+
+    fn insert_user(&mut self, user: PublicUser) {
+        if let Some(current_user) = self.current_user.as_mut() {
+            if current_user.id == user.id {
+                update_current_user(current_user, &user);
+            }
+        }
+
+        self.users.insert(user.id, user);
+    }
+
+    fn insert_presence(&mut self, presence: PresenceUpdate) {
+        self.presences
+            .insert((presence.guild_id, presence.user.id), presence);
+    }
+
+    fn insert_voice_state(&mut self, voice_state: VoiceState) {
+        let key = (voice_state.guild_id, voice_state.user_id);
+
+        // A voice state without a channel means the user left
+        if voice_state.channel_id.is_none() {
+            self.voice_states.remove(&key);
+        } else {
+            self.voice_states.insert(key, voice_state);
+        }
+    }
+
+    /// Replaces all emojis of a guild
+    fn replace_emojis(&mut self, guild_id: Snowflake, emojis: Vec<Emoji>) {
+        self.emojis.retain(|_, (guild, _)| *guild != guild_id);
+        for emoji in emojis {
+            self.emojis.insert(emoji.id, (guild_id, emoji));
+        }
+    }
+}
+
+/// Applies the fields of a partial user to the full current user object
+fn update_current_user(current_user: &mut User, user: &PublicUser) {
+    if let Some(username) = &user.username {
+        current_user.username = username.clone();
+    }
+    if let Some(discriminator) = &user.discriminator {
+        current_user.discriminator = discriminator.clone();
+    }
+    current_user.avatar = user.avatar.clone();
+    // Not Copy with the sqlx feature
+    current_user.accent_color.clone_from(&user.accent_color);
+    current_user.banner = user.banner.clone();
+    current_user.theme_colors = user.theme_colors;
+    current_user.pronouns = user.pronouns.clone();
+    current_user.bio = user.bio.clone();
+    if user.premium_type.is_some() {
+        current_user.premium_type = user.premium_type;
+    }
+    if user.public_flags.is_some() {
+        current_user.public_flags = user.public_flags;
+    }
+}
+
+/// Converts one of READY's merged presences into a [PresenceUpdate]
+fn merged_presence(
+    user_id: Snowflake,
+    guild_id: Option<Snowflake>,
+    status: &str,
+    activities: Vec<types::Activity>,
+    client_status: types::ClientStatusObject,
+) -> PresenceUpdate {
+    PresenceUpdate {
+        user: PublicUser {
+            id: user_id,
+            ..Default::default()
+        },
+        guild_id,
+        status: serde_json::from_value(serde_json::Value::String(status.to_string()))
+            .unwrap_or(UserStatus::Unknown),
+        activities,
+        client_status,
+    }
+}
+
+/// A client-side cache of the guilds, channels, roles, members, users, emojis, presences,
+/// voice states, auto moderation rules and thread members received over a gateway connection.
+///
+/// The cache is seeded from [types::GatewayReady] and [types::GatewayReadySupplemental], and kept
+/// up to date by every following dispatch. What it keeps can be configured with
+/// [GatewayCacheOptions], which are part of the [GatewayOptions].
+///
+/// Cloning the cache is cheap; all clones refer to the same data.
+///
+/// # Example
+/// ```no_run
+/// # async fn run(gateway: chorus::gateway::GatewayHandle, guild_id: chorus::types::Snowflake) {
+/// let cache = gateway.cache();
+///
+/// if let Some(guild) = cache.guild(guild_id) {
+///     println!("{:?} has {} members", guild.name, cache.members_of(guild_id).len());
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct GatewayCache {
+    options: GatewayCacheOptions,
+    store: Arc<RwLock<CacheStore>>,
+}
+
+impl Default for GatewayCache {
+    fn default() -> Self {
+        Self::new(GatewayCacheOptions::default())
+    }
+}
+
+impl GatewayCache {
+    /// Creates a new, empty cache
+    pub fn new(options: GatewayCacheOptions) -> Self {
+        Self {
+            options,
+            store: Arc::new(RwLock::new(CacheStore::new(options))),
+        }
+    }
+
+    /// Returns whether the cache is enabled at all
+    pub fn is_enabled(&self) -> bool {
+        self.options.enabled
+    }
+
+    /// Returns the options the cache was created with
+    pub fn options(&self) -> GatewayCacheOptions {
+        self.options
+    }
+
+    /// Removes everything from the cache
+    pub fn clear(&self) {
+        self.store.write().unwrap().clear();
+    }
+
+    /// Returns the user this session belongs to
+    pub fn current_user(&self) -> Option<User> {
+        self.store.read().unwrap().current_user.clone()
+    }
+
+    /// Returns a guild, including its cached channels, roles, emojis and voice states
+    pub fn guild(&self, id: Snowflake) -> Option<Guild> {
+        let mut guild = self.store.read().unwrap().guilds.get(&id)?.clone();
+
+        guild.channels = self
+            .channels_of(id)
+            .into_iter()
+            .map(IntoShared::into_shared)
+            .collect();
+        guild.roles = self
+            .roles_of(id)
+            .into_iter()
+            .map(IntoShared::into_shared)
+            .collect();
+        guild.emojis = self
+            .emojis_of(id)
+            .into_iter()
+            .map(IntoShared::into_shared)
+            .collect();
+        guild.voice_states = self
+            .voice_states_of(id)
+            .into_iter()
+            .map(IntoShared::into_shared)
+            .collect();
+
+        Some(guild)
+    }
+
+    /// Returns the ids of all cached guilds
+    pub fn guild_ids(&self) -> Vec<Snowflake> {
+        self.store
+            .read()
+            .unwrap()
+            .guilds
+            .values()
+            .map(|guild| guild.id)
+            .collect()
+    }
+
+    /// Returns a channel, thread or private channel
+    pub fn channel(&self, id: Snowflake) -> Option<Channel> {
+        self.store.read().unwrap().channels.get(&id).cloned()
+    }
+
+    /// Returns all cached channels and threads of a guild
+    pub fn channels_of(&self, guild_id: Snowflake) -> Vec<Channel> {
+        self.store
+            .read()
+            .unwrap()
+            .channels
+            .values()
+            .filter(|channel| channel.guild_id == Some(guild_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns all cached DMs and group DMs
+    pub fn private_channels(&self) -> Vec<Channel> {
+        self.store
+            .read()
+            .unwrap()
+            .channels
+            .values()
+            .filter(|channel| channel.guild_id.is_none())
+            .cloned()
+            .collect()
+    }
+
+    /// Returns a role
+    pub fn role(&self, id: Snowflake) -> Option<RoleObject> {
+        self.store
+            .read()
+            .unwrap()
+            .roles
+            .get(&id)
+            .map(|(_, role)| role.clone())
+    }
+
+    /// Returns all cached roles of a guild
+    pub fn roles_of(&self, guild_id: Snowflake) -> Vec<RoleObject> {
+        self.store
+            .read()
+            .unwrap()
+            .roles
+            .values()
+            .filter(|(guild, _)| *guild == guild_id)
+            .map(|(_, role)| role.clone())
+            .collect()
+    }
+
+    /// Returns a user's membership in a guild
+    pub fn member(&self, guild_id: Snowflake, user_id: Snowflake) -> Option<GuildMember> {
+        self.store
+            .read()
+            .unwrap()
+            .members
+            .get(&(guild_id, user_id))
+            .cloned()
+    }
+
+    /// Returns all cached members of a guild
+    pub fn members_of(&self, guild_id: Snowflake) -> Vec<GuildMember> {
+        self.store
+            .read()
+            .unwrap()
+            .members
+            .iter()
+            .filter(|((guild, _), _)| *guild == guild_id)
+            .map(|(_, member)| member.clone())
+            .collect()
+    }
+
+    /// Returns a user
+    pub fn user(&self, id: Snowflake) -> Option<PublicUser> {
+        self.store.read().unwrap().users.get(&id).cloned()
+    }
+
+    /// Returns an emoji
+    pub fn emoji(&self, id: Snowflake) -> Option<Emoji> {
+        self.store
+            .read()
+            .unwrap()
+            .emojis
+            .get(&id)
+            .map(|(_, emoji)| emoji.clone())
+    }
+
+    /// Returns all cached emojis of a guild
+    pub fn emojis_of(&self, guild_id: Snowflake) -> Vec<Emoji> {
+        self.store
+            .read()
+            .unwrap()
+            .emojis
+            .values()
+            .filter(|(guild, _)| *guild == guild_id)
+            .map(|(_, emoji)| emoji.clone())
+            .collect()
+    }
+
+    /// Returns a user's presence in a guild, or their friend presence if `guild_id` is [None]
+    pub fn presence(
+        &self,
+        guild_id: Option<Snowflake>,
+        user_id: Snowflake,
+    ) -> Option<PresenceUpdate> {
+        self.store
+            .read()
+            .unwrap()
+            .presences
+            .get(&(guild_id, user_id))
+            .cloned()
+    }
+
+    /// Returns a user's voice state in a guild, or in a private call if `guild_id` is [None]
+    pub fn voice_state(
+        &self,
+        guild_id: Option<Snowflake>,
+        user_id: Snowflake,
+    ) -> Option<VoiceState> {
+        self.store
+            .read()
+            .unwrap()
+            .voice_states
+            .get(&(guild_id, user_id))
+            .cloned()
+    }
+
+    /// Returns the voice states of all users connected to a guild's voice channels
+    pub fn voice_states_of(&self, guild_id: Snowflake) -> Vec<VoiceState> {
+        self.store
+            .read()
+            .unwrap()
+            .voice_states
+            .values()
+            .filter(|voice_state| voice_state.guild_id == Some(guild_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns an auto moderation rule.
+    ///
+    /// Rules are only cached once they were created or updated during this session, so this can
+    /// be used to look up the rule of an [types::AutoModerationActionExecution].
+    pub fn auto_moderation_rule(&self, id: Snowflake) -> Option<AutoModerationRule> {
+        self.store
+            .read()
+            .unwrap()
+            .auto_moderation_rules
+            .get(&id)
+            .cloned()
+    }
+
+    /// Returns all cached auto moderation rules of a guild
+    pub fn auto_moderation_rules_of(&self, guild_id: Snowflake) -> Vec<AutoModerationRule> {
+        self.store
+            .read()
+            .unwrap()
+            .auto_moderation_rules
+            .values()
+            .filter(|rule| rule.guild_id == guild_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns a member of a thread.
+    ///
+    /// The current user's thread members are cached for all threads they joined, other users'
+    /// only for threads whose member list was updated during this session.
+    pub fn thread_member(&self, thread_id: Snowflake, user_id: Snowflake) -> Option<ThreadMember> {
+        self.store
+            .read()
+            .unwrap()
+            .thread_members
+            .get(&(thread_id, user_id))
+            .cloned()
+    }
+
+    /// Returns all cached members of a thread
+    pub fn thread_members_of(&self, thread_id: Snowflake) -> Vec<ThreadMember> {
+        self.store
+            .read()
+            .unwrap()
+            .thread_members
+            .iter()
+            .filter(|((thread, _), _)| *thread == thread_id)
+            .map(|(_, member)| member.clone())
+            .collect()
+    }
+
+    /// Subscribes the cache to all events it needs to stay up to date.
+    ///
+    /// Does nothing if the cache is disabled.
+    pub(crate) fn subscribe(&self, events: &mut Events) {
+        if !self.options.enabled {
+            return;
+        }
+
+        let cache = Arc::new(self.clone());
+
+        events.session.ready.subscribe(cache.clone());
+        events.session.ready_supplemental.subscribe(cache.clone());
+        events.guild.create.subscribe(cache.clone());
+        events.guild.update.subscribe(cache.clone());
+        events.guild.delete.subscribe(cache.clone());
+        events.guild.emojis_update.subscribe(cache.clone());
+        events.guild.member_add.subscribe(cache.clone());
+        events.guild.member_update.subscribe(cache.clone());
+        events.guild.member_remove.subscribe(cache.clone());
+        events.guild.members_chunk.subscribe(cache.clone());
+        events.guild.role_create.subscribe(cache.clone());
+        events.guild.role_update.subscribe(cache.clone());
+        events.guild.role_delete.subscribe(cache.clone());
+        events.channel.create.subscribe(cache.clone());
+        events.channel.update.subscribe(cache.clone());
+        events.channel.delete.subscribe(cache.clone());
+        events.thread.create.subscribe(cache.clone());
+        events.thread.update.subscribe(cache.clone());
+        events.thread.delete.subscribe(cache.clone());
+        events.thread.list_sync.subscribe(cache.clone());
+        events.thread.member_update.subscribe(cache.clone());
+        events.thread.members_update.subscribe(cache.clone());
+        events.user.update.subscribe(cache.clone());
+        events.user.presence_update.subscribe(cache.clone());
+        events.voice.state_update.subscribe(cache.clone());
+        events.auto_moderation.rule_create.subscribe(cache.clone());
+        events.auto_moderation.rule_update.subscribe(cache.clone());
+        events.auto_moderation.rule_delete.subscribe(cache);
+    }
+
+    /// Applies an update to the cache
+    fn write(&self, update: impl FnOnce(&mut CacheStore)) {
+        update(&mut self.store.write().unwrap());
+    }
+}
+
+/// Implements [Subscriber] for an event, by applying it to the [CacheStore]
+macro_rules! cache_event {
+    ($event:ty, |$store:ident, $data:ident| $body:block) => {
+        #[async_trait]
+        impl Subscriber<$event> for GatewayCache {
+            async fn update(&self, $data: &$event) {
+                self.write(|$store| $body);
+            }
+        }
+    };
+}
+
+cache_event!(types::GatewayReady, |store, data| {
+    // A new session, everything we knew before may be outdated
+    store.clear();
+
+    store.current_user = Some(data.user.clone());
+    store.insert_user(data.user.clone().into_public_user());
+    store.ready_guilds = data.guilds.iter().map(|guild| guild.id).collect();
+
+    for user in data.users.iter() {
+        store.insert_user(user.clone().into_public_user());
+    }
+
+    for guild in data.guilds.iter() {
+        store.insert_guild(guild.clone());
+    }
+
+    for channel in data.private_channels.iter() {
+        store.insert_channel(channel.clone());
+    }
+
+    for (guild_id, members) in store
+        .ready_guilds
+        .clone()
+        .into_iter()
+        .zip(data.merged_members.iter().flatten())
+    {
+        for member in members {
+            store.insert_member(guild_id, member.clone());
+        }
+    }
+
+    for presence in data.presences.iter().flatten() {
+        store.insert_presence(presence.clone());
+    }
+
+    if let Some(merged_presences) = &data.merged_presences {
+        for friend in merged_presences.friends.iter() {
+            store.insert_presence(merged_presence(
+                friend.user_id,
+                None,
+                &friend.status,
+                friend.activities.clone(),
+                friend.client_status.clone(),
+            ));
+        }
+
+        for (guild_id, presences) in store
+            .ready_guilds
+            .clone()
+            .into_iter()
+            .zip(merged_presences.guilds.iter())
+        {
+            for presence in presences {
+                store.insert_presence(merged_presence(
+                    presence.user_id,
+                    Some(guild_id),
+                    &presence.status,
+                    presence.activities.clone(),
+                    presence.client_status.clone(),
+                ));
+            }
+        }
+    }
+});
+
+cache_event!(types::GatewayReadySupplemental, |store, data| {
+    let ready_guilds = store.ready_guilds.clone();
+
+    for (guild_id, members) in ready_guilds.iter().zip(data.merged_members.iter()) {
+        for member in members {
+            store.insert_member(*guild_id, member.clone());
+        }
+    }
+
+    for friend in data.merged_presences.friends.iter() {
+        store.insert_presence(merged_presence(
+            friend.user_id,
+            None,
+            &friend.status,
+            friend.activities.clone(),
+            friend.client_status.clone(),
+        ));
+    }
+
+    for (guild_id, presences) in ready_guilds.iter().zip(data.merged_presences.guilds.iter()) {
+        for presence in presences {
+            store.insert_presence(merged_presence(
+                presence.user_id,
+                Some(*guild_id),
+                &presence.status,
+                presence.activities.clone(),
+                presence.client_status.clone(),
+            ));
+        }
+    }
+
+    for channel in data.lazy_private_channels.iter() {
+        store.insert_channel(channel.clone());
+    }
+
+    for guild in data.guilds.iter() {
+        for voice_state in guild.voice_states.iter().flatten() {
+            let mut voice_state = voice_state.clone();
+            voice_state.guild_id = Some(guild.id);
+            store.insert_voice_state(voice_state);
+        }
+    }
+});
+
+cache_event!(types::GuildCreate, |store, data| {
+    // Unavailable guilds don't tell us anything we can cache
+    if let GuildCreateDataOption::Guild(guild) = &data.d {
+        store.insert_guild(guild.clone());
+    }
+});
+
+cache_event!(types::GuildUpdate, |store, data| {
+    // Guild updates don't include channels, roles etc., so we only replace the guild itself
+    let mut guild = data.guild.clone();
+    guild.channels.clear();
+    guild.roles.clear();
+    guild.emojis.clear();
+    guild.voice_states.clear();
+    store.guilds.insert(guild.id, guild);
+});
+
+cache_event!(types::GuildDelete, |store, data| {
+    // If the guild just became unavailable, it will come back, so we keep what we know
+    if data.guild.unavailable != Some(true) {
+        store.remove_guild(data.guild.id);
+    }
+});
+
+cache_event!(types::GuildEmojisUpdate, |store, data| {
+    store.replace_emojis(data.guild_id, data.emojis.clone());
+});
+
+cache_event!(types::GuildMemberAdd, |store, data| {
+    store.insert_member(data.guild_id, data.member.clone());
+});
+
+cache_event!(types::GuildMemberUpdate, |store, data| {
+    let key = (data.guild_id, data.user.id);
+
+    if let Some(member) = store.members.get_mut(&key) {
+        member.roles = data.roles.clone();
+        member.nick = data.nick.clone();
+        member.avatar = data.avatar.clone();
+        member.premium_since = data.premium_since;
+        member.communication_disabled_until = data.communication_disabled_until;
+        if let Some(joined_at) = data.joined_at {
+            member.joined_at = joined_at;
+        }
+        if let Some(deaf) = data.deaf {
+            member.deaf = deaf;
+        }
+        if let Some(mute) = data.mute {
+            member.mute = mute;
+        }
+        if data.pending.is_some() {
+            member.pending = data.pending;
+        }
+        member.user = Some(data.user.clone().into_shared());
+    } else {
+        let member = GuildMember {
+            user: Some(data.user.clone().into_shared()),
+            nick: data.nick.clone(),
+            avatar: data.avatar.clone(),
+            roles: data.roles.clone(),
+            joined_at: data.joined_at.unwrap_or_default(),
+            premium_since: data.premium_since,
+            deaf: data.deaf.unwrap_or_default(),
+            mute: data.mute.unwrap_or_default(),
+            pending: data.pending,
+            communication_disabled_until: data.communication_disabled_until,
+            ..Default::default()
+        };
+        store.insert_member(data.guild_id, member);
+    }
+
+    store.insert_user(data.user.clone());
+});
+
+cache_event!(types::GuildMemberRemove, |store, data| {
+    store.members.remove(&(data.guild_id, data.user.id));
+});
+
+cache_event!(types::GuildMembersChunk, |store, data| {
+    for member in data.members.iter() {
+        store.insert_member(data.guild_id, member.clone());
+    }
+
+    for presence in data.presences.iter().flatten() {
+        let mut presence = presence.clone();
+        presence.guild_id = Some(data.guild_id);
+        store.insert_presence(presence);
+    }
+});
+
+cache_event!(types::GuildRoleCreate, |store, data| {
+    store
+        .roles
+        .insert(data.role.id, (data.guild_id, data.role.clone()));
+});
+
+cache_event!(types::GuildRoleUpdate, |store, data| {
+    store
+        .roles
+        .insert(data.role.id, (data.guild_id, data.role.clone()));
+});
+
+cache_event!(types::GuildRoleDelete, |store, data| {
+    store.roles.remove(&data.role_id);
+
+    for member in store.members.values_mut() {
+        member.roles.retain(|role| *role != data.role_id);
+    }
+});
+
+cache_event!(types::ChannelCreate, |store, data| {
+    store.insert_channel(data.channel.clone());
+});
+
+cache_event!(types::ChannelUpdate, |store, data| {
+    store.insert_channel(data.channel.clone());
+});
+
+cache_event!(types::ChannelDelete, |store, data| {
+    store.channels.remove(&data.channel.id);
+});
+
+cache_event!(types::ThreadCreate, |store, data| {
+    store.insert_thread(data.thread.clone());
+});
+
+cache_event!(types::ThreadUpdate, |store, data| {
+    store.insert_thread(data.thread.clone());
+});
+
+cache_event!(types::ThreadDelete, |store, data| {
+    store.remove_thread(data.thread.id);
+});
+
+cache_event!(types::ThreadListSync, |store, data| {
+    let stale_threads: Vec<Snowflake> = store
+        .channels
+        .values()
+        .filter(|channel| {
+            channel.guild_id == Some(data.guild_id)
+                && channel.channel_type.is_thread()
+                && data.syncs_parent(channel.parent_id)
+        })
+        .map(|channel| channel.id)
+        .collect();
+    for thread_id in stale_threads {
+        store.remove_thread(thread_id);
+    }
+
+    for thread in &data.threads {
+        let mut thread = thread.clone();
+        thread.guild_id = Some(data.guild_id);
+        store.insert_thread(thread);
+    }
+
+    let Some(user_id) = store.current_user.as_ref().map(|user| user.id) else {
+        return;
+    };
+    for member in data.members.iter().flatten() {
+        if let Some(thread_id) = member.id {
+            store.insert_thread_member(thread_id, user_id, member.clone());
+        }
+    }
+});
+
+cache_event!(types::ThreadMemberUpdate, |store, data| {
+    let user_id = data
+        .member
+        .user_id
+        .or(store.current_user.as_ref().map(|user| user.id));
+    if let (Some(thread_id), Some(user_id)) = (data.member.id, user_id) {
+        store.insert_thread_member(thread_id, user_id, data.member.clone());
+    }
+});
+
+cache_event!(types::ThreadMembersUpdate, |store, data| {
+    for member in data.added_members.iter().flatten() {
+        if let Some(user_id) = member.user_id {
+            store.insert_thread_member(data.id, user_id, member.clone());
+        }
+    }
+    for user_id in data.removed_members.iter().flatten() {
+        store.thread_members.remove(&(data.id, *user_id));
+    }
+
+    if let Some(thread) = store.channels.get_mut(&data.id) {
+        thread.member_count = Some(data.member_count as i32);
+    }
+});
+
+cache_event!(types::UserUpdate, |store, data| {
+    store.insert_user(data.user.clone());
+});
+
+cache_event!(types::PresenceUpdate, |store, data| {
+    store.insert_presence(data.clone());
+});
+
+cache_event!(types::VoiceStateUpdate, |store, data| {
+    store.insert_voice_state(data.state.clone());
+});
+
+cache_event!(types::AutoModerationRuleCreate, |store, data| {
+    store
+        .auto_moderation_rules
+        .insert(data.rule.id, data.rule.clone());
+});
+
+cache_event!(types::AutoModerationRuleUpdate, |store, data| {
+    store
+        .auto_moderation_rules
+        .insert(data.rule.id, data.rule.clone());
+});
+
+cache_event!(types::AutoModerationRuleDelete, |store, data| {
+    store.auto_moderation_rules.remove(&data.rule.id);
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn limited_retention_evicts_oldest() {
+        let mut map = RetainedMap::new(CacheRetention::Limited(2));
+
+        map.insert(1, "one");
+        map.insert(2, "two");
+        // Updating moves an entry to the back
+        map.insert(1, "uno");
+        map.insert(3, "three");
+
+        assert_eq!(map.get(&1), Some(&"uno"));
+        assert_eq!(map.get(&2), None);
+        assert_eq!(map.get(&3), Some(&"three"));
+
+        let mut map = RetainedMap::new(CacheRetention::None);
+        map.insert(1, "one");
+        assert_eq!(map.get(&1), None);
+    }
+}
@@ -0,0 +1,425 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! An encoder and decoder for the Erlang External Term Format, used by the gateway when connecting
+//! with [GatewayEncoding::ETF](crate::gateway::GatewayEncoding::ETF).
+//!
+//! Terms are converted from and to [serde_json::Value]s, so that the rest of the gateway can keep
+//! working with the same data model regardless of the encoding:
+//!
+//! - binaries become strings (and strings are encoded as binaries)
+//! - the atoms `nil` and `null` become null, `true` and `false` become booleans, other atoms become
+//!   strings
+//! - lists and tuples become arrays
+//! - maps become objects, non-string keys are converted to strings
+//! - integers which don't fit into 64 bits become strings
+//!
+//! See <https://www.erlang.org/doc/apps/erts/erl_ext_dist.html> and
+//! <https://docs.discord.sex/topics/gateway#etf-encoding>
+
+use std::io::Read;
+
+use custom_error::custom_error;
+use serde_json::{Map, Number, Value};
+
+const FORMAT_VERSION: u8 = 131;
+
+const NEW_FLOAT_EXT: u8 = 70;
+const COMPRESSED: u8 = 80;
+const SMALL_INTEGER_EXT: u8 = 97;
+const INTEGER_EXT: u8 = 98;
+const FLOAT_EXT: u8 = 99;
+const ATOM_EXT: u8 = 100;
+const SMALL_TUPLE_EXT: u8 = 104;
+const LARGE_TUPLE_EXT: u8 = 105;
+const NIL_EXT: u8 = 106;
+const STRING_EXT: u8 = 107;
+const LIST_EXT: u8 = 108;
+const BINARY_EXT: u8 = 109;
+const SMALL_BIG_EXT: u8 = 110;
+const LARGE_BIG_EXT: u8 = 111;
+const SMALL_ATOM_EXT: u8 = 115;
+const MAP_EXT: u8 = 116;
+const ATOM_UTF8_EXT: u8 = 118;
+const SMALL_ATOM_UTF8_EXT: u8 = 119;
+
+/// How many bytes are preallocated at most for decompressing a compressed term, since its size
+/// is sent by the server
+const MAX_COMPRESSED_PREALLOCATION: usize = 1024 * 1024;
+
+/// How deeply terms may be nested, so that decoding doesn't overflow the stack.
+///
+/// Decoded messages are parsed by serde_json later on, which doesn't accept deeper nesting either.
+const MAX_DEPTH: usize = 128;
+
+custom_error! {
+    /// An error encountered while decoding an ETF term
+    #[derive(PartialEq, Eq, Clone)]
+    pub EtfError
+    InvalidVersion{version: u8} = "Unsupported etf format version {version}, expected 131",
+    UnexpectedEnd = "Unexpected end of etf data",
+    UnsupportedTag{tag: u8} = "Unsupported etf term tag {tag}",
+    InvalidFloat = "Invalid etf float",
+    InvalidCompressedTerm{error: String} = "Failed to decompress etf term: {error}",
+    TrailingData{remaining: usize} = "Etf term was followed by {remaining} unexpected bytes",
+    TooDeeplyNested{max_depth: usize} = "Etf term is nested deeper than {max_depth} levels",
+}
+
+/// Decodes a complete ETF message (starting with the version byte) into a [Value].
+pub fn decode(data: &[u8]) -> Result<Value, EtfError> {
+    let mut decoder = Decoder {
+        data,
+        position: 0,
+        depth: 0,
+    };
+
+    let version = decoder.read_u8()?;
+    if version != FORMAT_VERSION {
+        return Err(EtfError::InvalidVersion { version });
+    }
+
+    let value = decoder.read_term()?;
+
+    let remaining = data.len() - decoder.position;
+    if remaining > 0 {
+        return Err(EtfError::TrailingData { remaining });
+    }
+
+    Ok(value)
+}
+
+/// Encodes a [Value] into a complete ETF message (starting with the version byte).
+pub fn encode(value: &Value) -> Vec<u8> {
+    let mut output = vec![FORMAT_VERSION];
+    encode_term(value, &mut output);
+    output
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    position: usize,
+    /// How many terms we are currently nested in
+    depth: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn read_slice(&mut self, length: usize) -> Result<&'a [u8], EtfError> {
+        let end = self
+            .position
+            .checked_add(length)
+            .ok_or(EtfError::UnexpectedEnd)?;
+        let slice = self
+            .data
+            .get(self.position..end)
+            .ok_or(EtfError::UnexpectedEnd)?;
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], EtfError> {
+        // Unwrap is fine, we just read exactly N bytes
+        Ok(self.read_slice(N)?.try_into().unwrap())
+    }
+
+    fn read_u8(&mut self) -> Result<u8, EtfError> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16, EtfError> {
+        Ok(u16::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, EtfError> {
+        Ok(u32::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_term(&mut self) -> Result<Value, EtfError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(EtfError::TooDeeplyNested {
+                max_depth: MAX_DEPTH,
+            });
+        }
+
+        self.depth += 1;
+        let term = self.read_tagged_term();
+        self.depth -= 1;
+        term
+    }
+
+    fn read_tagged_term(&mut self) -> Result<Value, EtfError> {
+        let tag = self.read_u8()?;
+
+        match tag {
+            SMALL_INTEGER_EXT => Ok(Value::from(self.read_u8()?)),
+            INTEGER_EXT => Ok(Value::from(i32::from_be_bytes(self.read_array()?))),
+            NEW_FLOAT_EXT => Ok(float_to_value(f64::from_be_bytes(self.read_array()?))),
+            FLOAT_EXT => {
+                // An old style float, formatted as a null padded string
+                let text = std::str::from_utf8(self.read_slice(31)?)
+                    .map_err(|_| EtfError::InvalidFloat)?;
+                let float = text
+                    .trim_end_matches('\0')
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| EtfError::InvalidFloat)?;
+                Ok(float_to_value(float))
+            }
+            ATOM_EXT | ATOM_UTF8_EXT => {
+                let length = self.read_u16()? as usize;
+                Ok(atom_to_value(self.read_slice(length)?))
+            }
+            SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => {
+                let length = self.read_u8()? as usize;
+                Ok(atom_to_value(self.read_slice(length)?))
+            }
+            SMALL_TUPLE_EXT => {
+                let arity = self.read_u8()? as usize;
+                self.read_elements(arity)
+            }
+            LARGE_TUPLE_EXT => {
+                let arity = self.read_u32()? as usize;
+                self.read_elements(arity)
+            }
+            NIL_EXT => Ok(Value::Array(Vec::new())),
+            STRING_EXT => {
+                // Erlang strings are lists of bytes
+                let length = self.read_u16()? as usize;
+                let bytes = self.read_slice(length)?;
+                Ok(Value::Array(
+                    bytes.iter().map(|byte| Value::from(*byte)).collect(),
+                ))
+            }
+            LIST_EXT => {
+                let length = self.read_u32()? as usize;
+                let mut elements = match self.read_elements(length)? {
+                    Value::Array(elements) => elements,
+                    _ => unreachable!(),
+                };
+
+                // Proper lists end with an empty list; for improper ones, keep the tail around
+                match self.read_term()? {
+                    Value::Array(tail) if tail.is_empty() => {}
+                    tail => elements.push(tail),
+                }
+
+                Ok(Value::Array(elements))
+            }
+            BINARY_EXT => {
+                let length = self.read_u32()? as usize;
+                let bytes = self.read_slice(length)?;
+                Ok(Value::String(String::from_utf8_lossy(bytes).into_owned()))
+            }
+            SMALL_BIG_EXT => {
+                let length = self.read_u8()? as usize;
+                self.read_big(length)
+            }
+            LARGE_BIG_EXT => {
+                let length = self.read_u32()? as usize;
+                self.read_big(length)
+            }
+            MAP_EXT => {
+                let arity = self.read_u32()? as usize;
+                let mut map = Map::new();
+                for _ in 0..arity {
+                    let key = value_to_key(self.read_term()?);
+                    let value = self.read_term()?;
+                    map.insert(key, value);
+                }
+                Ok(Value::Object(map))
+            }
+            COMPRESSED => {
+                let uncompressed_size = self.read_u32()? as usize;
+                let compressed = &self.data[self.position..];
+
+                let mut uncompressed =
+                    Vec::with_capacity(uncompressed_size.min(MAX_COMPRESSED_PREALLOCATION));
+                // Reading one byte more than announced lets us notice if the term is too long,
+                // without decompressing all of it
+                flate2::read::ZlibDecoder::new(compressed)
+                    .take(uncompressed_size as u64 + 1)
+                    .read_to_end(&mut uncompressed)
+                    .map_err(|e| EtfError::InvalidCompressedTerm {
+                        error: e.to_string(),
+                    })?;
+                if uncompressed.len() != uncompressed_size {
+                    return Err(EtfError::InvalidCompressedTerm {
+                        error: format!(
+                            "Expected {} bytes after decompressing, got {}",
+                            uncompressed_size,
+                            uncompressed.len()
+                        ),
+                    });
+                }
+                self.position = self.data.len();
+
+                // The compressed term is nested in this one
+                let mut decoder = Decoder {
+                    data: &uncompressed,
+                    position: 0,
+                    depth: self.depth,
+                };
+                decoder.read_term()
+            }
+            tag => Err(EtfError::UnsupportedTag { tag }),
+        }
+    }
+
+    fn read_elements(&mut self, count: usize) -> Result<Value, EtfError> {
+        // Don't trust the length for preallocating, each element is at least one byte
+        let mut elements = Vec::with_capacity(count.min(self.data.len() - self.position));
+        for _ in 0..count {
+            elements.push(self.read_term()?);
+        }
+        Ok(Value::Array(elements))
+    }
+
+    fn read_big(&mut self, length: usize) -> Result<Value, EtfError> {
+        let negative = self.read_u8()? != 0;
+        // Digits are stored little endian in base 256
+        let digits = self.read_slice(length)?;
+
+        let significant = digits.len() - digits.iter().rev().take_while(|d| **d == 0).count();
+        if significant <= 8 {
+            let mut value: u64 = 0;
+            for digit in digits[..significant].iter().rev() {
+                value = (value << 8) | *digit as u64;
+            }
+
+            if !negative {
+                return Ok(Value::from(value));
+            }
+            if value <= i64::MAX as u64 + 1 {
+                return Ok(Value::from((value as i64).wrapping_neg()));
+            }
+        }
+
+        let mut text = big_to_decimal(&digits[..significant]);
+        if negative {
+            text.insert(0, '-');
+        }
+        Ok(Value::String(text))
+    }
+}
+
+/// Converts a little endian base 256 number to a decimal string
+fn big_to_decimal(digits: &[u8]) -> String {
+    let mut number: Vec<u8> = digits.iter().rev().copied().collect();
+    let mut decimal = Vec::new();
+
+    while number.iter().any(|digit| *digit != 0) {
+        let mut remainder: u32 = 0;
+        for digit in number.iter_mut() {
+            let current = (remainder << 8) | *digit as u32;
+            *digit = (current / 10) as u8;
+            remainder = current % 10;
+        }
+        decimal.push(b'0' + remainder as u8);
+    }
+
+    if decimal.is_empty() {
+        return String::from("0");
+    }
+
+    decimal.reverse();
+    // Unwrap is fine, we only pushed ascii digits
+    String::from_utf8(decimal).unwrap()
+}
+
+fn atom_to_value(atom: &[u8]) -> Value {
+    match atom {
+        b"nil" | b"null" => Value::Null,
+        b"true" => Value::Bool(true),
+        b"false" => Value::Bool(false),
+        other => Value::String(String::from_utf8_lossy(other).into_owned()),
+    }
+}
+
+fn float_to_value(float: f64) -> Value {
+    Number::from_f64(float)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+fn value_to_key(value: Value) -> String {
+    match value {
+        Value::String(string) => string,
+        other => other.to_string(),
+    }
+}
+
+fn encode_term(value: &Value, output: &mut Vec<u8>) {
+    match value {
+        Value::Null => encode_atom("nil", output),
+        Value::Bool(true) => encode_atom("true", output),
+        Value::Bool(false) => encode_atom("false", output),
+        Value::Number(number) => encode_number(number, output),
+        Value::String(string) => encode_binary(string, output),
+        Value::Array(elements) => {
+            if !elements.is_empty() {
+                output.push(LIST_EXT);
+                output.extend((elements.len() as u32).to_be_bytes());
+                for element in elements {
+                    encode_term(element, output);
+                }
+            }
+            output.push(NIL_EXT);
+        }
+        Value::Object(map) => {
+            output.push(MAP_EXT);
+            output.extend((map.len() as u32).to_be_bytes());
+            for (key, value) in map {
+                encode_binary(key, output);
+                encode_term(value, output);
+            }
+        }
+    }
+}
+
+fn encode_atom(atom: &str, output: &mut Vec<u8>) {
+    output.push(SMALL_ATOM_EXT);
+    output.push(atom.len() as u8);
+    output.extend(atom.as_bytes());
+}
+
+fn encode_binary(string: &str, output: &mut Vec<u8>) {
+    output.push(BINARY_EXT);
+    output.extend((string.len() as u32).to_be_bytes());
+    output.extend(string.as_bytes());
+}
+
+fn encode_number(number: &Number, output: &mut Vec<u8>) {
+    if let Some(unsigned) = number.as_u64() {
+        if unsigned <= u8::MAX as u64 {
+            output.push(SMALL_INTEGER_EXT);
+            output.push(unsigned as u8);
+        } else if unsigned <= i32::MAX as u64 {
+            output.push(INTEGER_EXT);
+            output.extend((unsigned as i32).to_be_bytes());
+        } else {
+            encode_big(unsigned, false, output);
+        }
+    } else if let Some(signed) = number.as_i64() {
+        // Only negative numbers end up here
+        if signed >= i32::MIN as i64 {
+            output.push(INTEGER_EXT);
+            output.extend((signed as i32).to_be_bytes());
+        } else {
+            encode_big(signed.unsigned_abs(), true, output);
+        }
+    } else {
+        // Unwrap is fine, a number which is not an integer is a float
+        output.push(NEW_FLOAT_EXT);
+        output.extend(number.as_f64().unwrap().to_be_bytes());
+    }
+}
+
+fn encode_big(value: u64, negative: bool, output: &mut Vec<u8>) {
+    let digits = value.to_le_bytes();
+    let length = 8 - digits.iter().rev().take_while(|d| **d == 0).count();
+
+    output.push(SMALL_BIG_EXT);
+    output.push(length as u8);
+    output.push(negative as u8);
+    output.extend(&digits[..length]);
+}
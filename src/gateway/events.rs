@@ -28,6 +28,8 @@ pub struct Events {
     pub mfa: Mfa,
     pub gateway_identify_payload: Publisher<types::GatewayIdentifyPayload>,
     pub gateway_resume: Publisher<types::GatewayResume>,
+    /// Every dispatched event, before it is parsed into its specific type
+    pub dispatch: Publisher<types::GatewayDispatch>,
     pub error: Publisher<GatewayError>,
 }
 
@@ -52,6 +54,9 @@ pub struct Session {
     pub reconnect: Publisher<types::GatewayReconnect>,
     pub invalid: Publisher<types::GatewayInvalidSession>,
     pub resumed: Publisher<types::GatewayResumed>,
+    pub reconnecting: Publisher<types::GatewayReconnecting>,
+    pub reconnected: Publisher<types::GatewayReconnected>,
+    pub reconnect_failed: Publisher<types::GatewayReconnectFailed>,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -4,30 +4,36 @@
 
 use std::time::Duration;
 
-use flate2::Decompress;
 use futures_util::{SinkExt, StreamExt};
 use log::*;
 use pubserve::Publisher;
 #[cfg(not(target_arch = "wasm32"))]
 use tokio::task;
 
+use super::compression::GatewayDecompressor;
 use super::events::Events;
 use super::*;
 use super::{Sink, Stream};
 use crate::types::{
     self, AutoModerationRule, AutoModerationRuleUpdate, Channel, ChannelCreate, ChannelDelete,
-    ChannelUpdate, CloseCode, GatewayInvalidSession, GatewayReconnect, Guild, GuildRoleCreate,
-    GuildRoleUpdate, JsonField, Opcode, RoleObject, SourceUrlField, ThreadUpdate, UpdateMessage,
-    WebSocketEvent,
+    ChannelUpdate, CloseCode, GatewayInvalidSession, GatewayReconnect, GatewayReconnectFailed,
+    GatewayReconnected, GatewayReconnecting, Guild, GuildEmojisUpdate, GuildRoleCreate,
+    GuildRoleUpdate, GuildScheduledEvent, GuildScheduledEventUpdate, GuildScheduledEventUserAdd,
+    GuildScheduledEventUserRemove, GuildStickersUpdate, IntegrationCreate, IntegrationDelete,
+    IntegrationUpdate, InviteCreate, InviteDelete, Opcode, RoleObject, SourceUrlField,
+    StageInstance, StageInstanceUpdate, ThreadCreate, ThreadDelete, ThreadListSync,
+    ThreadMemberUpdate, ThreadMembersUpdate, ThreadUpdate, UpdateMessage, WebSocketEvent,
 };
 
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::sleep;
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::sleep;
+
 // Needed to observe close codes
 #[cfg(target_arch = "wasm32")]
 use pharos::Observable;
 
-/// Tells us we have received enough of the buffer to decompress it
-const ZLIB_SUFFIX: [u8; 4] = [0, 0, 255, 255];
-
 #[derive(Debug)]
 pub struct Gateway {
     events: Arc<Mutex<Events>>,
@@ -37,12 +43,60 @@ pub struct Gateway {
     kill_send: tokio::sync::broadcast::Sender<()>,
     kill_receive: tokio::sync::broadcast::Receiver<()>,
     store: Arc<Mutex<HashMap<Snowflake, Arc<RwLock<ObservableObject>>>>>,
+    /// Information about the current session, used for resuming after reconnects
+    session: Arc<Mutex<GatewaySessionState>>,
     /// Url which was used to initialize the gateway
     url: String,
     /// Options which were used to initialize the gateway
     options: GatewayOptions,
-    zlib_inflate: Option<flate2::Decompress>,
-    zlib_buffer: Option<Vec<u8>>,
+    /// Decompresses received messages, according to the transport compression in the options
+    decompressor: Box<dyn GatewayDecompressor>,
+    /// Set when we should reconnect after handling the current message, to whether we should
+    /// try to resume
+    reconnect_requested: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+/// Information about a gateway session, which is needed to resume it after the connection drops.
+///
+/// The session id and resume url are received in [types::GatewayReady], the sequence number is
+/// updated with every dispatched event.
+///
+/// When serialized, e. g. as part of a [SessionSnapshot](crate::api::auth::SessionSnapshot), the
+/// identify payload is left out, since it contains the user's token.
+pub struct GatewaySessionState {
+    /// The id of the current session, if we've received one
+    pub session_id: Option<String>,
+    /// The url we should use when resuming the session, if the server sent one
+    pub resume_gateway_url: Option<String>,
+    /// The last sequence number we have received
+    pub sequence_number: Option<u64>,
+    /// The last identify payload sent to the gateway, used to start a new session if the old one
+    /// cannot be resumed
+    #[serde(skip)]
+    pub(crate) identify: Option<types::GatewayIdentifyPayload>,
+}
+
+impl GatewaySessionState {
+    /// Returns whether we have all the information we need to resume the session
+    pub fn is_resumable(&self) -> bool {
+        self.session_id.is_some() && self.sequence_number.is_some() && self.identify.is_some()
+    }
+}
+
+/// The part of [types::GatewayReady] we need to track the session
+#[derive(Debug, serde::Deserialize)]
+struct ReadySessionInformation {
+    session_id: String,
+    resume_gateway_url: Option<String>,
+}
+
+/// A freshly opened websocket connection, which has already received its hello
+struct GatewayConnection {
+    websocket_send: Sink,
+    websocket_receive: Stream,
+    hello: types::HelloData,
+    decompressor: Box<dyn GatewayDecompressor>,
 }
 
 impl Gateway {
@@ -54,12 +108,100 @@ impl Gateway {
     pub async fn spawn(
         websocket_url: &str,
         options: GatewayOptions,
+    ) -> Result<GatewayHandle, GatewayError> {
+        Gateway::spawn_with_events(websocket_url, None, options, Events::default()).await
+    }
+
+    /// Opens a new gateway connection like [Gateway::spawn], publishing events to the observers
+    /// already subscribed to `events`, e. g. an instance's default events.
+    ///
+    /// If a session is about to be resumed, the connection is opened to its
+    /// `resume_gateway_url` instead; `websocket_url` is still used for new sessions if the
+    /// connection is lost.
+    ///
+    /// The [GatewayCache] is subscribed to `events` as well.
+    pub(crate) async fn spawn_with_events(
+        websocket_url: &str,
+        resume_gateway_url: Option<&str>,
+        options: GatewayOptions,
+        mut events: Events,
     ) -> Result<GatewayHandle, GatewayError> {
         let url = options.add_to_url(websocket_url);
+        let connect_url = match resume_gateway_url {
+            Some(resume_gateway_url) => options.add_to_url(resume_gateway_url),
+            None => url.clone(),
+        };
+
+        let connection = Gateway::connect(&connect_url, options).await?;
+
+        let shared_websocket_send = Arc::new(Mutex::new(connection.websocket_send));
+
+        // Create a shared broadcast channel for killing all gateway tasks
+        let (kill_send, mut _kill_receive) = tokio::sync::broadcast::channel::<()>(16);
+
+        // Subscribe the cache before anyone else can, so it is up to date before observers
+        // subscribed through the handle receive an event
+        let cache = GatewayCache::new(options.cache);
+        cache.subscribe(&mut events);
+
+        let shared_events = Arc::new(Mutex::new(events));
+
+        let store = Arc::new(Mutex::new(HashMap::new()));
+
+        let session = Arc::new(Mutex::new(GatewaySessionState::default()));
+
+        // This automatically spawns the heartbeat task, but from the main thread
+        let mut gateway = Gateway {
+            events: shared_events.clone(),
+            heartbeat_handler: HeartbeatHandler::new(
+                Duration::from_millis(connection.hello.heartbeat_interval),
+                shared_websocket_send.clone(),
+                kill_send.subscribe(),
+                options.encoding,
+            ),
+            websocket_send: shared_websocket_send.clone(),
+            websocket_receive: connection.websocket_receive,
+            kill_send: kill_send.clone(),
+            kill_receive: kill_send.subscribe(),
+            store: store.clone(),
+            session: session.clone(),
+            url: url.clone(),
+            options,
+            decompressor: connection.decompressor,
+            reconnect_requested: None,
+        };
+
+        // Now we can continuously check for messages in a different task, since we aren't going to receive another hello
+        #[cfg(not(target_arch = "wasm32"))]
+        task::spawn(async move {
+            gateway.gateway_listen_task_tungstenite().await;
+        });
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(async move {
+            gateway.gateway_listen_task_wasm().await;
+        });
 
+        Ok(GatewayHandle {
+            url: url.clone(),
+            events: shared_events,
+            websocket_send: shared_websocket_send.clone(),
+            kill_send: kill_send.clone(),
+            store,
+            session,
+            encoding: options.encoding,
+            cache,
+        })
+    }
+
+    /// Opens a websocket connection to the given (already parameterized) url and waits for the
+    /// first hello.
+    async fn connect(
+        url: &str,
+        options: GatewayOptions,
+    ) -> Result<GatewayConnection, GatewayError> {
         debug!("GW: Connecting to {}", url);
 
-        let (websocket_send, mut websocket_receive) = match WebSocketBackend::connect(&url).await {
+        let (websocket_send, mut websocket_receive) = match WebSocketBackend::connect(url).await {
             Ok(streams) => streams,
             Err(e) => {
                 return Err(GatewayError::CannotConnect {
@@ -68,21 +210,26 @@ impl Gateway {
             }
         };
 
-        let shared_websocket_send = Arc::new(Mutex::new(websocket_send));
-
-        // Create a shared broadcast channel for killing all gateway tasks
-        let (kill_send, mut _kill_receive) = tokio::sync::broadcast::channel::<()>(16);
-
-        // Wait for the first hello and then spawn both tasks so we avoid nested tasks
-        // This automatically spawns the heartbeat task, but from the main thread
+        // Wait for the first hello
         #[cfg(not(target_arch = "wasm32"))]
         let received: RawGatewayMessage = {
             // Note: The tungstenite backend handles close codes as messages, while the ws_stream_wasm one handles them differently.
             //
             // Hence why wasm receives straight RawGatewayMessages, and tungstenite receives
             // GatewayCommunications.
-            let communication: GatewayCommunication =
-                websocket_receive.next().await.unwrap().unwrap().into();
+            let communication: GatewayCommunication = match websocket_receive.next().await {
+                Some(Ok(message)) => message.into(),
+                Some(Err(e)) => {
+                    return Err(GatewayError::CannotConnect {
+                        error: format!("{:?}", e),
+                    })
+                }
+                None => {
+                    return Err(GatewayError::CannotConnect {
+                        error: "Websocket closed before receiving hello".to_string(),
+                    })
+                }
+            };
 
             match communication {
                 GatewayCommunication::Message(message) => message,
@@ -90,32 +237,40 @@ impl Gateway {
             }
         };
         #[cfg(target_arch = "wasm32")]
-        let received: RawGatewayMessage = websocket_receive.0.next().await.unwrap().into();
-
-        let message: GatewayMessage;
-
-        let zlib_buffer;
-        let zlib_inflate;
-
-        match options.transport_compression {
-            GatewayTransportCompression::None => {
-                zlib_buffer = None;
-                zlib_inflate = None;
-                message = GatewayMessage::from_raw_json_message(received).unwrap();
+        let received: RawGatewayMessage = match websocket_receive.0.next().await {
+            Some(message) => message.into(),
+            None => {
+                return Err(GatewayError::CannotConnect {
+                    error: "Websocket closed before receiving hello".to_string(),
+                })
             }
-            GatewayTransportCompression::ZLibStream => {
-                zlib_buffer = Some(Vec::new());
-                let mut inflate = Decompress::new(true);
-
-                message =
-                    GatewayMessage::from_zlib_stream_json_message(received, &mut inflate).unwrap();
+        };
 
-                zlib_inflate = Some(inflate);
+        let mut decompressor = options.transport_compression.decompressor().map_err(|e| {
+            GatewayError::CannotConnect {
+                error: format!("Failed to create decompressor: {}", e),
             }
-        }
+        })?;
+
+        let bytes = decompressor
+            .decompress(received.into_bytes())
+            .map_err(|e| GatewayError::CannotConnect {
+                error: format!("Failed to decompress hello: {}", e),
+            })?
+            .ok_or(GatewayError::CannotConnect {
+                error: "Received incomplete hello".to_string(),
+            })?;
+
+        let message = GatewayMessage::decode(bytes, options.encoding).map_err(|e| {
+            GatewayError::CannotConnect {
+                error: format!("Received invalid hello: {}", e),
+            }
+        })?;
 
-        let gateway_payload: types::GatewayReceivePayload =
-            serde_json::from_str(&message.0).unwrap();
+        let gateway_payload: types::GatewayReceivePayload = serde_json::from_str(&message.0)
+            .map_err(|e| GatewayError::CannotConnect {
+                error: format!("Received invalid hello: {}", e),
+            })?;
 
         if gateway_payload.op_code != (Opcode::Hello as u8) {
             warn!(
@@ -129,48 +284,18 @@ impl Gateway {
 
         debug!("GW: Received Hello");
 
-        let gateway_hello: types::HelloData =
-            serde_json::from_str(gateway_payload.event_data.unwrap().get()).unwrap();
-
-        let events = Events::default();
-        let shared_events = Arc::new(Mutex::new(events));
+        let hello: types::HelloData = gateway_payload
+            .event_data
+            .and_then(|data| serde_json::from_str(data.get()).ok())
+            .ok_or(GatewayError::CannotConnect {
+                error: "Received hello without valid hello data".to_string(),
+            })?;
 
-        let store = Arc::new(Mutex::new(HashMap::new()));
-
-        let mut gateway = Gateway {
-            events: shared_events.clone(),
-            heartbeat_handler: HeartbeatHandler::new(
-                Duration::from_millis(gateway_hello.heartbeat_interval),
-                shared_websocket_send.clone(),
-                kill_send.subscribe(),
-            ),
-            websocket_send: shared_websocket_send.clone(),
+        Ok(GatewayConnection {
+            websocket_send,
             websocket_receive,
-            kill_send: kill_send.clone(),
-            kill_receive: kill_send.subscribe(),
-            store: store.clone(),
-            url: url.clone(),
-            options,
-            zlib_inflate,
-            zlib_buffer,
-        };
-
-        // Now we can continuously check for messages in a different task, since we aren't going to receive another hello
-        #[cfg(not(target_arch = "wasm32"))]
-        task::spawn(async move {
-            gateway.gateway_listen_task_tungstenite().await;
-        });
-        #[cfg(target_arch = "wasm32")]
-        wasm_bindgen_futures::spawn_local(async move {
-            gateway.gateway_listen_task_wasm().await;
-        });
-
-        Ok(GatewayHandle {
-            url: url.clone(),
-            events: shared_events,
-            websocket_send: shared_websocket_send.clone(),
-            kill_send: kill_send.clone(),
-            store,
+            hello,
+            decompressor,
         })
     }
 
@@ -181,6 +306,9 @@ impl Gateway {
             let msg;
 
             tokio::select! {
+                // Check the kill signal first, so closing the gateway isn't mistaken for a broken
+                // websocket
+                biased;
                 Ok(_) = self.kill_receive.recv() => {
                     log::trace!("GW: Closing listener task");
                     break;
@@ -206,12 +334,25 @@ impl Gateway {
                     }
                 }
 
+                if let Some(resume) = self.reconnect_requested.take() {
+                    if !self.reconnect(resume).await {
+                        break;
+                    }
+                }
+
                 continue;
             }
 
-            // We couldn't receive the next message or it was an error, something is wrong with the websocket, close
-            warn!("GW: Websocket is broken, stopping gateway");
-            break;
+            // We couldn't receive the next message or it was an error, something is wrong with the websocket
+            if !self.options.reconnect.enabled {
+                warn!("GW: Websocket is broken, stopping gateway");
+                break;
+            }
+
+            warn!("GW: Websocket is broken, reconnecting");
+            if !self.reconnect(true).await {
+                break;
+            }
         }
     }
 
@@ -221,72 +362,271 @@ impl Gateway {
     /// bit
     #[cfg(target_arch = "wasm32")]
     async fn gateway_listen_task_wasm(&mut self) {
-        // Initiate the close event listener
-        let mut close_events = self
-            .websocket_receive
-            .1
-            .observe(pharos::Filter::Pointer(ws_stream_wasm::WsEvent::is_closed).into())
-            .await
-            .unwrap();
+        // Each iteration of this loop handles one websocket connection; we only go around again
+        // after reconnecting
+        'connection: loop {
+            // Initiate the close event listener
+            let mut close_events = self
+                .websocket_receive
+                .1
+                .observe(pharos::Filter::Pointer(ws_stream_wasm::WsEvent::is_closed).into())
+                .await
+                .unwrap();
 
-        loop {
-            let msg;
+            loop {
+                let msg;
+
+                tokio::select! {
+                     biased;
+                     Ok(_) = self.kill_receive.recv() => {
+                          log::trace!("GW: Closing listener task");
+                          break 'connection;
+                     }
+                     message = self.websocket_receive.0.next() => {
+                          msg = message;
+                     }
+                     maybe_event = close_events.next() => {
+                          if let Some(event) = maybe_event {
+                                  match event {
+                                        ws_stream_wasm::WsEvent::Closed(closed_event) => {
+                                            let close_code = CloseCode::try_from(closed_event.code).unwrap_or(CloseCode::UnknownError);
+                                            self.handle_close_code(close_code).await;
+
+                                            if let Some(resume) = self.reconnect_requested.take() {
+                                                if self.reconnect(resume).await {
+                                                    continue 'connection;
+                                                }
+                                            }
 
-            tokio::select! {
-                 Ok(_) = self.kill_receive.recv() => {
-                      log::trace!("GW: Closing listener task");
-                      break;
-                 }
-                 message = self.websocket_receive.0.next() => {
-                      msg = message;
-                 }
-                 maybe_event = close_events.next() => {
-                      if let Some(event) = maybe_event {
-                              match event {
-                                    ws_stream_wasm::WsEvent::Closed(closed_event) => {
-                                        let close_code = CloseCode::try_from(closed_event.code).unwrap_or(CloseCode::UnknownError);
-                                        self.handle_close_code(close_code).await;
-                                        break;
-                                    }
-                                    _ => unreachable!() // Should be impossible, we filtered close events
-                              }
-                      }
-                      continue;
+                                            break 'connection;
+                                        }
+                                        _ => unreachable!() // Should be impossible, we filtered close events
+                                  }
+                          }
+                          continue;
+                    }
                 }
-            }
 
-            // Note: The tungstenite backend handles close codes as messages, while the ws_stream_wasm one handles them as a seperate receiver.
-            //
-            // Hence why wasm receives RawGatewayMessages, and tungstenite receives
-            // GatewayCommunications.
-            if let Some(message) = msg {
-                self.handle_raw_message(message.into()).await;
-                continue;
-            }
+                // Note: The tungstenite backend handles close codes as messages, while the ws_stream_wasm one handles them as a seperate receiver.
+                //
+                // Hence why wasm receives RawGatewayMessages, and tungstenite receives
+                // GatewayCommunications.
+                if let Some(message) = msg {
+                    self.handle_raw_message(message.into()).await;
+
+                    if let Some(resume) = self.reconnect_requested.take() {
+                        if self.reconnect(resume).await {
+                            continue 'connection;
+                        }
+                        break 'connection;
+                    }
+
+                    continue;
+                }
 
-            // We couldn't receive the next message or it was an error, something is wrong with the websocket, close
-            warn!("GW: Websocket is broken, stopping gateway");
-            break;
+                // We couldn't receive the next message or it was an error, something is wrong with the websocket
+                if !self.options.reconnect.enabled {
+                    warn!("GW: Websocket is broken, stopping gateway");
+                    break 'connection;
+                }
+
+                warn!("GW: Websocket is broken, reconnecting");
+                if self.reconnect(true).await {
+                    continue 'connection;
+                }
+
+                break 'connection;
+            }
         }
     }
 
     /// Closes the websocket connection and stops all tasks
     async fn close(&mut self) {
-        self.kill_send.send(()).unwrap();
-        self.websocket_send.lock().await.close().await.unwrap();
+        // Errors here only mean the tasks or the websocket are already gone
+        let _ = self.kill_send.send(());
+        let _ = self.websocket_send.lock().await.close().await;
     }
 
     /// Handles receiving a [CloseCode].
     ///
-    /// Closes the connection and publishes an error event.
+    /// Publishes an error event. If the code allows it and reconnecting is enabled, requests a
+    /// reconnect, otherwise closes the connection.
     async fn handle_close_code(&mut self, code: CloseCode) {
         let error = GatewayError::from(code);
 
-        warn!("GW: Received error {:?}, connection will close..", error);
-        self.close().await;
+        if self.options.reconnect.enabled && code.is_reconnectable() {
+            warn!("GW: Received error {:?}, will reconnect..", error);
+            self.reconnect_requested = Some(code.is_resumable());
+        } else {
+            warn!("GW: Received error {:?}, connection will close..", error);
+            self.close().await;
+        }
+
         self.events.lock().await.error.publish(error).await;
     }
 
+    /// Sends json to the gateway with an opcode
+    async fn send_json_event(&self, op_code: u8, to_send: serde_json::Value) {
+        let gateway_payload = types::GatewaySendPayload {
+            op_code,
+            event_data: Some(to_send),
+            sequence_number: None,
+        };
+
+        let message = RawGatewayMessage::encode(&gateway_payload, self.options.encoding).unwrap();
+
+        // If this fails, the websocket is broken and we'll notice when receiving
+        if let Err(e) = self.websocket_send.lock().await.send(message.into()).await {
+            warn!("GW: Failed to send opcode {}: {:?}", op_code, e);
+        }
+    }
+
+    /// Re-establishes the websocket connection, retrying with backoff as configured in
+    /// [GatewayReconnectOptions].
+    ///
+    /// If `resume` is true and we know enough about the session, sends a [types::GatewayResume],
+    /// otherwise starts a new session by sending the last identify payload again.
+    ///
+    /// Returns whether we are connected again; if not, the gateway has been closed.
+    async fn reconnect(&mut self, resume: bool) -> bool {
+        let reconnect_options = self.options.reconnect;
+
+        // The old connection may still be open, e. g. if we were asked to reconnect
+        let _ = self.websocket_send.lock().await.close().await;
+
+        let mut attempt: u32 = 0;
+
+        loop {
+            attempt += 1;
+
+            if let Some(max_attempts) = reconnect_options.max_attempts {
+                if attempt > max_attempts {
+                    warn!(
+                        "GW: Failed to reconnect after {} attempts, closing",
+                        max_attempts
+                    );
+                    self.events
+                        .lock()
+                        .await
+                        .session
+                        .reconnect_failed
+                        .publish(GatewayReconnectFailed {
+                            attempts: max_attempts,
+                        })
+                        .await;
+                    self.close().await;
+                    return false;
+                }
+            }
+
+            let delay = reconnect_options.backoff_for_attempt(attempt);
+
+            info!("GW: Reconnecting in {:?} (attempt {})", delay, attempt);
+
+            self.events
+                .lock()
+                .await
+                .session
+                .reconnecting
+                .publish(GatewayReconnecting { attempt, delay })
+                .await;
+
+            tokio::select! {
+                biased;
+                Ok(_) = self.kill_receive.recv() => {
+                    log::trace!("GW: Closed while waiting to reconnect");
+                    return false;
+                }
+                () = sleep(delay) => {}
+            }
+
+            let session = self.session.lock().await.clone();
+            let resuming = resume && session.is_resumable();
+
+            let url = match (&session.resume_gateway_url, resuming) {
+                (Some(resume_gateway_url), true) => self.options.add_to_url(resume_gateway_url),
+                _ => self.url.clone(),
+            };
+
+            let connection = match Gateway::connect(&url, self.options).await {
+                Ok(connection) => connection,
+                Err(e) => {
+                    warn!("GW: Reconnect attempt {} failed: {}", attempt, e);
+                    continue;
+                }
+            };
+
+            *self.websocket_send.lock().await = connection.websocket_send;
+            self.websocket_receive = connection.websocket_receive;
+            self.decompressor = connection.decompressor;
+
+            // Replacing the handler also stops the old heartbeat task
+            self.heartbeat_handler = HeartbeatHandler::new(
+                Duration::from_millis(connection.hello.heartbeat_interval),
+                self.websocket_send.clone(),
+                self.kill_send.subscribe(),
+                self.options.encoding,
+            );
+
+            if resuming {
+                // Unwraps are fine, we checked the session is resumable
+                let sequence_number = session.sequence_number.unwrap();
+
+                let resume = types::GatewayResume {
+                    token: session.identify.unwrap().token,
+                    session_id: session.session_id.unwrap(),
+                    seq: sequence_number.to_string(),
+                };
+
+                // The new heartbeat task has to know where we left off as well
+                let heartbeat_communication = HeartbeatThreadCommunication {
+                    sequence_number: Some(sequence_number),
+                    op_code: None,
+                };
+
+                let _ = self
+                    .heartbeat_handler
+                    .send
+                    .send(heartbeat_communication)
+                    .await;
+
+                trace!("GW: Sending Resume..");
+                self.send_json_event(Opcode::Resume as u8, serde_json::to_value(&resume).unwrap())
+                    .await;
+            } else {
+                {
+                    let mut session = self.session.lock().await;
+                    session.session_id = None;
+                    session.resume_gateway_url = None;
+                    session.sequence_number = None;
+                }
+
+                if let Some(identify) = session.identify {
+                    trace!("GW: Sending Identify..");
+                    self.send_json_event(
+                        Opcode::Identify as u8,
+                        serde_json::to_value(&identify).unwrap(),
+                    )
+                    .await;
+                } else {
+                    debug!("GW: Reconnected, but we never identified, so not identifying");
+                }
+            }
+
+            info!("GW: Reconnected (resuming: {})", resuming);
+
+            self.events
+                .lock()
+                .await
+                .session
+                .reconnected
+                .publish(GatewayReconnected { resuming })
+                .await;
+
+            return true;
+        }
+    }
+
     /// Deserializes and updates a dispatched event, when we already know its type;
     /// (Called for every event in handle_message)
     #[allow(dead_code)] // TODO: Remove this allow annotation
@@ -307,31 +647,21 @@ impl Gateway {
     /// Takes a [RawGatewayMessage], converts it to [GatewayMessage] based
     /// of connection options and calls [Self::handle_message]
     async fn handle_raw_message(&mut self, raw_message: RawGatewayMessage) {
-        let message;
-
-        match self.options.transport_compression {
-            GatewayTransportCompression::None => {
-                message = GatewayMessage::from_raw_json_message(raw_message).unwrap()
+        let bytes = match self.decompressor.decompress(raw_message.into_bytes()) {
+            Ok(Some(bytes)) => bytes,
+            // We need to receive more of the message first
+            Ok(None) => return,
+            Err(e) => {
+                warn!("GW: Failed to decompress message: {}", e);
+                return;
             }
-            GatewayTransportCompression::ZLibStream => {
-                let message_bytes = raw_message.into_bytes();
-
-                let can_decompress = message_bytes.len() > 4
-                    && message_bytes[message_bytes.len() - 4..] == ZLIB_SUFFIX;
-
-                let zlib_buffer = self.zlib_buffer.as_mut().unwrap();
-                zlib_buffer.extend(message_bytes.clone());
-
-                if !can_decompress {
-                    return;
-                }
-
-                let zlib_buffer = self.zlib_buffer.as_ref().unwrap();
-                let inflate = self.zlib_inflate.as_mut().unwrap();
+        };
 
-                message =
-                    GatewayMessage::from_zlib_stream_json_bytes(zlib_buffer, inflate).unwrap();
-                self.zlib_buffer = Some(Vec::new());
+        let message = match GatewayMessage::decode(bytes, self.options.encoding) {
+            Ok(message) => message,
+            Err(e) => {
+                warn!("GW: Failed to decode message: {}", e);
+                return;
             }
         };
 
@@ -372,6 +702,35 @@ impl Gateway {
 
                 trace!("GW: Received {event_name}");
 
+                {
+                    let dispatch = &mut self.events.lock().await.dispatch;
+                    if dispatch.has_subscribers() {
+                        let data = gateway_payload
+                            .event_data
+                            .and_then(|data| serde_json::from_str(data.get()).ok())
+                            .unwrap_or_default();
+                        dispatch
+                            .publish(types::GatewayDispatch {
+                                event_name: event_name.clone(),
+                                sequence_number: gateway_payload.sequence_number,
+                                data,
+                            })
+                            .await;
+                    }
+                }
+
+                if event_name == "READY" {
+                    // Remember what we need to resume this session later
+                    if let Some(Ok(ready)) = gateway_payload
+                        .event_data
+                        .map(|data| serde_json::from_str::<ReadySessionInformation>(data.get()))
+                    {
+                        let mut session = self.session.lock().await;
+                        session.session_id = Some(ready.session_id);
+                        session.resume_gateway_url = ready.resume_gateway_url;
+                    }
+                }
+
                 macro_rules! handle {
                     ($($name:literal => $($path:ident).+ $( $message_type:ty: $update_type:ty)?),*) => {
                         match event_name.as_str() {
@@ -453,20 +812,20 @@ impl Gateway {
                     "CALL_CREATE" => call.create,
                     "CALL_UPDATE" => call.update,
                     "CALL_DELETE" => call.delete,
-                    "THREAD_CREATE" => thread.create, // TODO
+                    "THREAD_CREATE" => thread.create ThreadCreate: Guild,
                     "THREAD_UPDATE" => thread.update ThreadUpdate: Channel,
-                    "THREAD_DELETE" => thread.delete, // TODO
-                    "THREAD_LIST_SYNC" => thread.list_sync, // TODO
-                    "THREAD_MEMBER_UPDATE" => thread.member_update, // TODO
-                    "THREAD_MEMBERS_UPDATE" => thread.members_update, // TODO
+                    "THREAD_DELETE" => thread.delete ThreadDelete: Guild,
+                    "THREAD_LIST_SYNC" => thread.list_sync ThreadListSync: Guild,
+                    "THREAD_MEMBER_UPDATE" => thread.member_update ThreadMemberUpdate: Channel,
+                    "THREAD_MEMBERS_UPDATE" => thread.members_update ThreadMembersUpdate: Channel,
                     "GUILD_CREATE" => guild.create, // TODO
                     "GUILD_UPDATE" => guild.update, // TODO
                     "GUILD_DELETE" => guild.delete, // TODO
                     "GUILD_AUDIT_LOG_ENTRY_CREATE" => guild.audit_log_entry_create,
                     "GUILD_BAN_ADD" => guild.ban_add, // TODO
                     "GUILD_BAN_REMOVE" => guild.ban_remove, // TODO
-                    "GUILD_EMOJIS_UPDATE" => guild.emojis_update, // TODO
-                    "GUILD_STICKERS_UPDATE" => guild.stickers_update, // TODO
+                    "GUILD_EMOJIS_UPDATE" => guild.emojis_update GuildEmojisUpdate: Guild,
+                    "GUILD_STICKERS_UPDATE" => guild.stickers_update GuildStickersUpdate: Guild,
                     "GUILD_INTEGRATIONS_UPDATE" => guild.integrations_update,
                     "GUILD_MEMBER_ADD" => guild.member_add,
                     "GUILD_MEMBER_REMOVE" => guild.member_remove,
@@ -476,17 +835,17 @@ impl Gateway {
                     "GUILD_ROLE_UPDATE" => guild.role_update GuildRoleUpdate: RoleObject,
                     "GUILD_ROLE_DELETE" => guild.role_delete, // TODO
                     "GUILD_SCHEDULED_EVENT_CREATE" => guild.role_scheduled_event_create, // TODO
-                    "GUILD_SCHEDULED_EVENT_UPDATE" => guild.role_scheduled_event_update, // TODO
+                    "GUILD_SCHEDULED_EVENT_UPDATE" => guild.role_scheduled_event_update GuildScheduledEventUpdate: GuildScheduledEvent,
                     "GUILD_SCHEDULED_EVENT_DELETE" => guild.role_scheduled_event_delete, // TODO
-                    "GUILD_SCHEDULED_EVENT_USER_ADD" => guild.role_scheduled_event_user_add,
-                    "GUILD_SCHEDULED_EVENT_USER_REMOVE" => guild.role_scheduled_event_user_remove,
+                    "GUILD_SCHEDULED_EVENT_USER_ADD" => guild.role_scheduled_event_user_add GuildScheduledEventUserAdd: GuildScheduledEvent,
+                    "GUILD_SCHEDULED_EVENT_USER_REMOVE" => guild.role_scheduled_event_user_remove GuildScheduledEventUserRemove: GuildScheduledEvent,
                     "PASSIVE_UPDATE_V1" => guild.passive_update_v1, // TODO
-                    "INTEGRATION_CREATE" => integration.create, // TODO
-                    "INTEGRATION_UPDATE" => integration.update, // TODO
-                    "INTEGRATION_DELETE" => integration.delete, // TODO
+                    "INTEGRATION_CREATE" => integration.create IntegrationCreate: Guild,
+                    "INTEGRATION_UPDATE" => integration.update IntegrationUpdate: Guild,
+                    "INTEGRATION_DELETE" => integration.delete IntegrationDelete: Guild,
                     "INTERACTION_CREATE" => interaction.create, // TODO
-                    "INVITE_CREATE" => invite.create, // TODO
-                    "INVITE_DELETE" => invite.delete, // TODO
+                    "INVITE_CREATE" => invite.create InviteCreate: Guild,
+                    "INVITE_DELETE" => invite.delete InviteDelete: Guild,
                     "LAST_MESSAGES" => message.last_messages,
                     "MESSAGE_CREATE" => message.create,
                     "MESSAGE_UPDATE" => message.update, // TODO
@@ -503,7 +862,7 @@ impl Gateway {
                     "RELATIONSHIP_ADD" => relationship.add,
                     "RELATIONSHIP_REMOVE" => relationship.remove,
                     "STAGE_INSTANCE_CREATE" => stage_instance.create,
-                    "STAGE_INSTANCE_UPDATE" => stage_instance.update, // TODO
+                    "STAGE_INSTANCE_UPDATE" => stage_instance.update StageInstanceUpdate: StageInstance,
                     "STAGE_INSTANCE_DELETE" => stage_instance.delete,
                     "TYPING_START" => user.typing_start,
                     "USER_UPDATE" => user.update, // TODO
@@ -559,6 +918,10 @@ impl Gateway {
                     .reconnect
                     .publish(reconnect)
                     .await;
+
+                if self.options.reconnect.enabled {
+                    self.reconnect_requested = Some(true);
+                }
             }
             Opcode::InvalidSession => {
                 trace!("GW: Received Invalid Session");
@@ -584,6 +947,11 @@ impl Gateway {
                     .invalid
                     .publish(invalid_session)
                     .await;
+
+                // If the session can't be resumed, we'll start a new one
+                if self.options.reconnect.enabled {
+                    self.reconnect_requested = Some(resumable);
+                }
             }
             // Starts our heartbeat
             // We should have already handled this
@@ -600,6 +968,8 @@ impl Gateway {
 
         // If we we received a sequence number we should let the heartbeat thread know
         if let Some(seq_num) = gateway_payload.sequence_number {
+            self.session.lock().await.sequence_number = Some(seq_num);
+
             let heartbeat_communication = HeartbeatThreadCommunication {
                 sequence_number: Some(seq_num),
                 // Op code is irrelevant here
@@ -28,6 +28,12 @@ pub struct GatewayHandle {
     /// Tells gateway tasks to close
     pub(super) kill_send: tokio::sync::broadcast::Sender<()>,
     pub(crate) store: Arc<Mutex<HashMap<Snowflake, Arc<RwLock<ObservableObject>>>>>,
+    /// Information about the current session, shared with the gateway task
+    pub(crate) session: Arc<Mutex<GatewaySessionState>>,
+    /// The encoding the gateway connection uses
+    pub(crate) encoding: GatewayEncoding,
+    /// Entities received over this connection
+    pub(crate) cache: GatewayCache,
 }
 
 impl GatewayHandle {
@@ -39,8 +45,7 @@ impl GatewayHandle {
             sequence_number: None,
         };
 
-        let payload_json = serde_json::to_string(&gateway_payload).unwrap();
-        let message = GatewayMessage(payload_json);
+        let message = RawGatewayMessage::encode(&gateway_payload, self.encoding).unwrap();
 
         self.websocket_send
             .lock()
@@ -110,12 +115,45 @@ impl GatewayHandle {
         object
     }
 
+    /// Returns information about the current gateway session, such as its id and the last
+    /// received sequence number.
+    ///
+    /// The gateway uses this information to resume the session after reconnecting.
+    pub async fn session_state(&self) -> GatewaySessionState {
+        self.session.lock().await.clone()
+    }
+
+    /// Returns the cache of entities received over this connection.
+    ///
+    /// See [GatewayCache]
+    pub fn cache(&self) -> &GatewayCache {
+        &self.cache
+    }
+
+    /// Returns a [Stream](futures_util::Stream) of every event dispatched on this connection.
+    ///
+    /// See [EventStream]
+    pub async fn event_stream(&self) -> EventStream<GatewayEvent> {
+        EventStream::subscribe(&self.events, self.kill_send.subscribe()).await
+    }
+
+    /// Returns a [Stream](futures_util::Stream) of one type of event dispatched on this
+    /// connection, such as [types::MessageCreate].
+    ///
+    /// See [EventStream]
+    pub async fn stream<T: FromGatewayDispatch>(&self) -> EventStream<T> {
+        EventStream::subscribe(&self.events, self.kill_send.subscribe()).await
+    }
+
     /// Sends an identify event ([types::GatewayIdentifyPayload]) to the gateway
     ///
     /// Fires off a [types::GatewayReady] event
     pub async fn send_identify(&self, to_send: types::GatewayIdentifyPayload) {
         let to_send_value = serde_json::to_value(&to_send).unwrap();
 
+        // Kept so we can start a new session on our own if reconnecting requires it
+        self.session.lock().await.identify = Some(to_send);
+
         trace!("GW: Sending Identify..");
 
         self.send_json_event(Opcode::Identify as u8, to_send_value)
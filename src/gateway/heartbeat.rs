@@ -43,17 +43,32 @@ impl HeartbeatHandler {
         heartbeat_interval: Duration,
         websocket_tx: Arc<Mutex<Sink>>,
         kill_rc: tokio::sync::broadcast::Receiver<()>,
+        encoding: GatewayEncoding,
     ) -> Self {
         let (send, receive) = tokio::sync::mpsc::channel(32);
         let kill_receive = kill_rc.resubscribe();
 
         #[cfg(not(target_arch = "wasm32"))]
         task::spawn(async move {
-            Self::heartbeat_task(websocket_tx, heartbeat_interval, receive, kill_receive).await;
+            Self::heartbeat_task(
+                websocket_tx,
+                heartbeat_interval,
+                receive,
+                kill_receive,
+                encoding,
+            )
+            .await;
         });
         #[cfg(target_arch = "wasm32")]
         wasm_bindgen_futures::spawn_local(async move {
-            Self::heartbeat_task(websocket_tx, heartbeat_interval, receive, kill_receive).await;
+            Self::heartbeat_task(
+                websocket_tx,
+                heartbeat_interval,
+                receive,
+                kill_receive,
+                encoding,
+            )
+            .await;
         });
 
         Self {
@@ -64,13 +79,14 @@ impl HeartbeatHandler {
 
     /// The main heartbeat task;
     ///
-    /// Can be killed by the kill broadcast;
+    /// Can be killed by the kill broadcast or by dropping the [HeartbeatHandler];
     /// If the websocket is closed, will die out next time it tries to send a heartbeat;
     pub async fn heartbeat_task(
         websocket_tx: Arc<Mutex<Sink>>,
         heartbeat_interval: Duration,
         mut receive: Receiver<HeartbeatThreadCommunication>,
         mut kill_receive: tokio::sync::broadcast::Receiver<()>,
+        encoding: GatewayEncoding,
     ) {
         let mut last_heartbeat_timestamp: Instant = Instant::now();
         let mut last_heartbeat_acknowledged = true;
@@ -90,7 +106,14 @@ impl HeartbeatHandler {
                 () = sleep_until(last_heartbeat_timestamp + timeout) => {
                     should_send = true;
                 }
-                Some(communication) = receive.recv() => {
+                communication = receive.recv() => {
+                    // If the handler was dropped (e. g. because the gateway reconnected and
+                    // created a new one), this task is no longer needed
+                    let Some(communication) = communication else {
+                        log::trace!("GW: Heartbeat handler dropped, closing heartbeat task");
+                        break;
+                    };
+
                     // If we received a seq number update, use that as the last seq number
                     if communication.sequence_number.is_some() {
                         last_seq_number = communication.sequence_number;
@@ -124,9 +147,7 @@ impl HeartbeatHandler {
                     d: last_seq_number,
                 };
 
-                let heartbeat_json = serde_json::to_string(&heartbeat).unwrap();
-
-                let msg = GatewayMessage(heartbeat_json);
+                let msg = RawGatewayMessage::encode(&heartbeat, encoding).unwrap();
 
                 let send_result = websocket_tx.lock().await.send(msg.into()).await;
                 if send_result.is_err() {
@@ -4,8 +4,21 @@
 
 use std::string::FromUtf8Error;
 
+use custom_error::custom_error;
+use serde::Serialize;
+
+use crate::gateway::etf::{self, EtfError};
+use crate::gateway::GatewayEncoding;
 use crate::types::{CloseCode, GatewayReceivePayload};
 
+custom_error! {
+    /// An error encountered while decoding a message received from the gateway
+    pub(crate) GatewayMessageDecodeError
+    InvalidUtf8{source: FromUtf8Error} = "Message is not valid utf-8: {source}",
+    InvalidEtf{source: EtfError} = "Message is not valid etf: {source}",
+    InvalidJson{error: String} = "Etf message could not be converted to json: {error}",
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 /// Defines a communication received from the gateway, being either an optionally compressed
 /// [RawGatewayMessage] or a [CloseCode].
@@ -33,14 +46,6 @@ pub(crate) enum RawGatewayMessage {
 }
 
 impl RawGatewayMessage {
-    /// Attempt to consume the message into a String, will try to convert binary to utf8
-    pub fn into_text(self) -> Result<String, FromUtf8Error> {
-        match self {
-            RawGatewayMessage::Text(text) => Ok(text),
-            RawGatewayMessage::Bytes(bytes) => String::from_utf8(bytes),
-        }
-    }
-
     /// Consume the message into bytes, will convert text to binary
     pub fn into_bytes(self) -> Vec<u8> {
         match self {
@@ -48,6 +53,22 @@ impl RawGatewayMessage {
             RawGatewayMessage::Bytes(bytes) => bytes,
         }
     }
+
+    /// Serializes a payload into a message in the given [GatewayEncoding]
+    ///
+    /// Json is sent as text, etf as binary
+    pub(crate) fn encode<T: Serialize>(
+        payload: &T,
+        encoding: GatewayEncoding,
+    ) -> Result<RawGatewayMessage, serde_json::Error> {
+        match encoding {
+            GatewayEncoding::Json => Ok(RawGatewayMessage::Text(serde_json::to_string(payload)?)),
+            GatewayEncoding::ETF => {
+                let value = serde_json::to_value(payload)?;
+                Ok(RawGatewayMessage::Bytes(etf::encode(&value)))
+            }
+        }
+    }
 }
 
 /// Represents a json message received from the gateway.
@@ -65,46 +86,24 @@ impl GatewayMessage {
         serde_json::from_str(&self.0)
     }
 
-    /// Create self from an uncompressed json [RawGatewayMessage]
-    pub(crate) fn from_raw_json_message(
-        message: RawGatewayMessage,
-    ) -> Result<GatewayMessage, FromUtf8Error> {
-        let text = message.into_text()?;
-        Ok(GatewayMessage(text))
-    }
-
-    /// Attempt to create self by decompressing zlib-stream bytes
-    // Thanks to <https://github.com/ByteAlex/zlib-stream-rs>, their
-    // code helped a lot with the stream implementation
-    pub(crate) fn from_zlib_stream_json_bytes(
-        bytes: &[u8],
-        inflate: &mut flate2::Decompress,
-    ) -> Result<GatewayMessage, std::io::Error> {
-        // Note: is there a better way to handle the size of this output buffer?
-        //
-        // This used to be 10, I measured it at 11.5, so a safe bet feels like 20
-        //
-        // ^ - This dude is naive. apparently not even 20x is okay. Measured at 47.9x!!!!
-        // If it is >100x ever, I will literally explode
-        //
-        // About an hour later, you ^ will literally explode.
-        // 133 vs 13994 -- 105.21805x ratio
-        // Let's hope it doesn't go above 200??
-        let mut output = Vec::with_capacity(bytes.len() * 200);
-        let _status = inflate.decompress_vec(bytes, &mut output, flate2::FlushDecompress::Sync)?;
-
-        output.shrink_to_fit();
-
-        let string = String::from_utf8(output).unwrap();
-
-        Ok(GatewayMessage(string))
-    }
-
-    /// Attempt to create self by decompressing a zlib-stream bytes raw message
-    pub(crate) fn from_zlib_stream_json_message(
-        message: RawGatewayMessage,
-        inflate: &mut flate2::Decompress,
-    ) -> Result<GatewayMessage, std::io::Error> {
-        Self::from_zlib_stream_json_bytes(&message.into_bytes(), inflate)
+    /// Create self from a complete, uncompressed message in the given [GatewayEncoding]
+    ///
+    /// Etf messages are converted to json
+    pub(crate) fn decode(
+        bytes: Vec<u8>,
+        encoding: GatewayEncoding,
+    ) -> Result<GatewayMessage, GatewayMessageDecodeError> {
+        match encoding {
+            GatewayEncoding::Json => Ok(GatewayMessage(String::from_utf8(bytes)?)),
+            GatewayEncoding::ETF => {
+                let value = etf::decode(&bytes)?;
+                let json = serde_json::to_string(&value).map_err(|e| {
+                    GatewayMessageDecodeError::InvalidJson {
+                        error: e.to_string(),
+                    }
+                })?;
+                Ok(GatewayMessage(json))
+            }
+        }
     }
 }
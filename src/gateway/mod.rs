@@ -5,6 +5,9 @@
 #![allow(deprecated)] // Since Opcode variants marked as deprecated are being used here, we need to suppress the warnings about them being deprecated
 
 pub mod backends;
+pub mod cache;
+pub(crate) mod compression;
+pub mod etf;
 pub mod events;
 pub mod gateway;
 pub mod handle;
@@ -12,14 +15,19 @@ pub mod heartbeat;
 pub mod message;
 pub mod observers;
 pub mod options;
+pub mod shard;
+pub mod stream;
 
 pub use backends::*;
+pub use cache::*;
 pub use gateway::*;
 pub use handle::*;
 use heartbeat::*;
 pub use message::*;
 pub use observers::*;
 pub use options::*;
+pub use shard::*;
+pub use stream::*;
 
 use crate::errors::GatewayError;
 use crate::types::Snowflake;
@@ -2,6 +2,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::time::Duration;
+
+use rand::Rng;
+
 use crate::instance::InstanceSoftware;
 
 #[derive(Clone, PartialEq, Eq, Ord, PartialOrd, Debug, Default, Copy)]
@@ -14,30 +18,37 @@ use crate::instance::InstanceSoftware;
 /// Discord allows specifying the api version (v10, v9, ...) as well, but chorus is built upon one
 /// main version (v9).
 ///
-/// Similarly, discord also supports etf encoding, while chorus does not (yet).
-/// We are looking into supporting it as an option, since it is faster and more lightweight.
-///
 /// See <https://docs.discord.sex/topics/gateway#connections>
 pub struct GatewayOptions {
     pub encoding: GatewayEncoding,
     pub transport_compression: GatewayTransportCompression,
+    /// How the gateway should behave when its connection is lost
+    pub reconnect: GatewayReconnectOptions,
+    /// Which entities the gateway should cache, see [GatewayCache](crate::gateway::GatewayCache)
+    pub cache: GatewayCacheOptions,
 }
 
 impl GatewayOptions {
     /// Creates the ideal gateway options for an [InstanceSoftware],
     /// based off which features it supports.
     pub fn for_instance_software(software: InstanceSoftware) -> GatewayOptions {
-        // TODO: Support ETF
+        // Etf has to be opted into, since servers' support for it varies a lot more than for json
         let encoding = GatewayEncoding::Json;
 
-        let transport_compression = match software.supports_gateway_zlib() {
-            true => GatewayTransportCompression::ZLibStream,
-            false => GatewayTransportCompression::None,
+        // Zlib stays preferred where both are supported, since it is more widely deployed
+        let transport_compression = if software.supports_gateway_zlib() {
+            GatewayTransportCompression::ZLibStream
+        } else if cfg!(feature = "zstd") && software.supports_gateway_zstd() {
+            GatewayTransportCompression::ZstdStream
+        } else {
+            GatewayTransportCompression::None
         };
 
         GatewayOptions {
             encoding,
             transport_compression,
+            reconnect: GatewayReconnectOptions::default(),
+            cache: GatewayCacheOptions::default(),
         }
     }
 
@@ -91,6 +102,10 @@ pub enum GatewayTransportCompression {
     /// Transport compress using zlib stream
     #[default]
     ZLibStream,
+    /// Transport compress using zstd stream
+    ///
+    /// Requires the `zstd` feature, connecting fails without it.
+    ZstdStream,
 }
 
 impl GatewayTransportCompression {
@@ -103,6 +118,7 @@ impl GatewayTransportCompression {
         match self {
             Self::None => None,
             Self::ZLibStream => Some(String::from("compress=zlib-stream")),
+            Self::ZstdStream => Some(String::from("compress=zstd-stream")),
         }
     }
 }
@@ -116,9 +132,8 @@ pub enum GatewayEncoding {
     Json,
     /// A binary format originating from Erlang
     ///
-    /// Should be lighter and faster than json.
-    ///
-    /// !! Chorus does not implement ETF yet !!
+    /// Produces smaller payloads than json; see [etf](crate::gateway::etf) for how terms are
+    /// mapped to the json data model.
     ETF,
 }
 
@@ -133,3 +148,125 @@ impl GatewayEncoding {
         }
     }
 }
+
+#[derive(Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Debug)]
+/// Options which control how a gateway connection is re-established after it drops.
+///
+/// When enabled, the gateway will reconnect on its own after the websocket breaks, after receiving
+/// a reconnect request or after being closed with a recoverable [CloseCode](crate::types::CloseCode).
+/// If possible, the previous session is resumed; otherwise a fresh identify is sent.
+///
+/// Attempts are spaced with an exponential backoff with jitter, starting at `initial_backoff` and
+/// never exceeding `max_backoff`.
+///
+/// See <https://docs.discord.sex/topics/gateway#resuming>
+pub struct GatewayReconnectOptions {
+    /// Whether to automatically reconnect at all
+    pub enabled: bool,
+    /// How many attempts to make before giving up, [None] for unlimited attempts
+    pub max_attempts: Option<u32>,
+    /// The delay before the first attempt
+    pub initial_backoff: Duration,
+    /// The maximum delay between two attempts
+    pub max_backoff: Duration,
+}
+
+impl Default for GatewayReconnectOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_attempts: None,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+impl GatewayReconnectOptions {
+    /// Options which disable automatic reconnecting entirely
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+
+    /// Returns how long to wait before the given (1-based) reconnect attempt.
+    ///
+    /// The delay doubles with every attempt up to `max_backoff`, and is then jittered to somewhere
+    /// between half of and the full delay, so that many clients don't reconnect in lockstep.
+    pub fn backoff_for_attempt(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .initial_backoff
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_backoff);
+
+        let half = delay / 2;
+        let jitter_ms = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+
+        half + Duration::from_millis(jitter_ms)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Debug, Default)]
+/// How many entities of one type a [GatewayCache](crate::gateway::GatewayCache) keeps
+pub enum CacheRetention {
+    /// Do not cache this entity type
+    None,
+    /// Cache every entity of this type we receive
+    #[default]
+    Unlimited,
+    /// Cache at most this many entities of this type.
+    ///
+    /// Once the limit is reached, the entity which was least recently inserted or updated is
+    /// evicted.
+    Limited(usize),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Debug)]
+/// Options which control what a [GatewayCache](crate::gateway::GatewayCache) keeps.
+///
+/// By default, the cache is enabled and keeps every entity it receives.
+pub struct GatewayCacheOptions {
+    /// Whether to cache anything at all
+    pub enabled: bool,
+    pub guilds: CacheRetention,
+    pub channels: CacheRetention,
+    pub roles: CacheRetention,
+    pub members: CacheRetention,
+    pub users: CacheRetention,
+    pub emojis: CacheRetention,
+    pub presences: CacheRetention,
+    pub voice_states: CacheRetention,
+    pub auto_moderation_rules: CacheRetention,
+    pub thread_members: CacheRetention,
+}
+
+impl Default for GatewayCacheOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            guilds: CacheRetention::Unlimited,
+            channels: CacheRetention::Unlimited,
+            roles: CacheRetention::Unlimited,
+            members: CacheRetention::Unlimited,
+            users: CacheRetention::Unlimited,
+            emojis: CacheRetention::Unlimited,
+            presences: CacheRetention::Unlimited,
+            voice_states: CacheRetention::Unlimited,
+            auto_moderation_rules: CacheRetention::Unlimited,
+            thread_members: CacheRetention::Unlimited,
+        }
+    }
+}
+
+impl GatewayCacheOptions {
+    /// Options which disable the cache entirely
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,289 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Running one session over multiple gateway connections (shards).
+//!
+//! See <https://docs.discord.sex/topics/gateway#sharding>
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use log::*;
+use pubserve::Subscriber;
+use tokio::sync::broadcast;
+
+use super::*;
+use crate::types;
+
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::sleep;
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::sleep;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Options for a [ShardManager]
+pub struct ShardManagerOptions {
+    /// How many shards to spawn
+    pub shard_count: u64,
+    /// How many shards may identify within one `identify_interval`.
+    ///
+    /// On Discord.com this is the `max_concurrency` returned by `GET /gateway/bot`.
+    ///
+    /// Default: 1
+    pub max_concurrency: u64,
+    /// How long to wait between identifying groups of `max_concurrency` shards.
+    ///
+    /// Default: 5 seconds
+    pub identify_interval: Duration,
+    /// How many events the merged event stream buffers for each receiver before the oldest
+    /// events are dropped.
+    ///
+    /// Default: 1024
+    pub event_capacity: usize,
+}
+
+impl Default for ShardManagerOptions {
+    fn default() -> Self {
+        Self {
+            shard_count: 1,
+            max_concurrency: 1,
+            identify_interval: Duration::from_secs(5),
+            event_capacity: 1024,
+        }
+    }
+}
+
+impl ShardManagerOptions {
+    /// Creates options for the given number of shards, leaving everything else at its default
+    pub fn with_shard_count(shard_count: u64) -> Self {
+        Self {
+            shard_count,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// An event received by one of a [ShardManager]'s shards
+pub struct ShardEvent {
+    /// The id of the shard which received the event
+    pub shard_id: u64,
+    /// The received event
+    pub dispatch: types::GatewayDispatch,
+}
+
+/// Forwards a shard's [types::GatewayDispatch]es into the merged event stream
+#[derive(Debug)]
+struct ShardEventForwarder {
+    shard_id: u64,
+    sender: broadcast::Sender<ShardEvent>,
+}
+
+#[async_trait]
+impl Subscriber<types::GatewayDispatch> for ShardEventForwarder {
+    async fn update(&self, data: &types::GatewayDispatch) {
+        // If nobody is listening, we have nothing to do
+        let _ = self.sender.send(ShardEvent {
+            shard_id: self.shard_id,
+            dispatch: data.clone(),
+        });
+    }
+}
+
+/// Runs multiple [Gateway] connections (shards) for one token.
+///
+/// Each shard is identified with its `(shard_id, shard_count)` tuple, and identifies are spaced
+/// out to respect the instance's identify concurrency.
+///
+/// Events of all shards are merged into one stream, see [ShardManager::events]. Each shard's
+/// [GatewayHandle] can also be used directly, see [ShardManager::shard].
+///
+/// # Example
+/// ```no_run
+/// # async fn run(identify: chorus::types::GatewayIdentifyPayload) -> Result<(), chorus::errors::GatewayError> {
+/// use chorus::gateway::{GatewayOptions, ShardManager, ShardManagerOptions};
+///
+/// let mut manager = ShardManager::new(
+///     "wss://gateway.discord.gg",
+///     GatewayOptions::default(),
+///     ShardManagerOptions::with_shard_count(4),
+///     identify,
+/// )?;
+///
+/// let mut events = manager.events();
+/// manager.start().await?;
+///
+/// while let Ok(event) = events.recv().await {
+///     println!("Shard {} received {}", event.shard_id, event.dispatch.event_name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ShardManager {
+    websocket_url: String,
+    gateway_options: GatewayOptions,
+    options: ShardManagerOptions,
+    /// The identify payload all shards are started with, without the shard tuple
+    identify: types::GatewayIdentifyPayload,
+    shards: Vec<GatewayHandle>,
+    events: broadcast::Sender<ShardEvent>,
+}
+
+impl ShardManager {
+    /// Creates a new shard manager, without connecting any shards yet.
+    ///
+    /// Subscribe to [ShardManager::events] and then call [ShardManager::start] to connect.
+    ///
+    /// Returns [GatewayError::InvalidShard] if the shard count is 0.
+    pub fn new(
+        websocket_url: &str,
+        gateway_options: GatewayOptions,
+        options: ShardManagerOptions,
+        identify: types::GatewayIdentifyPayload,
+    ) -> Result<ShardManager, GatewayError> {
+        if options.shard_count == 0 {
+            return Err(GatewayError::InvalidShard);
+        }
+
+        let (events, _) = broadcast::channel(options.event_capacity.max(1));
+
+        Ok(ShardManager {
+            websocket_url: websocket_url.to_string(),
+            gateway_options,
+            options,
+            identify,
+            shards: Vec::with_capacity(options.shard_count as usize),
+            events,
+        })
+    }
+
+    /// Spawns and identifies all shards which are not running yet.
+    ///
+    /// Shards are identified in groups of [ShardManagerOptions::max_concurrency], waiting
+    /// [ShardManagerOptions::identify_interval] between groups. The `shard` field of the
+    /// identify payload is filled in for every shard.
+    pub async fn start(&mut self) -> Result<(), GatewayError> {
+        let shard_ids: Vec<u64> = (self.shards.len() as u64..self.options.shard_count).collect();
+
+        for (index, group) in shard_ids
+            .chunks(self.options.max_concurrency.max(1) as usize)
+            .enumerate()
+        {
+            if index != 0 {
+                sleep(self.options.identify_interval).await;
+            }
+
+            for shard_id in group {
+                let handle = self.start_shard(*shard_id).await?;
+                self.shards.push(handle);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Connects a single shard, subscribes it to the merged event stream and identifies it
+    async fn start_shard(&self, shard_id: u64) -> Result<GatewayHandle, GatewayError> {
+        trace!("SHARDS: Starting shard {shard_id}");
+
+        let handle = Gateway::spawn(&self.websocket_url, self.gateway_options).await?;
+
+        handle
+            .events
+            .lock()
+            .await
+            .dispatch
+            .subscribe(Arc::new(ShardEventForwarder {
+                shard_id,
+                sender: self.events.clone(),
+            }));
+
+        handle.send_identify(self.identify_for(shard_id)).await;
+
+        Ok(handle)
+    }
+
+    /// Returns the identify payload for the given shard
+    fn identify_for(&self, shard_id: u64) -> types::GatewayIdentifyPayload {
+        let mut identify = self.identify.clone();
+        identify.shard = Some((shard_id, self.options.shard_count));
+        identify
+    }
+
+    /// Returns a new receiver for the events of all shards.
+    ///
+    /// Only events received after calling this method are returned, so subscribe before
+    /// calling [ShardManager::start] to receive every shard's [types::GatewayReady]. If the receiver falls
+    /// behind by more than [ShardManagerOptions::event_capacity] events, the oldest events are
+    /// dropped and the receiver returns [broadcast::error::RecvError::Lagged].
+    pub fn events(&self) -> broadcast::Receiver<ShardEvent> {
+        self.events.subscribe()
+    }
+
+    /// Returns the handle of the shard with the given id
+    pub fn shard(&self, shard_id: u64) -> Option<&GatewayHandle> {
+        self.shards.get(shard_id as usize)
+    }
+
+    /// Returns the handles of all shards, ordered by shard id
+    pub fn shards(&self) -> &[GatewayHandle] {
+        &self.shards
+    }
+
+    /// Returns the number of shards
+    pub fn shard_count(&self) -> u64 {
+        self.options.shard_count
+    }
+
+    /// Returns the id of the shard which receives events for the given guild
+    pub fn shard_id_for_guild(&self, guild_id: Snowflake) -> u64 {
+        shard_id_for_guild(guild_id, self.options.shard_count)
+    }
+
+    /// Closes a shard and starts a new session in its place.
+    ///
+    /// Returns [GatewayError::InvalidShard] if there is no shard with the given id.
+    pub async fn restart_shard(&mut self, shard_id: u64) -> Result<(), GatewayError> {
+        let Some(old_handle) = self.shards.get(shard_id as usize) else {
+            return Err(GatewayError::InvalidShard);
+        };
+
+        old_handle.close().await;
+
+        let handle = self.start_shard(shard_id).await?;
+        self.shards[shard_id as usize] = handle;
+
+        Ok(())
+    }
+
+    /// Restarts all shards one at a time, waiting
+    /// [ShardManagerOptions::identify_interval] between each, so that the other shards keep
+    /// receiving events.
+    pub async fn rolling_restart(&mut self) -> Result<(), GatewayError> {
+        for shard_id in 0..self.shards.len() as u64 {
+            if shard_id != 0 {
+                sleep(self.options.identify_interval).await;
+            }
+
+            self.restart_shard(shard_id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Closes all shards
+    pub async fn close(&self) {
+        for shard in self.shards.iter() {
+            shard.close().await;
+        }
+    }
+}
+
+/// Returns the id of the shard which receives events for the given guild
+///
+/// See <https://docs.discord.sex/topics/gateway#sharding-formula>
+pub fn shard_id_for_guild(guild_id: Snowflake, shard_count: u64) -> u64 {
+    (guild_id.0 >> 22) % shard_count.max(1)
+}
@@ -0,0 +1,645 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Consuming gateway events as an async [Stream].
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Mutex as StdMutex, Weak};
+use std::task::{Context, Poll, Waker};
+
+use async_trait::async_trait;
+use futures_util::future::BoxFuture;
+use futures_util::{FutureExt, Stream};
+use log::*;
+use pubserve::Subscriber;
+use serde::de::DeserializeOwned;
+use tokio::sync::{broadcast, Notify};
+
+use super::events::Events;
+use super::*;
+use crate::types::{self, GatewayDispatch};
+
+/// An event type which can be received as a [GatewayDispatch], see [EventStream]
+pub trait FromGatewayDispatch: Sized + Send + 'static {
+    /// Whether a dispatch can be converted into this type, without parsing it
+    fn matches(dispatch: &GatewayDispatch) -> bool;
+
+    /// Converts a dispatch into this type, or returns [None] if it is a different event or
+    /// fails to parse
+    fn from_dispatch(dispatch: GatewayDispatch) -> Option<Self>;
+}
+
+impl FromGatewayDispatch for GatewayDispatch {
+    fn matches(_: &GatewayDispatch) -> bool {
+        true
+    }
+
+    fn from_dispatch(dispatch: GatewayDispatch) -> Option<Self> {
+        Some(dispatch)
+    }
+}
+
+/// Parses a dispatch's data, logging a warning if it fails
+fn parse_dispatch<T: DeserializeOwned>(dispatch: &GatewayDispatch) -> Option<T> {
+    match T::deserialize(&dispatch.data) {
+        Ok(event) => Some(event),
+        Err(err) => {
+            warn!(
+                "Failed to parse gateway event {} ({err})",
+                dispatch.event_name
+            );
+            None
+        }
+    }
+}
+
+macro_rules! gateway_events {
+    ($($name:literal => $variant:ident($event:ty)),* $(,)?) => {
+        /// Any event dispatched by the gateway.
+        ///
+        /// See [GatewayHandle::event_stream]
+        #[derive(Debug, Clone)]
+        pub enum GatewayEvent {
+            $($variant($event),)*
+            SessionsReplace(types::SessionsReplace),
+            /// An event chorus does not know about, or which failed to parse
+            Unknown(GatewayDispatch),
+        }
+
+        impl GatewayEvent {
+            /// Returns the name of the event, such as `MESSAGE_CREATE`
+            pub fn event_name(&self) -> &str {
+                match self {
+                    $(Self::$variant(_) => $name,)*
+                    Self::SessionsReplace(_) => "SESSIONS_REPLACE",
+                    Self::Unknown(dispatch) => &dispatch.event_name,
+                }
+            }
+        }
+
+        impl FromGatewayDispatch for GatewayEvent {
+            fn matches(_: &GatewayDispatch) -> bool {
+                true
+            }
+
+            fn from_dispatch(dispatch: GatewayDispatch) -> Option<Self> {
+                let event = match dispatch.event_name.as_str() {
+                    $($name => parse_dispatch(&dispatch).map(Self::$variant),)*
+                    "SESSIONS_REPLACE" => parse_sessions_replace(&dispatch).map(Self::SessionsReplace),
+                    _ => None,
+                };
+
+                Some(event.unwrap_or(Self::Unknown(dispatch)))
+            }
+        }
+
+        $(
+            impl FromGatewayDispatch for $event {
+                fn matches(dispatch: &GatewayDispatch) -> bool {
+                    dispatch.event_name == $name
+                }
+
+                fn from_dispatch(dispatch: GatewayDispatch) -> Option<Self> {
+                    if !Self::matches(&dispatch) {
+                        return None;
+                    }
+                    parse_dispatch(&dispatch)
+                }
+            }
+        )*
+    };
+}
+
+// Keep in sync with the events handled in Gateway::handle_message
+gateway_events!(
+    "READY" => Ready(types::GatewayReady),
+    "READY_SUPPLEMENTAL" => ReadySupplemental(types::GatewayReadySupplemental),
+    "RESUMED" => Resumed(types::GatewayResumed),
+    "APPLICATION_COMMAND_PERMISSIONS_UPDATE" => ApplicationCommandPermissionsUpdate(types::ApplicationCommandPermissionsUpdate),
+    "AUTO_MODERATION_RULE_CREATE" => AutoModerationRuleCreate(types::AutoModerationRuleCreate),
+    "AUTO_MODERATION_RULE_UPDATE" => AutoModerationRuleUpdate(types::AutoModerationRuleUpdate),
+    "AUTO_MODERATION_RULE_DELETE" => AutoModerationRuleDelete(types::AutoModerationRuleDelete),
+    "AUTO_MODERATION_ACTION_EXECUTION" => AutoModerationActionExecution(types::AutoModerationActionExecution),
+    "AUTHENTICATOR_CREATE" => AuthenticatorCreate(types::AuthenticatorCreate),
+    "AUTHENTICATOR_UPDATE" => AuthenticatorUpdate(types::AuthenticatorUpdate),
+    "AUTHENTICATOR_DELETE" => AuthenticatorDelete(types::AuthenticatorDelete),
+    "CHANNEL_CREATE" => ChannelCreate(types::ChannelCreate),
+    "CHANNEL_UPDATE" => ChannelUpdate(types::ChannelUpdate),
+    "CHANNEL_UNREAD_UPDATE" => ChannelUnreadUpdate(types::ChannelUnreadUpdate),
+    "CHANNEL_DELETE" => ChannelDelete(types::ChannelDelete),
+    "CHANNEL_PINS_UPDATE" => ChannelPinsUpdate(types::ChannelPinsUpdate),
+    "CALL_CREATE" => CallCreate(types::CallCreate),
+    "CALL_UPDATE" => CallUpdate(types::CallUpdate),
+    "CALL_DELETE" => CallDelete(types::CallDelete),
+    "THREAD_CREATE" => ThreadCreate(types::ThreadCreate),
+    "THREAD_UPDATE" => ThreadUpdate(types::ThreadUpdate),
+    "THREAD_DELETE" => ThreadDelete(types::ThreadDelete),
+    "THREAD_LIST_SYNC" => ThreadListSync(types::ThreadListSync),
+    "THREAD_MEMBER_UPDATE" => ThreadMemberUpdate(types::ThreadMemberUpdate),
+    "THREAD_MEMBERS_UPDATE" => ThreadMembersUpdate(types::ThreadMembersUpdate),
+    "GUILD_CREATE" => GuildCreate(types::GuildCreate),
+    "GUILD_UPDATE" => GuildUpdate(types::GuildUpdate),
+    "GUILD_DELETE" => GuildDelete(types::GuildDelete),
+    "GUILD_AUDIT_LOG_ENTRY_CREATE" => GuildAuditLogEntryCreate(types::GuildAuditLogEntryCreate),
+    "GUILD_BAN_ADD" => GuildBanAdd(types::GuildBanAdd),
+    "GUILD_BAN_REMOVE" => GuildBanRemove(types::GuildBanRemove),
+    "GUILD_EMOJIS_UPDATE" => GuildEmojisUpdate(types::GuildEmojisUpdate),
+    "GUILD_STICKERS_UPDATE" => GuildStickersUpdate(types::GuildStickersUpdate),
+    "GUILD_INTEGRATIONS_UPDATE" => GuildIntegrationsUpdate(types::GuildIntegrationsUpdate),
+    "GUILD_MEMBER_ADD" => GuildMemberAdd(types::GuildMemberAdd),
+    "GUILD_MEMBER_REMOVE" => GuildMemberRemove(types::GuildMemberRemove),
+    "GUILD_MEMBER_UPDATE" => GuildMemberUpdate(types::GuildMemberUpdate),
+    "GUILD_MEMBERS_CHUNK" => GuildMembersChunk(types::GuildMembersChunk),
+    "GUILD_ROLE_CREATE" => GuildRoleCreate(types::GuildRoleCreate),
+    "GUILD_ROLE_UPDATE" => GuildRoleUpdate(types::GuildRoleUpdate),
+    "GUILD_ROLE_DELETE" => GuildRoleDelete(types::GuildRoleDelete),
+    "GUILD_SCHEDULED_EVENT_CREATE" => GuildScheduledEventCreate(types::GuildScheduledEventCreate),
+    "GUILD_SCHEDULED_EVENT_UPDATE" => GuildScheduledEventUpdate(types::GuildScheduledEventUpdate),
+    "GUILD_SCHEDULED_EVENT_DELETE" => GuildScheduledEventDelete(types::GuildScheduledEventDelete),
+    "GUILD_SCHEDULED_EVENT_USER_ADD" => GuildScheduledEventUserAdd(types::GuildScheduledEventUserAdd),
+    "GUILD_SCHEDULED_EVENT_USER_REMOVE" => GuildScheduledEventUserRemove(types::GuildScheduledEventUserRemove),
+    "PASSIVE_UPDATE_V1" => PassiveUpdateV1(types::PassiveUpdateV1),
+    "INTEGRATION_CREATE" => IntegrationCreate(types::IntegrationCreate),
+    "INTEGRATION_UPDATE" => IntegrationUpdate(types::IntegrationUpdate),
+    "INTEGRATION_DELETE" => IntegrationDelete(types::IntegrationDelete),
+    "INTERACTION_CREATE" => InteractionCreate(types::InteractionCreate),
+    "INVITE_CREATE" => InviteCreate(types::InviteCreate),
+    "INVITE_DELETE" => InviteDelete(types::InviteDelete),
+    "LAST_MESSAGES" => LastMessages(types::LastMessages),
+    "MESSAGE_CREATE" => MessageCreate(types::MessageCreate),
+    "MESSAGE_UPDATE" => MessageUpdate(types::MessageUpdate),
+    "MESSAGE_DELETE" => MessageDelete(types::MessageDelete),
+    "MESSAGE_DELETE_BULK" => MessageDeleteBulk(types::MessageDeleteBulk),
+    "MESSAGE_REACTION_ADD" => MessageReactionAdd(types::MessageReactionAdd),
+    "MESSAGE_REACTION_REMOVE" => MessageReactionRemove(types::MessageReactionRemove),
+    "MESSAGE_REACTION_REMOVE_ALL" => MessageReactionRemoveAll(types::MessageReactionRemoveAll),
+    "MESSAGE_REACTION_REMOVE_EMOJI" => MessageReactionRemoveEmoji(types::MessageReactionRemoveEmoji),
+    "RECENT_MENTION_DELETE" => RecentMentionDelete(types::RecentMentionDelete),
+    "MESSAGE_ACK" => MessageAck(types::MessageACK),
+    "PRESENCE_UPDATE" => PresenceUpdate(types::PresenceUpdate),
+    "RELATIONSHIP_ADD" => RelationshipAdd(types::RelationshipAdd),
+    "RELATIONSHIP_REMOVE" => RelationshipRemove(types::RelationshipRemove),
+    "STAGE_INSTANCE_CREATE" => StageInstanceCreate(types::StageInstanceCreate),
+    "STAGE_INSTANCE_UPDATE" => StageInstanceUpdate(types::StageInstanceUpdate),
+    "STAGE_INSTANCE_DELETE" => StageInstanceDelete(types::StageInstanceDelete),
+    "TYPING_START" => TypingStart(types::TypingStartEvent),
+    "USER_UPDATE" => UserUpdate(types::UserUpdate),
+    "USER_CONNECTIONS_UPDATE" => UserConnectionsUpdate(types::UserConnectionsUpdate),
+    "USER_NOTE_UPDATE" => UserNoteUpdate(types::UserNoteUpdate),
+    "USER_GUILD_SETTINGS_UPDATE" => UserGuildSettingsUpdate(types::UserGuildSettingsUpdate),
+    "VOICE_STATE_UPDATE" => VoiceStateUpdate(types::VoiceStateUpdate),
+    "VOICE_SERVER_UPDATE" => VoiceServerUpdate(types::VoiceServerUpdate),
+    "WEBHOOKS_UPDATE" => WebhooksUpdate(types::WebhooksUpdate),
+);
+
+impl FromGatewayDispatch for types::SessionsReplace {
+    fn matches(dispatch: &GatewayDispatch) -> bool {
+        dispatch.event_name == "SESSIONS_REPLACE"
+    }
+
+    fn from_dispatch(dispatch: GatewayDispatch) -> Option<Self> {
+        if !Self::matches(&dispatch) {
+            return None;
+        }
+        parse_sessions_replace(&dispatch)
+    }
+}
+
+/// [types::SessionsReplace] is sent as a bare array of sessions
+fn parse_sessions_replace(dispatch: &GatewayDispatch) -> Option<types::SessionsReplace> {
+    parse_dispatch(dispatch).map(|sessions| types::SessionsReplace { sessions })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Debug, Default)]
+/// What an [EventStream] does when an event arrives while its buffer is full
+pub enum EventStreamOverflow {
+    /// Drop the oldest buffered event to make room for the new one
+    #[default]
+    DropOldest,
+    /// Drop the new event
+    DropNewest,
+    /// Wait until the stream is polled and there is room in the buffer.
+    ///
+    /// Note that this holds up the whole gateway connection, including all other subscribers,
+    /// until the stream has caught up.
+    Wait,
+}
+
+/// The default amount of events an [EventStream] buffers
+pub const DEFAULT_EVENT_STREAM_CAPACITY: usize = 256;
+
+type DispatchFilter = Box<dyn Fn(&GatewayDispatch) -> bool + Send + Sync>;
+
+/// The buffer shared between an [EventStream] and the subscriber feeding it
+struct EventStreamState {
+    queue: VecDeque<GatewayDispatch>,
+    capacity: usize,
+    overflow: EventStreamOverflow,
+    filters: Vec<DispatchFilter>,
+    /// How many events were dropped because the buffer was full
+    dropped: u64,
+    /// Set once the stream is dropped, so the subscriber stops buffering
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl EventStreamState {
+    fn matches(&self, dispatch: &GatewayDispatch) -> bool {
+        self.filters.iter().all(|filter| filter(dispatch))
+    }
+
+    fn push(&mut self, dispatch: GatewayDispatch) {
+        self.queue.push_back(dispatch);
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+struct EventStreamShared {
+    state: StdMutex<EventStreamState>,
+    /// Notified when there is room in the buffer again, for [EventStreamOverflow::Wait]
+    space: Notify,
+}
+
+/// Feeds the [GatewayDispatch]es of a gateway connection into an [EventStream]
+struct EventStreamSubscriber {
+    shared: Arc<EventStreamShared>,
+}
+
+#[async_trait]
+impl Subscriber<GatewayDispatch> for EventStreamSubscriber {
+    async fn update(&self, dispatch: &GatewayDispatch) {
+        loop {
+            let space = self.shared.space.notified();
+
+            {
+                let mut state = self.shared.state.lock().unwrap();
+
+                if state.closed || !state.matches(dispatch) {
+                    return;
+                }
+
+                if state.queue.len() < state.capacity {
+                    state.push(dispatch.clone());
+                    return;
+                }
+
+                match state.overflow {
+                    EventStreamOverflow::DropOldest => {
+                        state.queue.pop_front();
+                        state.dropped += 1;
+                        state.push(dispatch.clone());
+                        return;
+                    }
+                    EventStreamOverflow::DropNewest => {
+                        state.dropped += 1;
+                        return;
+                    }
+                    EventStreamOverflow::Wait => {}
+                }
+            }
+
+            space.await;
+        }
+    }
+}
+
+/// An async [Stream] of events received on a gateway connection.
+///
+/// Created with [GatewayHandle::event_stream] for all events as [GatewayEvent]s, or with
+/// [GatewayHandle::stream] for one event type.
+///
+/// The stream ends once the gateway connection is closed and all buffered events have been
+/// yielded. Dropping the stream unsubscribes it from the connection's events.
+///
+/// Events are buffered until the stream is polled. The buffer holds
+/// [DEFAULT_EVENT_STREAM_CAPACITY] events by default; what happens when it is full is decided
+/// by its [EventStreamOverflow] policy, see [EventStream::with_buffer]. Filters, such as
+/// [EventStream::in_guild], are applied before events are buffered.
+///
+/// # Example
+/// ```no_run
+/// # async fn run(gateway: chorus::gateway::GatewayHandle, channel_id: chorus::types::Snowflake) {
+/// use futures_util::StreamExt;
+/// use chorus::types::MessageCreate;
+///
+/// let mut messages = gateway.stream::<MessageCreate>().await.in_channel(channel_id);
+///
+/// while let Some(message) = messages.next().await {
+///     println!("{:?}", message.message.content);
+/// }
+/// # }
+/// ```
+pub struct EventStream<T> {
+    shared: Arc<EventStreamShared>,
+    subscriber: Arc<EventStreamSubscriber>,
+    /// The events the subscriber was added to, to remove it when the stream is dropped
+    events: Weak<Mutex<Events>>,
+    /// Resolves once the gateway connection is closed; [None] once it has
+    gateway_closed: StdMutex<Option<BoxFuture<'static, ()>>>,
+    _event: PhantomData<fn() -> T>,
+}
+
+impl<T> Debug for EventStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.shared.state.lock().unwrap();
+        f.debug_struct("EventStream")
+            .field("buffered", &state.queue.len())
+            .field("capacity", &state.capacity)
+            .field("overflow", &state.overflow)
+            .field("dropped", &state.dropped)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: FromGatewayDispatch> EventStream<T> {
+    /// Creates a new stream and subscribes it to the given events.
+    ///
+    /// The stream ends once a message is sent on `kill_receive`, or once its sender is dropped.
+    pub(crate) async fn subscribe(
+        events: &Arc<Mutex<Events>>,
+        mut kill_receive: broadcast::Receiver<()>,
+    ) -> EventStream<T> {
+        let shared = Arc::new(EventStreamShared {
+            state: StdMutex::new(EventStreamState {
+                queue: VecDeque::new(),
+                capacity: DEFAULT_EVENT_STREAM_CAPACITY,
+                overflow: EventStreamOverflow::default(),
+                filters: vec![Box::new(T::matches)],
+                dropped: 0,
+                closed: false,
+                waker: None,
+            }),
+            space: Notify::new(),
+        });
+
+        let subscriber = Arc::new(EventStreamSubscriber {
+            shared: shared.clone(),
+        });
+        events.lock().await.dispatch.subscribe(subscriber.clone());
+
+        let gateway_closed = async move {
+            // Errors mean the gateway is gone as well
+            let _ = kill_receive.recv().await;
+        };
+
+        EventStream {
+            shared,
+            subscriber,
+            events: Arc::downgrade(events),
+            gateway_closed: StdMutex::new(Some(gateway_closed.boxed())),
+            _event: PhantomData,
+        }
+    }
+
+    /// Sets how many events are buffered and what happens when the buffer is full
+    pub fn with_buffer(self, capacity: usize, overflow: EventStreamOverflow) -> Self {
+        {
+            let mut state = self.shared.state.lock().unwrap();
+            state.capacity = capacity.max(1);
+            state.overflow = overflow;
+        }
+        self.shared.space.notify_waiters();
+        self
+    }
+
+    /// Only yields events for which the filter returns true
+    pub fn filter_dispatch(
+        self,
+        filter: impl Fn(&GatewayDispatch) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        {
+            let mut state = self.shared.state.lock().unwrap();
+            state.queue.retain(&filter);
+            state.filters.push(Box::new(filter));
+        }
+        self.shared.space.notify_waiters();
+        self
+    }
+
+    /// Only yields events which happened in the given guild
+    pub fn in_guild(self, guild_id: Snowflake) -> Self {
+        self.filter_dispatch(move |dispatch| dispatch_guild_id(dispatch) == Some(guild_id))
+    }
+
+    /// Only yields events which happened in the given channel.
+    ///
+    /// For channel and thread events, this is the channel which was created, updated or deleted.
+    pub fn in_channel(self, channel_id: Snowflake) -> Self {
+        self.filter_dispatch(move |dispatch| dispatch_channel_id(dispatch) == Some(channel_id))
+    }
+
+    /// Only yields events caused by the given user, such as the author of a message
+    pub fn from_author(self, user_id: Snowflake) -> Self {
+        self.filter_dispatch(move |dispatch| dispatch_author_id(dispatch) == Some(user_id))
+    }
+
+    /// Returns how many events were dropped because the buffer was full
+    pub fn dropped(&self) -> u64 {
+        self.shared.state.lock().unwrap().dropped
+    }
+}
+
+impl<T: FromGatewayDispatch> Stream for EventStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let dispatch = {
+                let mut state = this.shared.state.lock().unwrap();
+                match state.queue.pop_front() {
+                    Some(dispatch) => dispatch,
+                    None => {
+                        let gateway_closed = this.gateway_closed.get_mut().unwrap();
+                        let closed = match gateway_closed {
+                            Some(future) => future.poll_unpin(cx).is_ready(),
+                            None => true,
+                        };
+                        if closed {
+                            *gateway_closed = None;
+                            return Poll::Ready(None);
+                        }
+
+                        state.waker = Some(cx.waker().clone());
+                        return Poll::Pending;
+                    }
+                }
+            };
+
+            this.shared.space.notify_one();
+
+            if let Some(event) = T::from_dispatch(dispatch) {
+                return Poll::Ready(Some(event));
+            }
+        }
+    }
+}
+
+impl<T> Drop for EventStream<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.closed = true;
+        state.queue.clear();
+        drop(state);
+        self.shared.space.notify_waiters();
+
+        let Some(events) = self.events.upgrade() else {
+            return;
+        };
+        let subscriber: Arc<dyn Subscriber<GatewayDispatch>> = self.subscriber.clone();
+
+        // The events are locked while they are being published, in which case the subscriber
+        // (which ignores everything from now on) is removed once they are done
+        if let Ok(mut events) = events.try_lock() {
+            events.dispatch.unsubscribe(subscriber);
+            return;
+        }
+        let unsubscribe = async move {
+            events.lock().await.dispatch.unsubscribe(subscriber);
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(unsubscribe);
+        }
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(unsubscribe);
+    }
+}
+
+/// Reads a snowflake from a field of a dispatch's data
+fn snowflake_field(data: &serde_json::Value, field: &str) -> Option<Snowflake> {
+    serde_json::from_value(data.get(field)?.clone()).ok()
+}
+
+/// Returns the guild a dispatch happened in, if any
+fn dispatch_guild_id(dispatch: &GatewayDispatch) -> Option<Snowflake> {
+    snowflake_field(&dispatch.data, "guild_id").or_else(|| {
+        // Guild events have the guild as their data
+        if dispatch.event_name.starts_with("GUILD_") && !dispatch.event_name.contains("MEMBER") {
+            snowflake_field(&dispatch.data, "id")
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the channel a dispatch happened in, if any
+fn dispatch_channel_id(dispatch: &GatewayDispatch) -> Option<Snowflake> {
+    snowflake_field(&dispatch.data, "channel_id").or_else(|| {
+        // Channel and thread events have the channel as their data
+        if dispatch.event_name.starts_with("CHANNEL_") || dispatch.event_name.starts_with("THREAD_")
+        {
+            snowflake_field(&dispatch.data, "id")
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the user who caused a dispatch, if any
+fn dispatch_author_id(dispatch: &GatewayDispatch) -> Option<Snowflake> {
+    let data = &dispatch.data;
+
+    data.get("author")
+        .and_then(|author| snowflake_field(author, "id"))
+        .or_else(|| snowflake_field(data, "user_id"))
+        .or_else(|| {
+            data.get("user")
+                .and_then(|user| snowflake_field(user, "id"))
+        })
+        .or_else(|| {
+            data.get("member")
+                .and_then(|member| member.get("user"))
+                .and_then(|user| snowflake_field(user, "id"))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use futures_util::StreamExt;
+    use serde_json::json;
+
+    use super::*;
+
+    fn message_create(channel_id: u64, author_id: u64) -> GatewayDispatch {
+        GatewayDispatch {
+            event_name: "MESSAGE_CREATE".to_string(),
+            sequence_number: None,
+            data: json!({
+                "id": "1",
+                "channel_id": channel_id.to_string(),
+                "author": {"id": author_id.to_string()},
+            }),
+        }
+    }
+
+    async fn publish(events: &Arc<Mutex<Events>>, dispatch: GatewayDispatch) {
+        events.lock().await.dispatch.publish(dispatch).await;
+    }
+
+    #[tokio::test]
+    async fn filters_and_overflow() {
+        let events = Arc::new(Mutex::new(Events::default()));
+        let (_kill_send, kill_receive) = broadcast::channel(1);
+        let mut stream = EventStream::<GatewayDispatch>::subscribe(&events, kill_receive)
+            .await
+            .in_channel(Snowflake(10))
+            .with_buffer(2, EventStreamOverflow::DropOldest);
+
+        for author in 1..=3 {
+            publish(&events, message_create(10, author)).await;
+        }
+        publish(&events, message_create(11, 4)).await;
+
+        assert_eq!(stream.dropped(), 1);
+
+        let first = stream.next().await.unwrap();
+        assert_eq!(dispatch_author_id(&first), Some(Snowflake(2)));
+        let second = stream.next().await.unwrap();
+        assert_eq!(dispatch_author_id(&second), Some(Snowflake(3)));
+    }
+
+    #[tokio::test]
+    async fn unknown_events() {
+        let events = Arc::new(Mutex::new(Events::default()));
+        let (_kill_send, kill_receive) = broadcast::channel(1);
+        let mut stream = EventStream::<GatewayEvent>::subscribe(&events, kill_receive).await;
+
+        let dispatch = GatewayDispatch {
+            event_name: "SOMETHING_NEW".to_string(),
+            sequence_number: Some(1),
+            data: json!({}),
+        };
+        publish(&events, dispatch.clone()).await;
+
+        match stream.next().await.unwrap() {
+            GatewayEvent::Unknown(unknown) => assert_eq!(unknown, dispatch),
+            other => panic!("Expected an unknown event, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn close_and_drop() {
+        let events = Arc::new(Mutex::new(Events::default()));
+        let (kill_send, kill_receive) = broadcast::channel(1);
+        let mut stream = EventStream::<GatewayDispatch>::subscribe(&events, kill_receive).await;
+
+        // Buffered events are still yielded after the gateway closed
+        publish(&events, message_create(10, 1)).await;
+        kill_send.send(()).unwrap();
+        assert!(stream.next().await.is_some());
+        assert!(stream.next().await.is_none());
+        assert!(stream.next().await.is_none());
+
+        assert!(events.lock().await.dispatch.has_subscribers());
+        drop(stream);
+        assert!(!events.lock().await.dispatch.has_subscribers());
+    }
+}
@@ -11,12 +11,17 @@ use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 use chrono::Utc;
+use reqwest::header::HeaderMap;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Certificate;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
-use crate::errors::ChorusResult;
+use crate::errors::{ChorusError, ChorusResult};
 use crate::gateway::{events::Events, Gateway, GatewayHandle, GatewayOptions};
-use crate::ratelimiter::ChorusRequest;
+use crate::ratelimiter::{
+    ChorusRequest, RatelimitBehaviour, RatelimitQueues, RetryPolicy, RouteRatelimits,
+};
 use crate::types::types::subconfigs::limits::rates::RateLimits;
 use crate::types::{
     ClientProperties, GatewayIdentifyPayload, GeneralConfiguration, Limit, LimitType,
@@ -49,18 +54,35 @@ pub struct Instance {
     pub limits_information: Option<LimitsInformation>,
 
     #[serde(skip)]
-    /// The reqwest HTTP request client
+    /// The reqwest HTTP request client, which all requests to the instance are sent with.
+    ///
+    /// It is built from the instance's [HttpConfig], see [Instance::set_http_config].
     pub client: Client,
 
+    #[serde(skip)]
+    pub(crate) http_config: HttpConfig,
+
     #[serde(skip)]
     pub(crate) gateway_options: GatewayOptions,
 
+    #[serde(skip)]
+    pub(crate) ratelimit_behaviour: RatelimitBehaviour,
+
+    #[serde(skip)]
+    pub(crate) retry_policy: RetryPolicy,
+
+    #[serde(skip)]
+    pub(crate) gateway_connect_behaviour: GatewayConnectBehaviour,
+
     #[serde(skip)]
     /// The default gateway [`Events`] new gateway connections will inherit.
     ///
     /// This field can be used to subscribe to events that are received before we get access to the
     /// gateway handle object on new [ChorusUser]s created with [Instance::login_account],
     /// [Instance::login_with_token] and [Instance::register_account]
+    ///
+    /// Observers subscribed here receive each event before the connection's
+    /// [GatewayCache](crate::gateway::GatewayCache) is updated with it.
     pub default_gateway_events: Events,
 }
 
@@ -107,7 +129,22 @@ impl Instance {
         urls: UrlBundle,
         options: Option<GatewayOptions>,
     ) -> ChorusResult<Instance> {
-        let is_limited: Option<LimitsConfiguration> = Instance::is_limited(&urls.api).await?;
+        Instance::from_url_bundle_with_http_config(urls, options, HttpConfig::default()).await
+    }
+
+    /// Creates a new [`Instance`] from the [relevant instance urls](UrlBundle), sending all
+    /// requests with a client built from `http_config`.
+    ///
+    /// See [`Instance::from_url_bundle`]
+    pub async fn from_url_bundle_with_http_config(
+        urls: UrlBundle,
+        options: Option<GatewayOptions>,
+        http_config: HttpConfig,
+    ) -> ChorusResult<Instance> {
+        let client = http_config.build_client()?;
+
+        let is_limited: Option<LimitsConfiguration> =
+            Instance::is_limited_with_client(&urls.api, &client).await?;
         let limit_information;
 
         if let Some(limits_configuration) = is_limited {
@@ -125,8 +162,12 @@ impl Instance {
             // Will be overwritten in the next step
             instance_info: GeneralConfiguration::default(),
             limits_information: limit_information,
-            client: Client::new(),
+            client,
+            http_config,
             gateway_options: options.unwrap_or_default(),
+            ratelimit_behaviour: RatelimitBehaviour::default(),
+            retry_policy: RetryPolicy::default(),
+            gateway_connect_behaviour: GatewayConnectBehaviour::default(),
             // Will also be detected soon
             software: InstanceSoftware::Other,
             default_gateway_events: Events::default(),
@@ -155,13 +196,34 @@ impl Instance {
     ///
     /// Shorthand for `Instance::from_url_bundle(UrlBundle::from_root_domain(root_domain).await?)`.
     pub async fn new(root_url: &str, options: Option<GatewayOptions>) -> ChorusResult<Instance> {
-        let urls = UrlBundle::from_root_url(root_url).await?;
-        Instance::from_url_bundle(urls, options).await
+        Instance::new_with_http_config(root_url, options, HttpConfig::default()).await
+    }
+
+    /// Creates a new [`Instance`] by trying to get the [relevant instance urls](UrlBundle) from a
+    /// root url, sending all requests with a client built from `http_config`.
+    ///
+    /// See [`Instance::new`]
+    pub async fn new_with_http_config(
+        root_url: &str,
+        options: Option<GatewayOptions>,
+        http_config: HttpConfig,
+    ) -> ChorusResult<Instance> {
+        let client = http_config.build_client()?;
+        let urls = UrlBundle::from_root_url_with_client(root_url, &client).await?;
+        Instance::from_url_bundle_with_http_config(urls, options, http_config).await
     }
 
     pub async fn is_limited(api_url: &str) -> ChorusResult<Option<LimitsConfiguration>> {
+        Instance::is_limited_with_client(api_url, &Client::new()).await
+    }
+
+    /// Fetches the instance's rate limit configuration, if it is rate limited, see
+    /// [Instance::is_limited]
+    async fn is_limited_with_client(
+        api_url: &str,
+        client: &Client,
+    ) -> ChorusResult<Option<LimitsConfiguration>> {
         let api_url = UrlBundle::parse_url(api_url);
-        let client = Client::new();
         let request = client
             .get(format!("{}/policies/instance/limits", &api_url))
             .header(http::header::ACCEPT, "application/json")
@@ -212,6 +274,69 @@ impl Instance {
         self.gateway_options = options;
     }
 
+    /// Returns the [`RatelimitBehaviour`] requests to the instance use.
+    pub fn ratelimit_behaviour(&self) -> RatelimitBehaviour {
+        self.ratelimit_behaviour
+    }
+
+    /// Sets what requests to the instance should do when they would exceed a rate limit.
+    ///
+    /// By default, [`ChorusError::RateLimited`](crate::errors::ChorusError::RateLimited) is
+    /// returned right away. See [`RatelimitBehaviour::Wait`] to wait for the rate limit instead.
+    ///
+    /// Note that [ChorusUser]s hold their own copy of the instance; to change the behaviour for
+    /// an existing user, set it on [ChorusUser::belongs_to].
+    pub fn set_ratelimit_behaviour(&mut self, behaviour: RatelimitBehaviour) {
+        self.ratelimit_behaviour = behaviour;
+    }
+
+    /// Returns the [`RetryPolicy`] requests to the instance use.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Sets how requests to the instance are retried after transient failures, such as server
+    /// errors or dropped connections.
+    ///
+    /// By default, requests are not retried.
+    ///
+    /// Note that [ChorusUser]s hold their own copy of the instance; to change the policy for
+    /// an existing user, set it on [ChorusUser::belongs_to].
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Returns whether [ChorusUser]s created by logging in or registering connect to the gateway.
+    pub fn gateway_connect_behaviour(&self) -> GatewayConnectBehaviour {
+        self.gateway_connect_behaviour
+    }
+
+    /// Sets whether [ChorusUser]s created by logging in or registering connect to the gateway.
+    ///
+    /// By default, they connect right away. With [`GatewayConnectBehaviour::Manual`], users only
+    /// use the REST api until [ChorusUser::connect_gateway] is called.
+    pub fn set_gateway_connect_behaviour(&mut self, behaviour: GatewayConnectBehaviour) {
+        self.gateway_connect_behaviour = behaviour;
+    }
+
+    /// Returns the configuration of the client requests to the instance are sent with.
+    pub fn http_config(&self) -> &HttpConfig {
+        &self.http_config
+    }
+
+    /// Replaces the client requests to the instance are sent with by one built from `config`.
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the configuration is invalid, e. g. if the
+    /// proxy url cannot be parsed.
+    ///
+    /// Note that [ChorusUser]s hold their own copy of the instance; to change the client for
+    /// an existing user, set it on [ChorusUser::belongs_to].
+    pub fn set_http_config(&mut self, config: HttpConfig) -> ChorusResult<()> {
+        self.client = config.build_client()?;
+        self.http_config = config;
+        Ok(())
+    }
+
     /// Returns which [`InstanceSoftware`] the instance is running.
     pub fn software(&self) -> InstanceSoftware {
         self.software
@@ -229,6 +354,83 @@ impl Instance {
     }
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Configures the HTTP client an [Instance] sends its requests with, see
+/// [Instance::set_http_config] and [Instance::new_with_http_config].
+///
+/// On `wasm32-unknown-unknown`, the browser decides about proxies, timeouts, trusted
+/// certificates and the HTTP version; only the default headers are used.
+pub struct HttpConfig {
+    /// The url of an HTTP(S) proxy to send all requests through, e. g. `http://proxy:8080`
+    ///
+    /// If [None], the system's proxy settings are used.
+    pub proxy: Option<String>,
+    /// How long to wait for a connection to be established
+    pub connect_timeout: Option<Duration>,
+    /// How long to wait for a whole request, from connecting until the response has been read
+    pub timeout: Option<Duration>,
+    /// PEM encoded root certificates to trust in addition to the built-in ones, e. g. for
+    /// self-hosted instances with a private certificate authority
+    pub root_certificates: Vec<Vec<u8>>,
+    /// Which HTTP version to use
+    pub http_version: HttpVersionPreference,
+    /// Headers sent with every request
+    pub default_headers: HeaderMap,
+}
+
+impl HttpConfig {
+    /// Builds a client with this configuration.
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the proxy url or one of the certificates is
+    /// invalid.
+    pub fn build_client(&self) -> ChorusResult<Client> {
+        let invalid = |e: reqwest::Error| ChorusError::InvalidArguments {
+            error: e.to_string(),
+        };
+
+        let builder = Client::builder().default_headers(self.default_headers.clone());
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let builder = {
+            let mut builder = builder;
+
+            if let Some(proxy) = &self.proxy {
+                builder = builder.proxy(reqwest::Proxy::all(proxy).map_err(invalid)?);
+            }
+            if let Some(timeout) = self.connect_timeout {
+                builder = builder.connect_timeout(timeout);
+            }
+            if let Some(timeout) = self.timeout {
+                builder = builder.timeout(timeout);
+            }
+            for certificate in self.root_certificates.iter() {
+                builder = builder
+                    .add_root_certificate(Certificate::from_pem(certificate).map_err(invalid)?);
+            }
+            match self.http_version {
+                HttpVersionPreference::Negotiate => builder,
+                HttpVersionPreference::Http1Only => builder.http1_only(),
+                HttpVersionPreference::Http2PriorKnowledge => builder.http2_prior_knowledge(),
+            }
+        };
+
+        builder.build().map_err(invalid)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+/// Which HTTP version an [Instance]'s client uses, see [HttpConfig]
+pub enum HttpVersionPreference {
+    /// Use HTTP/2 if the server supports it, and HTTP/1.1 otherwise
+    #[default]
+    Negotiate,
+    /// Only use HTTP/1.1
+    Http1Only,
+    /// Use HTTP/2 right away, without negotiating; only works if the server is known to
+    /// support it
+    Http2PriorKnowledge,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 /// The software implementation the spacebar-compatible instance is running.
 ///
@@ -249,6 +451,20 @@ pub enum InstanceSoftware {
     Other,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+/// Whether [ChorusUser]s created by [Instance::login_account], [Instance::login_with_token],
+/// [Instance::register_account] and [Instance::verify_mfa_login] connect to the gateway.
+pub enum GatewayConnectBehaviour {
+    /// Connect to the gateway and identify right after logging in
+    #[default]
+    OnLogin,
+    /// Only connect when [ChorusUser::connect_gateway] is called.
+    ///
+    /// Until then, the user can only use the REST api, which is useful for stateless workers that
+    /// never need events.
+    Manual,
+}
+
 impl InstanceSoftware {
     /// Returns whether the software supports z-lib stream compression on the gateway
     pub fn supports_gateway_zlib(self) -> bool {
@@ -259,6 +475,15 @@ impl InstanceSoftware {
         }
     }
 
+    /// Returns whether the software supports z-standard stream compression on the gateway
+    pub fn supports_gateway_zstd(self) -> bool {
+        match self {
+            InstanceSoftware::SpacebarTypescript => true,
+            InstanceSoftware::Symfonia => false,
+            InstanceSoftware::Other => true,
+        }
+    }
+
     /// Returns whether the software supports sending data in the Erlang external term format on the gateway
     pub fn supports_gateway_etf(self) -> bool {
         match self {
@@ -285,7 +510,7 @@ impl fmt::Display for Token {
 ///
 /// It is used for most authenticated actions on a Spacebar server.
 ///
-/// It also has its own [Gateway] connection.
+/// It can also have its own [Gateway] connection, see [ChorusUser::connect_gateway].
 pub struct ChorusUser {
     /// A reference to the [Instance] the user is registered on
     pub belongs_to: Shared<Instance>,
@@ -304,14 +529,27 @@ pub struct ChorusUser {
     /// Ratelimit data
     pub limits: Option<HashMap<LimitType, Limit>>,
 
+    /// The queues requests wait in when using [RatelimitBehaviour::Wait]
+    ///
+    /// Shared between clones of the user
+    pub ratelimit_queues: RatelimitQueues,
+
+    /// Ratelimit buckets learned from the headers the server sent
+    ///
+    /// Shared between clones of the user
+    pub route_ratelimits: RouteRatelimits,
+
     /// The user's settings
     pub settings: Shared<UserSettings>,
 
     /// Information about the user
     pub object: Shared<User>,
 
-    /// The user's connection to the gateway
-    pub gateway: GatewayHandle,
+    /// The user's connection to the gateway.
+    ///
+    /// [None] until [ChorusUser::connect_gateway] is called, if the user was created with
+    /// [GatewayConnectBehaviour::Manual].
+    pub gateway: Option<GatewayHandle>,
 }
 
 impl ChorusUser {
@@ -323,6 +561,11 @@ impl ChorusUser {
         self.token = token.to_string();
     }
 
+    /// Returns the [client](Instance::client) of the user's instance, to build requests with.
+    pub(crate) fn http_client(&self) -> Client {
+        self.belongs_to.read().unwrap().client.clone()
+    }
+
     /// Creates a new [ChorusUser] from existing data.
     ///
     /// # Notes
@@ -335,7 +578,7 @@ impl ChorusUser {
         limits: Option<HashMap<LimitType, Limit>>,
         settings: Shared<UserSettings>,
         object: Shared<User>,
-        gateway: GatewayHandle,
+        gateway: Option<GatewayHandle>,
     ) -> ChorusUser {
         ChorusUser {
             belongs_to,
@@ -343,6 +586,8 @@ impl ChorusUser {
             client_properties,
             mfa_token: None,
             limits,
+            ratelimit_queues: RatelimitQueues::default(),
+            route_ratelimits: RouteRatelimits::default(),
             settings,
             object,
             gateway,
@@ -360,16 +605,25 @@ impl ChorusUser {
         token: String,
         received_settings: Option<Shared<UserSettings>>,
     ) -> ChorusResult<()> {
-        self.token = token.clone();
+        self.fetch_login_data(token, received_settings).await?;
 
-		  let instance_default_events = self.belongs_to.read().unwrap().default_gateway_events.clone();
+        if self.connects_on_login() {
+            self.connect_gateway().await?;
+        }
 
-        *self.gateway.events.lock().await = instance_default_events;
+        Ok(())
+    }
 
-        let mut identify = GatewayIdentifyPayload::default_w_client_capabilities();
-        identify.token = token;
-        identify.properties = self.client_properties.clone();
-        self.gateway.send_identify(identify).await;
+    /// Sets the user's token and fetches the user object and settings, without connecting to the
+    /// gateway.
+    ///
+    /// Fails with [ChorusError::TokenExpired] if the token is not valid.
+    pub(crate) async fn fetch_login_data(
+        &mut self,
+        token: String,
+        received_settings: Option<Shared<UserSettings>>,
+    ) -> ChorusResult<()> {
+        self.token = token;
 
         *self.object.write().unwrap() = self.get_current_user().await?;
 
@@ -382,20 +636,93 @@ impl ChorusUser {
         Ok(())
     }
 
+    /// Returns whether the user should connect to the gateway right after logging in, see
+    /// [Instance::set_gateway_connect_behaviour]
+    pub(crate) fn connects_on_login(&self) -> bool {
+        self.belongs_to.read().unwrap().gateway_connect_behaviour
+            == GatewayConnectBehaviour::OnLogin
+    }
+
+    /// Returns the user's gateway connection.
+    ///
+    /// Returns [ChorusError::GatewayNotConnected](crate::errors::ChorusError::GatewayNotConnected)
+    /// if the user has not connected yet, see [ChorusUser::connect_gateway].
+    pub fn gateway_handle(&self) -> ChorusResult<&GatewayHandle> {
+        self.gateway
+            .as_ref()
+            .ok_or(ChorusError::GatewayNotConnected)
+    }
+
+    /// Connects the user to the gateway and identifies, returning the connection.
+    ///
+    /// The connection inherits the instance's [default events](Instance::default_gateway_events).
+    ///
+    /// If the user is already connected, the existing connection is returned.
+    pub async fn connect_gateway(&mut self) -> ChorusResult<&GatewayHandle> {
+        if self.gateway.is_none() {
+            let gateway = self.spawn_gateway(None).await?;
+            gateway.send_identify(self.identify_payload()).await;
+            self.gateway = Some(gateway);
+        }
+
+        self.gateway_handle()
+    }
+
+    /// Opens a new gateway connection to the user's instance, with the instance's gateway options
+    /// and default events, without identifying.
+    ///
+    /// If `resume_gateway_url` is set, the connection is opened to it instead of the instance's
+    /// gateway url, see [Gateway::spawn_with_events].
+    pub(crate) async fn spawn_gateway(
+        &self,
+        resume_gateway_url: Option<&str>,
+    ) -> ChorusResult<GatewayHandle> {
+        let (wss_url, gateway_options, default_events) = {
+            let instance = self.belongs_to.read().unwrap();
+            (
+                instance.urls.wss.clone(),
+                instance.gateway_options,
+                instance.default_gateway_events.clone(),
+            )
+        };
+
+        Gateway::spawn_with_events(
+            &wss_url,
+            resume_gateway_url,
+            gateway_options,
+            default_events,
+        )
+        .await
+        .map_err(|e| ChorusError::GatewayConnectionFailed {
+            error: e.to_string(),
+        })
+    }
+
+    /// Returns the payload the user identifies with on the gateway
+    pub(crate) fn identify_payload(&self) -> GatewayIdentifyPayload {
+        let mut identify = GatewayIdentifyPayload::default_w_client_capabilities();
+        identify.token = self.token.clone();
+        identify.properties = self.client_properties.clone();
+        identify
+    }
+
+    /// Closes the user's gateway connection, if there is one.
+    ///
+    /// The user can still use the REST api, and reconnect with [ChorusUser::connect_gateway].
+    pub async fn disconnect_gateway(&mut self) {
+        if let Some(gateway) = self.gateway.take() {
+            gateway.close().await;
+        }
+    }
+
     /// Creates a new 'shell' of a user. The user does not exist as an object, and exists so that you have
     /// a ChorusUser object to make Rate Limited requests with. This is useful in scenarios like
     /// registering or logging in to the Instance, where you do not yet have a User object, but still
-    /// need to make a RateLimited request. To use the [`GatewayHandle`], you will have to identify
-    /// first.
+    /// need to make a RateLimited request. The shell is not connected to the gateway.
     pub(crate) async fn shell(instance: Shared<Instance>, token: &str) -> ChorusUser {
         let settings = Arc::new(RwLock::new(UserSettings::default()));
         let object = Arc::new(RwLock::new(User::default()));
 
-        let wss_url = &instance.read().unwrap().urls.wss.clone();
-        let gateway_options = instance.read().unwrap().gateway_options;
-
-        // Dummy gateway object
-        let gateway = Gateway::spawn(wss_url, gateway_options).await.unwrap();
         ChorusUser {
             token: token.to_string(),
             client_properties: ClientProperties::default(),
@@ -407,9 +734,11 @@ impl ChorusUser {
                 .limits_information
                 .as_ref()
                 .map(|info| info.ratelimits.clone()),
+            ratelimit_queues: RatelimitQueues::default(),
+            route_ratelimits: RouteRatelimits::default(),
             settings,
             object,
-            gateway,
+            gateway: None,
         }
     }
 
@@ -430,7 +759,8 @@ impl ChorusUser {
     ) -> ChorusResult<()> {
         let endpoint_url = self.belongs_to.read().unwrap().urls.api.clone() + "/mfa/finish";
         let chorus_request = ChorusRequest {
-            request: Client::new()
+            request: self
+                .http_client()
                 .post(endpoint_url)
                 .header("Authorization", self.token())
                 .json(&mfa_verify_schema),
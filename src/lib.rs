@@ -157,6 +157,8 @@ use crate::errors::ChorusError;
 
 #[cfg(feature = "client")]
 pub mod api;
+#[cfg(feature = "client")]
+pub mod client;
 pub mod errors;
 #[cfg(feature = "client")]
 pub mod gateway;
@@ -268,9 +270,20 @@ impl UrlBundle {
     /// stores the CDN and WSS URLs under the `$api/policies/instance/domains` endpoint. If all three
     /// of the above approaches fail, it is very likely that the instance is misconfigured, unreachable, or that
     /// a wrong URL was provided.
+    ///
+    /// The [root](UrlBundle::root) of the returned bundle is the given url, as formatted by
+    /// [UrlBundle::parse_url], not the url of the domains endpoint.
     pub async fn from_root_url(url: &str) -> ChorusResult<UrlBundle> {
+        UrlBundle::from_root_url_with_client(url, &reqwest::Client::new()).await
+    }
+
+    /// Retrieves a `UrlBundle` from an instances' root url like [UrlBundle::from_root_url],
+    /// sending the requests with the given client.
+    pub async fn from_root_url_with_client(
+        url: &str,
+        client: &reqwest::Client,
+    ) -> ChorusResult<UrlBundle> {
         let parsed = UrlBundle::parse_url(url);
-        let client = reqwest::Client::new();
         let request_wellknown = client
             .get(format!("{}/.well-known/spacebar", &parsed))
             .header(http::header::ACCEPT, "application/json")
@@ -279,15 +292,28 @@ impl UrlBundle {
         if response_wellknown.status().is_success() {
             let api_url = response_wellknown.json::<WellKnownResponse>().await?.api;
 
-            UrlBundle::from_api_url(&format!("{}/policies/instance/domains", api_url)).await
+            UrlBundle::from_api_url(
+                &parsed,
+                &format!("{}/policies/instance/domains", api_url),
+                client,
+            )
+            .await
         } else {
-            if let Ok(response_slash_api) =
-                UrlBundle::from_api_url(&format!("{}/api/policies/instance/domains", parsed)).await
+            if let Ok(response_slash_api) = UrlBundle::from_api_url(
+                &parsed,
+                &format!("{}/api/policies/instance/domains", parsed),
+                client,
+            )
+            .await
             {
                 return Ok(response_slash_api);
             }
-            if let Ok(response_api) =
-                UrlBundle::from_api_url(&format!("{}/policies/instance/domains", parsed)).await
+            if let Ok(response_api) = UrlBundle::from_api_url(
+                &parsed,
+                &format!("{}/policies/instance/domains", parsed),
+                client,
+            )
+            .await
             {
                 Ok(response_api)
             } else {
@@ -296,8 +322,11 @@ impl UrlBundle {
         }
     }
 
-    async fn from_api_url(url: &str) -> ChorusResult<UrlBundle> {
-        let client = reqwest::Client::new();
+    async fn from_api_url(
+        root: &str,
+        url: &str,
+        client: &reqwest::Client,
+    ) -> ChorusResult<UrlBundle> {
         let request = client
             .get(url)
             .header(http::header::ACCEPT, "application/json")
@@ -308,7 +337,7 @@ impl UrlBundle {
             .await
         {
             Ok(UrlBundle::new(
-                url,
+                root,
                 &body.api_endpoint,
                 &body.gateway,
                 &body.cdn,
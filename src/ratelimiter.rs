@@ -5,17 +5,25 @@
 //! Ratelimiter and request handling functionality.
 
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use reqwest::{Client, RequestBuilder, Response};
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Method, Request, RequestBuilder, Response};
 use serde::Deserialize;
 use serde_json::from_str;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::sleep;
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::sleep;
 
 use crate::{
     errors::{ChorusError, ChorusResult},
     instance::ChorusUser,
     types::{
-        types::subconfigs::limits::rates::RateLimits, Limit, LimitType, LimitsConfiguration,
-        MfaRequiredSchema,
+        types::subconfigs::limits::rates::RateLimits, ClientProperties, Limit, LimitType,
+        LimitsConfiguration, MfaRequiredSchema,
     },
 };
 
@@ -31,83 +39,448 @@ impl ChorusRequest {
     /// Sends a [`ChorusRequest`]. Checks if the user is rate limited, and if not, sends the request.
     /// If the user is not rate limited and the instance has rate limits enabled, it will update the
     /// rate limits.
-    #[allow(clippy::await_holding_refcell_ref)]
+    ///
+    /// Rate limits are read from the `X-RateLimit-*` headers of the response if the server sends
+    /// them (see [`RouteRatelimits`]), otherwise the instance's configured limits are used.
+    ///
+    /// What happens if the user is rate limited depends on the instance's [`RatelimitBehaviour`].
+    /// Transient failures, such as server errors, are retried according to the instance's
+    /// [`RetryPolicy`].
     pub(crate) async fn send_request(self, user: &mut ChorusUser) -> ChorusResult<Response> {
-        if !ChorusRequest::can_send_request(user, &self.limit_type) {
-            log::info!("Rate limit hit. Bucket: {:?}", self.limit_type);
-            return Err(ChorusError::RateLimited {
-                bucket: format!("{:?}", self.limit_type),
-            });
-        }
-
         let request = self
             .request
-            .header("User-Agent", user.client_properties.user_agent.clone().0);
+            .header("User-Agent", user.client_properties.user_agent.clone().0)
+            .build()?;
+        let route = RouteRatelimits::route_for(&request);
 
-        let client = user.belongs_to.read().unwrap().client.clone();
-        let result = match client.execute(request.build().unwrap()).await {
-            Ok(result) => {
-                log::trace!("Request successful: {:?}", result);
-                result
+        let behaviour = user.belongs_to.read().unwrap().ratelimit_behaviour;
+        match behaviour {
+            RatelimitBehaviour::ReturnError => {
+                if !ChorusRequest::can_send_request(user, &route, &self.limit_type) {
+                    log::info!("Rate limit hit. Bucket: {:?}", self.limit_type);
+                    return Err(ChorusRequest::rate_limited_error(&self.limit_type));
+                }
+                ChorusRequest::execute_request(request, &route, &self.limit_type, user).await
             }
-            Err(error) => {
-                log::warn!("Request failed: {:?}", error);
-                return Err(ChorusError::RequestFailed {
-                    url: error.url().unwrap().to_string(),
-                    error: error.to_string(),
-                });
+            RatelimitBehaviour::Wait { max_wait } => {
+                ChorusRequest::send_request_queued(
+                    request,
+                    &route,
+                    &self.limit_type,
+                    user,
+                    max_wait,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Sends a request using [`RatelimitBehaviour::Wait`].
+    ///
+    /// Waits in the user's queue for the request's bucket, then waits for all relevant rate limits
+    /// to reset before sending the request. If the server still answers with a 429, the request is
+    /// retried after the cooldown, as long as it can be cloned.
+    ///
+    /// Returns [`ChorusError::RateLimited`] if the request would have to wait longer than `max_wait`
+    /// in total.
+    async fn send_request_queued(
+        request: Request,
+        route: &str,
+        limit_type: &LimitType,
+        user: &mut ChorusUser,
+        max_wait: Duration,
+    ) -> ChorusResult<Response> {
+        let started = chrono::Utc::now();
+        let elapsed = || (chrono::Utc::now() - started).to_std().unwrap_or_default();
+
+        // The queue stays locked until the request went out and the rate limits were updated, so
+        // requests in the same bucket are sent in the order they were made and don't overshoot it
+        let queue = user.ratelimit_queues.queue_for(*limit_type);
+        let _queue_guard = tokio::select! {
+            guard = queue.lock() => guard,
+            () = sleep(max_wait) => {
+                log::info!("Timed out waiting in the rate limit queue. Bucket: {:?}", limit_type);
+                return Err(ChorusRequest::rate_limited_error(limit_type));
+            }
+        };
+
+        let mut request = request;
+        loop {
+            if let Some(wait) = ChorusRequest::time_until_sendable(user, route, limit_type) {
+                if elapsed() + wait > max_wait {
+                    log::info!(
+                        "Rate limit hit. Bucket: {:?}. Waiting {:?} would exceed the maximum wait time.",
+                        limit_type,
+                        wait
+                    );
+                    return Err(ChorusRequest::rate_limited_error(limit_type));
+                }
+                log::info!(
+                    "Rate limit hit. Bucket: {:?}. Waiting {:?} for it to reset.",
+                    limit_type,
+                    wait
+                );
+                sleep(wait).await;
+                continue;
             }
+
+            let retry = request.try_clone();
+            match ChorusRequest::execute_request(request, route, limit_type, user).await {
+                Err(ChorusError::RateLimited { .. }) if retry.is_some() => {
+                    request = retry.unwrap();
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Sends the request without checking the rate limits beforehand, then updates them.
+    async fn execute_request(
+        request: Request,
+        route: &str,
+        limit_type: &LimitType,
+        user: &mut ChorusUser,
+    ) -> ChorusResult<Response> {
+        let (client, retry_policy) = {
+            let belongs_to = user.belongs_to.read().unwrap();
+            (belongs_to.client.clone(), belongs_to.retry_policy.clone())
         };
-        drop(client);
+        let result =
+            match ChorusRequest::execute_with_retries(&client, request, &retry_policy).await {
+                Ok(result) => {
+                    log::trace!("Request successful: {:?}", result);
+                    result
+                }
+                Err(error) => {
+                    log::warn!("Request failed: {:?}", error);
+                    return Err(ChorusError::RequestFailed {
+                        url: error.url().unwrap().to_string(),
+                        error: error.to_string(),
+                    });
+                }
+            };
+
+        let headers = RatelimitHeaders::from_headers(result.headers());
+        if let Some(headers) = &headers {
+            user.route_ratelimits.update(route, limit_type, headers);
+        }
+
         if !result.status().is_success() {
             if result.status().as_u16() == 429 {
-                log::warn!("Rate limit hit unexpectedly. Bucket: {:?}. Setting the instances' remaining global limit to 0 to have cooldown.", self.limit_type);
-                user.belongs_to
-                    .write()
-                    .unwrap()
-                    .limits_information
-                    .as_mut()
-                    .unwrap()
-                    .ratelimits
-                    .get_mut(&LimitType::Global)
-                    .unwrap()
-                    .remaining = 0;
-                return Err(ChorusError::RateLimited {
-                    bucket: format!("{:?}", self.limit_type),
-                });
+                ChorusRequest::handle_rate_limited_response(
+                    result, route, limit_type, user, headers,
+                )
+                .await;
+                return Err(ChorusRequest::rate_limited_error(limit_type));
             }
             log::warn!("Request failed: {:?}", result);
             return Err(ChorusRequest::interpret_error(result).await);
         }
-        ChorusRequest::update_rate_limits(user, &self.limit_type, !result.status().is_success());
+
+        if headers
+            .as_ref()
+            .is_some_and(|headers| headers.bucket.is_some())
+        {
+            log::trace!("Rate limits were sent by the server, not updating configured limits.");
+        } else {
+            ChorusRequest::update_rate_limits(user, limit_type, !result.status().is_success());
+        }
         Ok(result)
     }
 
-    fn can_send_request(user: &mut ChorusUser, limit_type: &LimitType) -> bool {
+    /// Sends a [`ChorusRequest`] which is not made on behalf of a [`ChorusUser`], e. g. a request
+    /// authenticated with a webhook token.
+    ///
+    /// Since there is no user (and thus no configured limits), only the rate limits the server sent
+    /// in previous responses (see [`RouteRatelimits`]) are respected.
+    pub(crate) async fn send_anonymous_request(
+        self,
+        client: &Client,
+        route_ratelimits: &RouteRatelimits,
+        behaviour: RatelimitBehaviour,
+        retry_policy: &RetryPolicy,
+    ) -> ChorusResult<Response> {
+        let mut request = self
+            .request
+            .header("User-Agent", ClientProperties::default().user_agent.0)
+            .build()?;
+        let route = RouteRatelimits::route_for(&request);
+        let limit_type = &self.limit_type;
+
+        let started = chrono::Utc::now();
+        let elapsed = || (chrono::Utc::now() - started).to_std().unwrap_or_default();
+
+        loop {
+            if let Some(wait) = route_ratelimits.time_until_sendable(&route, limit_type) {
+                match behaviour {
+                    RatelimitBehaviour::Wait { max_wait } if elapsed() + wait <= max_wait => {
+                        log::info!(
+                            "Rate limit hit. Bucket: {:?}. Waiting {:?} for it to reset.",
+                            limit_type,
+                            wait
+                        );
+                        sleep(wait).await;
+                        continue;
+                    }
+                    _ => {
+                        log::info!("Rate limit hit. Bucket: {:?}", limit_type);
+                        return Err(ChorusRequest::rate_limited_error(limit_type));
+                    }
+                }
+            }
+
+            let retry = match behaviour {
+                RatelimitBehaviour::Wait { .. } => request.try_clone(),
+                RatelimitBehaviour::ReturnError => None,
+            };
+            let result =
+                match ChorusRequest::execute_with_retries(client, request, retry_policy).await {
+                    Ok(result) => result,
+                    Err(error) => {
+                        log::warn!("Request failed: {:?}", error);
+                        return Err(ChorusError::RequestFailed {
+                            url: error.url().map(|url| url.to_string()).unwrap_or_default(),
+                            error: error.to_string(),
+                        });
+                    }
+                };
+
+            let headers = RatelimitHeaders::from_headers(result.headers());
+            if let Some(headers) = &headers {
+                route_ratelimits.update(&route, limit_type, headers);
+            }
+
+            if result.status().is_success() {
+                return Ok(result);
+            }
+            if result.status().as_u16() != 429 {
+                log::warn!("Request failed: {:?}", result);
+                return Err(ChorusRequest::interpret_error(result).await);
+            }
+
+            let headers = headers.unwrap_or_default();
+            let body = result
+                .json::<RateLimitedResponse>()
+                .await
+                .unwrap_or_default();
+            let retry_after = body.retry_after(&headers).unwrap_or(Duration::from_secs(1));
+            log::warn!(
+                "Rate limit hit unexpectedly. Bucket: {:?}. Retrying after {:?}.",
+                limit_type,
+                retry_after
+            );
+            if body.global || headers.global {
+                route_ratelimits.exhaust_global(retry_after);
+            } else {
+                route_ratelimits.exhaust(&route, limit_type, retry_after);
+            }
+
+            match retry {
+                Some(retry) => request = retry,
+                None => return Err(ChorusRequest::rate_limited_error(limit_type)),
+            }
+        }
+    }
+
+    /// Sends the request with the client, retrying transient failures according to the
+    /// [`RetryPolicy`].
+    ///
+    /// Only the response or error of the last attempt is returned; rate limits are left to the
+    /// caller.
+    async fn execute_with_retries(
+        client: &Client,
+        request: Request,
+        policy: &RetryPolicy,
+    ) -> Result<Response, reqwest::Error> {
+        let mut request = request;
+        let mut attempt = 1;
+        loop {
+            let method = request.method().clone();
+            let url = request.url().to_string();
+            // Requests with streamed bodies can't be cloned, and thus not retried
+            let retry = if attempt < policy.max_attempts {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            let result = client.execute(request).await;
+            let Some(retry) = retry else {
+                return result;
+            };
+
+            let reason = match &result {
+                Ok(response) if policy.retries_status(&method, response.status().as_u16()) => {
+                    RetryReason::Status(response.status().as_u16())
+                }
+                Err(error) if policy.retries_error(&method, error) => {
+                    RetryReason::Error(error.to_string())
+                }
+                _ => return result,
+            };
+
+            let delay = policy.delay(attempt);
+            log::warn!(
+                "{} request to {} failed ({:?}). Retrying in {:?} (attempt {} of {}).",
+                method,
+                url,
+                reason,
+                delay,
+                attempt + 1,
+                policy.max_attempts
+            );
+            if let Some(on_retry) = &policy.on_retry {
+                on_retry(&RetryAttempt {
+                    method,
+                    url,
+                    attempt: attempt + 1,
+                    delay,
+                    reason,
+                });
+            }
+
+            sleep(delay).await;
+            request = retry;
+            attempt += 1;
+        }
+    }
+
+    /// Sets up the cooldown after the server unexpectedly answered with a 429.
+    ///
+    /// Uses the `retry_after` the server sent, either in the body or in the headers. If the server
+    /// did not tell us which bucket we exhausted, the instance's configured global limit is used.
+    async fn handle_rate_limited_response(
+        response: Response,
+        route: &str,
+        limit_type: &LimitType,
+        user: &mut ChorusUser,
+        headers: Option<RatelimitHeaders>,
+    ) {
+        let body = response
+            .json::<RateLimitedResponse>()
+            .await
+            .unwrap_or_default();
+        let headers = headers.unwrap_or_default();
+        let retry_after = body.retry_after(&headers);
+
+        if body.global || headers.global {
+            log::warn!(
+                "Global rate limit hit unexpectedly. Bucket: {:?}. Retrying after {:?}.",
+                limit_type,
+                retry_after
+            );
+            user.route_ratelimits
+                .exhaust_global(retry_after.unwrap_or(Duration::from_secs(1)));
+            return;
+        }
+
+        if headers.bucket.is_some() {
+            log::warn!(
+                "Rate limit hit unexpectedly. Bucket: {:?} ({:?}). Retrying after {:?}.",
+                limit_type,
+                headers.bucket,
+                retry_after
+            );
+            if let Some(retry_after) = retry_after {
+                user.route_ratelimits
+                    .exhaust(route, limit_type, retry_after);
+            }
+            return;
+        }
+
+        log::warn!("Rate limit hit unexpectedly. Bucket: {:?}. Setting the instances' remaining global limit to 0 to have cooldown.", limit_type);
+        let time: u64 = chrono::Utc::now().timestamp() as u64;
+        let mut belongs_to = user.belongs_to.write().unwrap();
+        match belongs_to
+            .limits_information
+            .as_mut()
+            .and_then(|information| information.ratelimits.get_mut(&LimitType::Global))
+        {
+            Some(global) => {
+                global.remaining = 0;
+                // Make sure the cooldown doesn't end before the server wants it to
+                let retry_at = match retry_after {
+                    Some(retry_after) => time + retry_after.as_secs_f64().ceil() as u64,
+                    None => time + global.window,
+                };
+                global.reset = global.reset.max(retry_at);
+            }
+            None => {
+                if let Some(retry_after) = retry_after {
+                    user.route_ratelimits.exhaust_global(retry_after);
+                }
+            }
+        }
+    }
+
+    fn rate_limited_error(limit_type: &LimitType) -> ChorusError {
+        ChorusError::RateLimited {
+            bucket: format!("{:?}", limit_type),
+        }
+    }
+
+    fn can_send_request(user: &mut ChorusUser, route: &str, limit_type: &LimitType) -> bool {
+        ChorusRequest::time_until_sendable(user, route, limit_type).is_none()
+    }
+
+    /// Checks whether a request to a route can be sent right now.
+    ///
+    /// If the server told us which bucket the route belongs to, only the [`RouteRatelimits`] are
+    /// checked. Otherwise, the instance's configured limits are checked as well (see
+    /// [`Self::time_until_sendable_configured`]).
+    ///
+    /// Returns [`None`] if the request can be sent, or how long to wait otherwise.
+    fn time_until_sendable(
+        user: &mut ChorusUser,
+        route: &str,
+        limit_type: &LimitType,
+    ) -> Option<Duration> {
+        let learned = user.route_ratelimits.time_until_sendable(route, limit_type);
+        if user.route_ratelimits.knows_route(route) {
+            return learned;
+        }
+        let configured = ChorusRequest::time_until_sendable_configured(user, limit_type);
+        learned.max(configured)
+    }
+
+    /// Checks whether a request of the given [`LimitType`] can be sent right now, according to the
+    /// instance's configured limits.
+    ///
+    /// Replenishes all relevant rate limits whose reset time has passed. Returns [`None`] if the
+    /// request can be sent, or how long to wait until all relevant rate limits reset otherwise.
+    fn time_until_sendable_configured(
+        user: &mut ChorusUser,
+        limit_type: &LimitType,
+    ) -> Option<Duration> {
         log::trace!("Checking if user or instance is rate-limited...");
         let mut belongs_to = user.belongs_to.write().unwrap();
-        if belongs_to.limits_information.is_none() {
+        let Some(limits_information) = belongs_to.limits_information.as_mut() else {
             log::trace!("Instance indicates no rate limits are configured. Continuing.");
-            return true;
+            return None;
+        };
+
+        let time: u64 = chrono::Utc::now().timestamp() as u64;
+        let mut blocked_until: Option<u64> = None;
+        let mut check_limit = |limit: &mut Limit| {
+            ChorusRequest::replenish_if_expired(limit, time);
+            if limit.remaining == 0 {
+                blocked_until = Some(blocked_until.unwrap_or_default().max(limit.reset));
+            }
+        };
+
+        for instance_limit in [LimitType::Global, LimitType::Ip] {
+            if let Some(limit) = limits_information.ratelimits.get_mut(&instance_limit) {
+                check_limit(limit);
+            }
         }
-        let instance_dictated_limits = [
-            &LimitType::AuthLogin,
-            &LimitType::AuthRegister,
-            &LimitType::Global,
-            &LimitType::Ip,
-        ];
-        let limits = match instance_dictated_limits.contains(&limit_type) {
+
+        let limit_type_limit = match INSTANCE_DICTATED_LIMITS.contains(limit_type) {
             true => {
                 log::trace!(
                     "Limit type {:?} is dictated by the instance. Continuing.",
                     limit_type
                 );
-                belongs_to
-                    .limits_information
-                    .as_mut()
-                    .unwrap()
-                    .ratelimits
-                    .clone()
+                limits_information.ratelimits.get_mut(limit_type)
             }
             false => {
                 log::trace!(
@@ -115,33 +488,36 @@ impl ChorusRequest {
                     limit_type
                 );
                 ChorusRequest::ensure_limit_in_map(
-                    &belongs_to
-                        .limits_information
-                        .as_ref()
-                        .unwrap()
-                        .configuration,
+                    &limits_information.configuration,
                     user.limits.as_mut().unwrap(),
                     limit_type,
                 );
-                user.limits.as_mut().unwrap().clone()
+                user.limits.as_mut().unwrap().get_mut(limit_type)
             }
         };
-        let global = belongs_to
-            .limits_information
-            .as_ref()
-            .unwrap()
-            .ratelimits
-            .get(&LimitType::Global)
-            .unwrap();
-        let ip = belongs_to
-            .limits_information
-            .as_ref()
-            .unwrap()
-            .ratelimits
-            .get(&LimitType::Ip)
-            .unwrap();
-        let limit_type_limit = limits.get(limit_type).unwrap();
-        global.remaining > 0 && ip.remaining > 0 && limit_type_limit.remaining > 0
+        if let Some(limit) = limit_type_limit {
+            check_limit(limit);
+        }
+
+        // Limits are replenished once the current second is past their reset timestamp
+        blocked_until.map(|reset| {
+            let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+            Duration::from_millis(((reset + 1) * 1000).saturating_sub(now_millis))
+        })
+    }
+
+    /// Resets a [`Limit`]'s remaining requests if its reset time has passed.
+    fn replenish_if_expired(limit: &mut Limit, time: u64) {
+        if time > limit.reset {
+            // Without rate limit information in the response headers (which Spacebar does not
+            // send yet), we have to guess the next rate limit window. This is not ideal. Oh well!
+            log::trace!("Rate limit replenished. Bucket: {:?}", limit.bucket);
+            limit.reset += limit.window;
+            if limit.reset < time {
+                limit.reset = time + limit.window;
+            }
+            limit.remaining = limit.limit;
+        }
     }
 
     fn ensure_limit_in_map(
@@ -257,15 +633,9 @@ impl ChorusRequest {
         if user.belongs_to.read().unwrap().limits_information.is_none() {
             return;
         }
-        let instance_dictated_limits = [
-            &LimitType::AuthLogin,
-            &LimitType::AuthRegister,
-            &LimitType::Global,
-            &LimitType::Ip,
-        ];
         // modify this to store something to look up the value with later, instead of storing a reference to the actual data itself.
         let mut relevant_limits = Vec::new();
-        if instance_dictated_limits.contains(&limit_type) {
+        if INSTANCE_DICTATED_LIMITS.contains(limit_type) {
             relevant_limits.push((LimitOrigin::Instance, *limit_type));
         } else {
             relevant_limits.push((LimitOrigin::User, *limit_type));
@@ -294,21 +664,25 @@ impl ChorusRequest {
                 }
                 LimitOrigin::User => {
                     log::trace!("Updating user rate limit. Bucket: {:?}", relevant_limit.1);
-                    user.limits
-                        .as_mut()
-                        .unwrap()
-                        .get_mut(&relevant_limit.1)
-                        .unwrap()
+                    let Some(limits) = user.limits.as_mut() else {
+                        continue;
+                    };
+                    // The limit may not have been checked before sending, e. g. if the route's
+                    // bucket was learned from an earlier response
+                    ChorusRequest::ensure_limit_in_map(
+                        &belongs_to
+                            .limits_information
+                            .as_ref()
+                            .unwrap()
+                            .configuration,
+                        limits,
+                        &relevant_limit.1,
+                    );
+                    limits.get_mut(&relevant_limit.1).unwrap()
                 }
             };
-            if time > limit.reset {
-                // Spacebar does not yet return rate limit information in its response headers. We
-                // therefore have to guess the next rate limit window. This is not ideal. Oh well!
-                log::trace!("Rate limit replenished. Bucket: {:?}", limit.bucket);
-                limit.reset += limit.window;
-                limit.remaining = limit.limit;
-            }
-            limit.remaining -= 1;
+            ChorusRequest::replenish_if_expired(limit, time);
+            limit.remaining = limit.remaining.saturating_sub(1);
         }
     }
 
@@ -320,7 +694,16 @@ impl ChorusRequest {
     /// # Reference
     /// See <https://docs.spacebar.chat/routes/#get-/policies/instance/limits/>
     pub async fn get_limits_config(url_api: &str) -> ChorusResult<LimitsConfiguration> {
-        let request = Client::new()
+        ChorusRequest::get_limits_config_with_client(url_api, &Client::new()).await
+    }
+
+    /// Gets the ratelimit configuration like [ChorusRequest::get_limits_config], sending the
+    /// request with the given client, e. g. an [Instance](crate::instance::Instance)'s.
+    pub async fn get_limits_config_with_client(
+        url_api: &str,
+        client: &Client,
+    ) -> ChorusResult<LimitsConfiguration> {
+        let request = client
             .get(format!("{}/policies/instance/limits/", url_api))
             .send()
             .await;
@@ -460,6 +843,13 @@ impl ChorusRequest {
         user: &mut ChorusUser,
     ) -> ChorusResult<T> {
         let response = self.send_request(user).await?;
+        ChorusRequest::deserialize_body(response).await
+    }
+
+    /// Reads the body of a successful response and deserializes it into a [`T`].
+    pub(crate) async fn deserialize_body<T: for<'a> Deserialize<'a>>(
+        response: Response,
+    ) -> ChorusResult<T> {
         log::trace!("Got response: {:?}", response);
         let response_text = match response.text().await {
             Ok(string) => string,
@@ -537,7 +927,427 @@ impl ChorusRequest {
     }
 }
 
+/// The [`LimitType`]s whose limits are shared by all users of an instance.
+const INSTANCE_DICTATED_LIMITS: [LimitType; 4] = [
+    LimitType::AuthLogin,
+    LimitType::AuthRegister,
+    LimitType::Global,
+    LimitType::Ip,
+];
+
+/// How [`ChorusRequest`]s behave when they would exceed a rate limit.
+///
+/// Configured per [Instance](crate::instance::Instance), see
+/// [Instance::set_ratelimit_behaviour](crate::instance::Instance::set_ratelimit_behaviour).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RatelimitBehaviour {
+    /// Return [`ChorusError::RateLimited`] right away.
+    #[default]
+    ReturnError,
+    /// Wait until the rate limit resets, then send the request.
+    ///
+    /// Requests from the same [`ChorusUser`] (and its clones) wait in a queue per bucket, and are
+    /// sent in the order they were made.
+    ///
+    /// If a request would have to wait longer than `max_wait` in total, [`ChorusError::RateLimited`]
+    /// is returned instead.
+    Wait { max_wait: Duration },
+}
+
+/// Called before a request is retried, see [`RetryPolicy::on_retry`]
+pub type RetryHook = Arc<dyn Fn(&RetryAttempt) + Send + Sync>;
+
+/// How [`ChorusRequest`]s are retried after transient failures, such as server errors or
+/// dropped connections.
+///
+/// Configured per [Instance](crate::instance::Instance), see
+/// [Instance::set_retry_policy](crate::instance::Instance::set_retry_policy). By default,
+/// requests are not retried.
+///
+/// Before each retry, the request waits for an exponentially growing backoff, shortened by a
+/// random amount (see [`RetryPolicy::jitter`]) so that many clients don't retry all at once.
+///
+/// Since a `POST` or `PATCH` request may already have been applied when the server failed or
+/// the connection was lost, only idempotent requests (see [`RetryPolicy::is_idempotent`]) are
+/// retried after a response or a timeout, unless `retry_non_idempotent` is set. Requests which
+/// could not connect to the server never reached it, and are always retried.
+///
+/// Rate limits are not handled here, see [`RatelimitBehaviour`].
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// use chorus::ratelimiter::RetryPolicy;
+///
+/// let policy = RetryPolicy {
+///     max_attempts: 3,
+///     initial_backoff: Duration::from_millis(200),
+///     ..Default::default()
+/// };
+/// assert_eq!(policy.backoff(2), Duration::from_millis(400));
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// How often a request is sent at most, including the first attempt
+    ///
+    /// Default: 1, so requests are not retried
+    pub max_attempts: u32,
+    /// How long to wait before the first retry; doubled for every following one
+    ///
+    /// Default: 500 milliseconds
+    pub initial_backoff: Duration,
+    /// The longest time to wait before a retry
+    ///
+    /// Default: 10 seconds
+    pub max_backoff: Duration,
+    /// Which fraction of the backoff may randomly be cut off, between 0 (always wait the full
+    /// backoff) and 1
+    ///
+    /// Default: 0.5
+    pub jitter: f64,
+    /// The response status codes which are retried
+    ///
+    /// Default: 500, 502, 503 and 504
+    pub retry_status_codes: Vec<u16>,
+    /// Whether requests which failed without a response, e. g. because of a timeout or a
+    /// dropped connection, are retried
+    ///
+    /// Default: true
+    pub retry_network_errors: bool,
+    /// Whether requests which are not idempotent are retried after a response or a timeout
+    ///
+    /// Default: false
+    pub retry_non_idempotent: bool,
+    /// Called before every retry, e. g. to record metrics
+    ///
+    /// Retries are also logged as warnings.
+    pub on_retry: Option<RetryHook>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            jitter: 0.5,
+            retry_status_codes: vec![500, 502, 503, 504],
+            retry_network_errors: true,
+            retry_non_idempotent: false,
+            on_retry: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("jitter", &self.jitter)
+            .field("retry_status_codes", &self.retry_status_codes)
+            .field("retry_network_errors", &self.retry_network_errors)
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
+            .field("on_retry", &self.on_retry.is_some())
+            .finish()
+    }
+}
+
+impl RetryPolicy {
+    /// Returns whether sending a request with this method more than once has the same effect as
+    /// sending it once, which is the case for all methods but `POST`, `PATCH` and `CONNECT`.
+    pub fn is_idempotent(method: &Method) -> bool {
+        !matches!(*method, Method::POST | Method::PATCH | Method::CONNECT)
+    }
+
+    /// Returns the backoff before the `retry`th retry, without jitter.
+    ///
+    /// The first retry waits for `initial_backoff`, every following one twice as long, up to
+    /// `max_backoff`.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Returns the backoff before the `retry`th retry, with a random part cut off
+    fn delay(&self, retry: u32) -> Duration {
+        let jitter = self.jitter.clamp(0.0, 1.0) * rand::random::<f64>();
+        self.backoff(retry).mul_f64(1.0 - jitter)
+    }
+
+    /// Returns whether a request is retried after the server answered with `status`
+    fn retries_status(&self, method: &Method, status: u16) -> bool {
+        self.retry_status_codes.contains(&status)
+            && (self.retry_non_idempotent || RetryPolicy::is_idempotent(method))
+    }
+
+    /// Returns whether a request is retried after it failed without a response
+    fn retries_error(&self, method: &Method, error: &reqwest::Error) -> bool {
+        if !self.retry_network_errors || error.is_builder() || error.is_redirect() {
+            return false;
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if error.is_connect() {
+            return true;
+        }
+
+        self.retry_non_idempotent || RetryPolicy::is_idempotent(method)
+    }
+}
+
+/// A retry of a request, passed to the [`RetryPolicy::on_retry`] hook
+#[derive(Debug, Clone)]
+pub struct RetryAttempt {
+    pub method: Method,
+    pub url: String,
+    /// Which attempt the retry is; the first retry is attempt 2
+    pub attempt: u32,
+    /// How long the request waits before it is retried
+    pub delay: Duration,
+    pub reason: RetryReason,
+}
+
+/// Why a request is retried, see [`RetryAttempt`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetryReason {
+    /// The server answered with a retryable status code
+    Status(u16),
+    /// The request failed without a response
+    Error(String),
+}
+
+/// The per-bucket queues requests wait in when using [`RatelimitBehaviour::Wait`].
+///
+/// Clones share the same queues, so that concurrent tasks using clones of one [`ChorusUser`] wait
+/// in line together.
+#[derive(Debug, Clone, Default)]
+pub struct RatelimitQueues {
+    queues: Arc<Mutex<HashMap<LimitType, Arc<tokio::sync::Mutex<()>>>>>,
+}
+
+impl RatelimitQueues {
+    /// Returns the queue for a bucket, creating it if it doesn't exist yet.
+    fn queue_for(&self, bucket: LimitType) -> Arc<tokio::sync::Mutex<()>> {
+        self.queues
+            .lock()
+            .unwrap()
+            .entry(bucket)
+            .or_default()
+            .clone()
+    }
+}
+
 enum LimitOrigin {
     Instance,
     User,
 }
+
+/// Rate limit information the server sent in the `X-RateLimit-*` and `Retry-After` headers of a
+/// response.
+///
+/// See <https://discord.com/developers/docs/topics/rate-limits#header-format> for more information.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RatelimitHeaders {
+    /// A unique string denoting the rate limit bucket, sent as `X-RateLimit-Bucket`
+    pub bucket: Option<String>,
+    /// The number of requests that can be made, sent as `X-RateLimit-Limit`
+    pub limit: Option<u64>,
+    /// The number of remaining requests that can be made, sent as `X-RateLimit-Remaining`
+    pub remaining: Option<u64>,
+    /// How long until the rate limit resets, sent as `X-RateLimit-Reset-After`
+    pub reset_after: Option<Duration>,
+    /// Whether the global rate limit was hit, sent as `X-RateLimit-Global`
+    pub global: bool,
+    /// How long to wait before retrying, sent as `Retry-After` on 429 responses
+    pub retry_after: Option<Duration>,
+}
+
+impl RatelimitHeaders {
+    /// Parses the rate limit headers of a response.
+    ///
+    /// Returns [`None`] if the response did not contain any rate limit headers.
+    pub fn from_headers(headers: &HeaderMap) -> Option<RatelimitHeaders> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+        };
+        let seconds = |name: &str| {
+            header(name)
+                .and_then(|value| value.parse::<f64>().ok())
+                .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        };
+
+        let parsed = RatelimitHeaders {
+            bucket: header("x-ratelimit-bucket").map(str::to_string),
+            limit: header("x-ratelimit-limit").and_then(|value| value.parse().ok()),
+            remaining: header("x-ratelimit-remaining").and_then(|value| value.parse().ok()),
+            reset_after: seconds("x-ratelimit-reset-after"),
+            global: header("x-ratelimit-global")
+                .is_some_and(|value| value.eq_ignore_ascii_case("true")),
+            retry_after: seconds("retry-after"),
+        };
+
+        if parsed == RatelimitHeaders::default() {
+            return None;
+        }
+        Some(parsed)
+    }
+}
+
+/// The body of a 429 response.
+///
+/// See <https://discord.com/developers/docs/topics/rate-limits#exceeding-a-rate-limit-rate-limit-response-structure>
+#[derive(Debug, Clone, Default, Deserialize)]
+struct RateLimitedResponse {
+    /// The number of seconds to wait before submitting another request
+    retry_after: Option<f64>,
+    /// Whether this is a global rate limit
+    #[serde(default)]
+    global: bool,
+}
+
+impl RateLimitedResponse {
+    /// Returns how long to wait before retrying, preferring the body over the headers
+    fn retry_after(&self, headers: &RatelimitHeaders) -> Option<Duration> {
+        self.retry_after
+            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+            .or(headers.retry_after)
+            .or(headers.reset_after)
+    }
+}
+
+/// The rate limit buckets a [`ChorusUser`] learned from the [`RatelimitHeaders`] the server sent.
+///
+/// Routes are identified by their method and path, with all ids replaced by `:id`. Once the server
+/// tells us which bucket a route belongs to, its state is tracked per bucket and
+/// [`LimitType`] (which acts as the major parameter, e. g. the channel id), and the instance's
+/// configured limits are no longer used for that route.
+///
+/// Clones share the same buckets.
+#[derive(Debug, Clone, Default)]
+pub struct RouteRatelimits {
+    inner: Arc<Mutex<RouteRatelimitsInner>>,
+}
+
+#[derive(Debug, Default)]
+struct RouteRatelimitsInner {
+    /// Which bucket each route belongs to
+    routes: HashMap<String, String>,
+    /// The state of each bucket, per major parameter
+    buckets: HashMap<(String, LimitType), RouteBucket>,
+    /// Until when we hit the global rate limit, if we did
+    global_reset: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RouteBucket {
+    limit: u64,
+    remaining: u64,
+    reset: DateTime<Utc>,
+}
+
+impl RouteRatelimits {
+    /// Returns the route a request is sent to, e. g. `POST /api/channels/:id/messages`
+    pub(crate) fn route_for(request: &Request) -> String {
+        let path = request
+            .url()
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .map(|segment| match segment.parse::<u64>() {
+                        Ok(_) => ":id",
+                        Err(_) => segment,
+                    })
+                    .collect::<Vec<&str>>()
+                    .join("/")
+            })
+            .unwrap_or_default();
+        format!("{} /{}", request.method(), path)
+    }
+
+    /// Returns whether the server told us which bucket a route belongs to.
+    pub fn knows_route(&self, route: &str) -> bool {
+        self.inner.lock().unwrap().routes.contains_key(route)
+    }
+
+    /// Returns the bucket the server told us a route belongs to, if any.
+    pub fn bucket_of(&self, route: &str) -> Option<String> {
+        self.inner.lock().unwrap().routes.get(route).cloned()
+    }
+
+    /// Returns how long to wait before a request to a route can be sent, or [`None`] if it can be
+    /// sent right now.
+    pub(crate) fn time_until_sendable(&self, route: &str, major: &LimitType) -> Option<Duration> {
+        let now = Utc::now();
+        let inner = self.inner.lock().unwrap();
+
+        let mut blocked_until = inner.global_reset.filter(|reset| *reset > now);
+        if let Some(bucket) = inner
+            .routes
+            .get(route)
+            .and_then(|bucket| inner.buckets.get(&(bucket.clone(), *major)))
+        {
+            if bucket.remaining == 0 && bucket.reset > now {
+                blocked_until = blocked_until.max(Some(bucket.reset));
+            }
+        }
+
+        blocked_until.map(|until| (until - now).to_std().unwrap_or_default())
+    }
+
+    /// Updates the bucket of a route with the headers of a response.
+    pub(crate) fn update(&self, route: &str, major: &LimitType, headers: &RatelimitHeaders) {
+        let Some(bucket_id) = &headers.bucket else {
+            return;
+        };
+        let now = Utc::now();
+        let mut inner = self.inner.lock().unwrap();
+        inner.routes.insert(route.to_string(), bucket_id.clone());
+
+        let bucket = inner
+            .buckets
+            .entry((bucket_id.clone(), *major))
+            .or_insert(RouteBucket {
+                limit: 1,
+                remaining: 1,
+                reset: now,
+            });
+        if let Some(limit) = headers.limit {
+            bucket.limit = limit;
+        }
+        bucket.remaining = headers.remaining.unwrap_or(bucket.limit);
+        if let Some(reset_after) = headers.reset_after {
+            bucket.reset = RouteRatelimits::after(now, reset_after);
+        }
+    }
+
+    /// Marks the bucket of a route as exhausted for the given duration.
+    pub(crate) fn exhaust(&self, route: &str, major: &LimitType, retry_after: Duration) {
+        let now = Utc::now();
+        let mut inner = self.inner.lock().unwrap();
+        let Some(bucket_id) = inner.routes.get(route).cloned() else {
+            return;
+        };
+        if let Some(bucket) = inner.buckets.get_mut(&(bucket_id, *major)) {
+            bucket.remaining = 0;
+            bucket.reset = RouteRatelimits::after(now, retry_after);
+        }
+    }
+
+    /// Blocks all requests for the given duration, after we hit the global rate limit.
+    pub(crate) fn exhaust_global(&self, retry_after: Duration) {
+        let reset = RouteRatelimits::after(Utc::now(), retry_after);
+        let mut inner = self.inner.lock().unwrap();
+        inner.global_reset = inner.global_reset.max(Some(reset));
+    }
+
+    fn after(time: DateTime<Utc>, duration: Duration) -> DateTime<Utc> {
+        time + chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::zero())
+    }
+}
@@ -9,7 +9,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::types::utils::Snowflake;
 use crate::types::Shared;
-use crate::types::{Team, User};
+use crate::types::{ChannelType, PermissionFlags, Team, User};
 
 #[allow(unused_imports)]
 use super::{arc_rwlock_ptr_eq, option_arc_rwlock_ptr_eq};
@@ -198,22 +198,83 @@ bitflags! {
 /// See <https://discord.com/developers/docs/interactions/application-commands#application-command-object>
 pub struct ApplicationCommand {
     pub id: Snowflake,
+    #[serde(rename = "type", default)]
+    pub command_type: ApplicationCommandType,
     pub application_id: Snowflake,
+    /// The guild of the command, if it is a guild command
+    pub guild_id: Option<Snowflake>,
     pub name: String,
     pub description: String,
+    #[serde(default)]
     pub options: Vec<Shared<ApplicationCommandOption>>,
+    /// The permissions a member needs to use the command, unless overwritten by
+    /// [GuildApplicationCommandPermissions]
+    pub default_member_permissions: Option<PermissionFlags>,
+    pub nsfw: Option<bool>,
+    /// Changes whenever the command is updated
+    pub version: Option<Snowflake>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Serialize_repr,
+    Deserialize_repr,
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+)]
+#[repr(u8)]
+/// # Reference
+/// See <https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-types>
+pub enum ApplicationCommandType {
+    /// A slash command
+    #[default]
+    ChatInput = 1,
+    /// A command in the context menu of a user
+    User = 2,
+    /// A command in the context menu of a message
+    Message = 3,
+    PrimaryEntryPoint = 4,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 /// Reference
 /// See <https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-structure>
 pub struct ApplicationCommandOption {
     pub r#type: ApplicationCommandOptionType,
     pub name: String,
     pub description: String,
+    #[serde(default)]
     pub required: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub choices: Vec<ApplicationCommandOptionChoice>,
+    /// The options of a [ApplicationCommandOptionType::SubCommand] or
+    /// [ApplicationCommandOptionType::SubCommandGroup]
+    #[serde(default)]
     pub options: Shared<Vec<ApplicationCommandOption>>,
+    /// The channel types a [ApplicationCommandOptionType::Channel] option is restricted to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_types: Option<Vec<ChannelType>>,
+    /// The minimum value of an integer or number option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<f64>,
+    /// The maximum value of an integer or number option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<f64>,
+    /// The minimum length of a string option (0-6000)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    /// The maximum length of a string option (1-6000)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    /// Whether the option's choices are suggested with autocomplete interactions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autocomplete: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -222,7 +283,7 @@ pub struct ApplicationCommandOptionChoice {
     pub value: Value,
 }
 
-#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash)]
+#[derive(Debug, Default, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
 #[cfg_attr(not(feature = "sqlx"), repr(u8))]
 #[cfg_attr(feature = "sqlx", repr(i16))]
@@ -231,6 +292,7 @@ pub struct ApplicationCommandOptionChoice {
 pub enum ApplicationCommandOptionType {
     SubCommand = 1,
     SubCommandGroup = 2,
+    #[default]
     String = 3,
     /// Any integer between -2^53 and 2^53
     Integer = 4,
@@ -247,17 +309,41 @@ pub enum ApplicationCommandOptionType {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+/// The data of an application command [Interaction](crate::types::Interaction), see
+/// [Interaction::command_data](crate::types::Interaction::command_data)
+///
+/// # Reference
+/// See <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-data-structure>
 pub struct ApplicationCommandInteractionData {
     pub id: Snowflake,
     pub name: String,
+    #[serde(rename = "type", default)]
+    pub command_type: ApplicationCommandType,
+    #[serde(default)]
     pub options: Vec<Shared<ApplicationCommandInteractionDataOption>>,
+    /// The guild of the command, if it is a guild command
+    pub guild_id: Option<Snowflake>,
+    /// The user or message a context menu command was used on
+    pub target_id: Option<Snowflake>,
+    /// The users, members, roles, channels, messages and attachments referenced by the options
+    pub resolved: Option<Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+/// # Reference
+/// See <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-interaction-data-option-structure>
 pub struct ApplicationCommandInteractionDataOption {
     pub name: String,
+    #[serde(rename = "type", default)]
+    pub option_type: ApplicationCommandOptionType,
+    /// The value of the option; not set for subcommands and subcommand groups
+    #[serde(default)]
     pub value: Value,
+    /// The options of a subcommand or subcommand group
+    #[serde(default)]
     pub options: Vec<Shared<ApplicationCommandInteractionDataOption>>,
+    /// Whether this is the option the user is typing, in autocomplete interactions
+    pub focused: Option<bool>,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
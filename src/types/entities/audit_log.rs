@@ -5,12 +5,16 @@
 #[allow(unused_imports)]
 use super::option_vec_arc_rwlock_ptr_eq;
 
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::types::utils::Snowflake;
 use crate::types::{
-    AutoModerationRuleTriggerType, IntegrationType, PermissionOverwriteType, Shared,
+    AutoModerationRuleTriggerType, ChannelType, IntegrationType, PermissionFlags,
+    PermissionOverwrite, PermissionOverwriteType, Shared,
 };
 use crate::UInt64;
 
@@ -88,6 +92,37 @@ fn compare_changes(
     option_vec_arc_rwlock_ptr_eq(a, b)
 }
 
+impl AuditLogEntry {
+    /// Returns the entry's changes, with their values decoded into typed values based on the
+    /// entry's action type and each change's key.
+    ///
+    /// See [AuditLogChange::decode]
+    pub fn decoded_changes(&self) -> Vec<DecodedAuditLogChange> {
+        #[cfg(feature = "sqlx")]
+        let changes = self.changes.0.as_ref();
+        #[cfg(not(feature = "sqlx"))]
+        let changes = self.changes.as_ref();
+
+        changes
+            .map(|changes| {
+                changes
+                    .iter()
+                    .map(|change| {
+                        #[cfg(feature = "client")]
+                        {
+                            change.read().unwrap().decode(self.action_type)
+                        }
+                        #[cfg(not(feature = "client"))]
+                        {
+                            change.decode(self.action_type)
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 /// See <https://discord.com/developers/docs/resources/audit-log#audit-log-change-object>
 pub struct AuditLogChange {
@@ -96,6 +131,120 @@ pub struct AuditLogChange {
     pub key: String,
 }
 
+impl AuditLogChange {
+    /// Decodes the change's old and new value into typed values.
+    ///
+    /// Which type a value has depends on the change's key and, for ambiguous keys like `type`,
+    /// on the action type of the entry the change belongs to. Values which can't be decoded
+    /// into a more specific type are returned as [AuditLogChangeValue::Other].
+    ///
+    /// # Reference
+    /// See <https://docs.discord.sex/resources/audit-log#audit-log-change-key>
+    pub fn decode(&self, action_type: AuditLogActionType) -> DecodedAuditLogChange {
+        let decode = |value: &Option<Value>| {
+            value
+                .as_ref()
+                .filter(|value| !value.is_null())
+                .map(|value| AuditLogChangeValue::decode(&self.key, action_type, value))
+        };
+
+        DecodedAuditLogChange {
+            key: self.key.clone(),
+            old_value: decode(&self.old_value),
+            new_value: decode(&self.new_value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// An [AuditLogChange] with typed values, see [AuditLogChange::decode]
+pub struct DecodedAuditLogChange {
+    /// The name of the changed property, e.g. `name` or `$add`
+    pub key: String,
+    /// The value before the change, if there was one
+    pub old_value: Option<AuditLogChangeValue>,
+    /// The value after the change, if there is one
+    pub new_value: Option<AuditLogChangeValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A typed value of an [AuditLogChange]
+pub enum AuditLogChangeValue {
+    String(String),
+    Bool(bool),
+    Integer(i64),
+    /// The id of an object, e.g. for the `owner_id` or `channel_id` keys
+    Snowflake(Snowflake),
+    /// A permission bitfield, for the `permissions`, `allow` and `deny` keys
+    Permissions(PermissionFlags),
+    /// The `permission_overwrites` of a channel
+    PermissionOverwrites(Vec<PermissionOverwrite>),
+    /// The roles added to (`$add`) or removed from (`$remove`) a member
+    Roles(Vec<AuditLogChangeRole>),
+    /// The `type` of a channel or thread
+    ChannelType(ChannelType),
+    /// The `type` of a permission overwrite
+    OverwriteType(PermissionOverwriteType),
+    /// A point in time, e.g. for the `communication_disabled_until` key
+    Timestamp(DateTime<Utc>),
+    /// A value we don't know a more specific type for
+    Other(Value),
+}
+
+impl AuditLogChangeValue {
+    fn decode(key: &str, action_type: AuditLogActionType, value: &Value) -> AuditLogChangeValue {
+        fn typed<T: DeserializeOwned>(
+            value: &Value,
+            variant: impl FnOnce(T) -> AuditLogChangeValue,
+        ) -> Option<AuditLogChangeValue> {
+            serde_json::from_value(value.clone()).ok().map(variant)
+        }
+
+        let decoded = match key {
+            "$add" | "$remove" => typed(value, AuditLogChangeValue::Roles),
+            "permissions" | "allow" | "deny" => typed(value, AuditLogChangeValue::Permissions),
+            "permission_overwrites" => typed(value, AuditLogChangeValue::PermissionOverwrites),
+            "communication_disabled_until" => typed(value, AuditLogChangeValue::Timestamp),
+            "type" => match action_type {
+                AuditLogActionType::ChannelCreate
+                | AuditLogActionType::ChannelUpdate
+                | AuditLogActionType::ChannelDelete
+                | AuditLogActionType::ThreadCreate
+                | AuditLogActionType::ThreadUpdate
+                | AuditLogActionType::ThreadDelete => {
+                    typed(value, AuditLogChangeValue::ChannelType)
+                }
+                AuditLogActionType::ChannelOverwriteCreate
+                | AuditLogActionType::ChannelOverwriteUpdate
+                | AuditLogActionType::ChannelOverwriteDelete => {
+                    typed(value, AuditLogChangeValue::OverwriteType)
+                }
+                _ => None,
+            },
+            key if key == "id" || key.ends_with("_id") => {
+                typed(value, AuditLogChangeValue::Snowflake)
+            }
+            _ => None,
+        };
+
+        decoded.unwrap_or_else(|| match value {
+            Value::String(string) => AuditLogChangeValue::String(string.clone()),
+            Value::Bool(bool) => AuditLogChangeValue::Bool(*bool),
+            Value::Number(number) if number.is_i64() => {
+                AuditLogChangeValue::Integer(number.as_i64().unwrap())
+            }
+            other => AuditLogChangeValue::Other(other.clone()),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// A role added to or removed from a member, as found in the `$add` and `$remove` changes
+pub struct AuditLogChangeRole {
+    pub id: Snowflake,
+    pub name: Option<String>,
+}
+
 #[derive(
     Default,
     Serialize_repr,
@@ -416,6 +416,18 @@ pub enum ChannelType {
     Unhandled = 255,
 }
 
+impl ChannelType {
+    /// Whether channels of this type are threads
+    pub fn is_thread(&self) -> bool {
+        matches!(
+            self,
+            ChannelType::GuildNewsThread
+                | ChannelType::GuildPublicThread
+                | ChannelType::GuildPrivateThread
+        )
+    }
+}
+
 /// # Reference
 /// See <https://docs.discord.sex/resources/message#followed-channel-object>
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Copy, Hash, PartialOrd, Ord)]
@@ -13,7 +13,9 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 use crate::types::types::guild_configuration::GuildFeaturesList;
 use crate::types::Shared;
 use crate::types::{
-    entities::{Channel, Emoji, RoleObject, Sticker, User, VoiceState, Webhook},
+    entities::{
+        Channel, Emoji, GuildMember, Integration, RoleObject, Sticker, User, VoiceState, Webhook,
+    },
     interfaces::WelcomeScreenObject,
     utils::Snowflake,
 };
@@ -69,6 +71,11 @@ pub struct Guild {
     #[cfg_attr(feature = "sqlx", sqlx(skip))]
     pub icon_hash: Option<String>,
     pub id: Snowflake,
+    /// The services connected to the guild, see
+    /// [Integration::get_all](crate::types::Integration::get_all)
+    #[cfg_attr(feature = "sqlx", sqlx(skip))]
+    #[serde(default)]
+    pub integrations: Vec<Integration>,
     #[cfg_attr(feature = "sqlx", sqlx(skip))]
     #[serde(default)]
     pub invites: Vec<GuildInvite>,
@@ -148,6 +155,7 @@ impl PartialEq for Guild {
             && self.icon == other.icon
             && self.icon_hash == other.icon_hash
             && self.id == other.id
+            && self.integrations == other.integrations
             && self.invites == other.invites
             && self.joined_at == other.joined_at
             && self.large == other.large
@@ -353,6 +361,7 @@ pub struct GuildWidgetMemberActivity {
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[cfg_attr(feature = "client", derive(Updateable))]
 /// See <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object>
 pub struct GuildScheduledEvent {
     pub id: Snowflake,
@@ -416,6 +425,25 @@ pub enum GuildScheduledEventStatus {
     Canceled = 4,
 }
 
+impl GuildScheduledEventStatus {
+    /// Whether an event with this status can be changed to `next`.
+    ///
+    /// Scheduled events can be started or canceled, active events can be completed. Completed
+    /// and canceled events can't be changed anymore.
+    pub fn can_transition_to(self, next: GuildScheduledEventStatus) -> bool {
+        matches!(
+            (self, next),
+            (
+                GuildScheduledEventStatus::Scheduled,
+                GuildScheduledEventStatus::Active | GuildScheduledEventStatus::Canceled
+            ) | (
+                GuildScheduledEventStatus::Active,
+                GuildScheduledEventStatus::Completed
+            )
+        )
+    }
+}
+
 #[derive(
     Serialize_repr,
     Deserialize_repr,
@@ -445,6 +473,19 @@ pub struct GuildScheduledEventEntityMetadata {
     pub location: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+/// A user who subscribed to a [GuildScheduledEvent]
+///
+/// # Reference
+/// See <https://docs.discord.sex/resources/guild-scheduled-event#guild-scheduled-event-user-structure>
+pub struct GuildScheduledEventUser {
+    pub guild_scheduled_event_id: Snowflake,
+    pub user: PublicUser,
+    /// Only included if requested with
+    /// [GetGuildScheduledEventUsersQuery::with_member](crate::types::GetGuildScheduledEventUsersQuery::with_member)
+    pub member: Option<GuildMember>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct VoiceRegion {
     id: String,
@@ -13,6 +13,8 @@ use crate::types::{
 };
 use crate::UInt16;
 
+use super::option_arc_rwlock_ptr_eq;
+
 #[derive(Default, Debug, Deserialize, Serialize, Clone)]
 #[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
 /// See <https://discord.com/developers/docs/resources/guild#integration-object-integration-structure>
@@ -39,7 +41,29 @@ pub struct Integration {
     pub scopes: Option<Vec<String>>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize, Clone)]
+#[cfg(not(tarpaulin_include))]
+impl PartialEq for Integration {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.name == other.name
+            && self.integration_type == other.integration_type
+            && self.enabled == other.enabled
+            && self.syncing == other.syncing
+            && self.role_id == other.role_id
+            && self.enabled_emoticons == other.enabled_emoticons
+            && self.expire_behaviour == other.expire_behaviour
+            && self.expire_grace_period == other.expire_grace_period
+            && option_arc_rwlock_ptr_eq(&self.user, &other.user)
+            && self.account == other.account
+            && self.synced_at == other.synced_at
+            && self.subscriber_count == other.subscriber_count
+            && self.revoked == other.revoked
+            && option_arc_rwlock_ptr_eq(&self.application, &other.application)
+            && self.scopes == other.scopes
+    }
+}
+
+#[derive(Default, Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Hash)]
 /// See <https://discord.com/developers/docs/resources/guild#integration-account-object-integration-account-structure>
 pub struct IntegrationAccount {
     pub id: String,
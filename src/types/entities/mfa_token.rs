@@ -4,10 +4,11 @@
 
 use chrono::{DateTime, Utc};
 use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
 
 use crate::ratelimiter::ChorusRequest;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 /// A Token used to bypass mfa for five minutes.
 pub struct MfaToken {
     pub token: String,
@@ -2,11 +2,18 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "client")]
+use crate::gateway::Updateable;
+
+#[cfg(feature = "client")]
+use chorus_macros::Updateable;
+
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::types::Snowflake;
 
+#[cfg_attr(feature = "client", derive(Updateable))]
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 /// See <https://discord.com/developers/docs/resources/stage-instance>
 pub struct StageInstance {
@@ -61,6 +61,22 @@ impl Sticker {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+/// A pack of standard stickers.
+///
+/// # Reference
+/// See <https://docs.discord.sex/resources/sticker#sticker-pack-object>
+pub struct StickerPack {
+    pub id: Snowflake,
+    pub stickers: Vec<Sticker>,
+    pub name: String,
+    pub sku_id: Option<Snowflake>,
+    /// The id of the sticker shown as the pack's icon
+    pub cover_sticker_id: Option<Snowflake>,
+    pub description: Option<String>,
+    pub banner_asset_id: Option<Snowflake>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 /// A partial sticker object.
 ///
@@ -8,7 +8,8 @@ use serde::{Deserialize, Serialize};
 use crate::types::{
     entities::{Guild, User},
     utils::Snowflake,
-    Shared,
+    ChannelType, ExplicitContentFilterLevel, MessageNotificationLevel, PermissionFlags,
+    PermissionOverwrite, Shared, SystemChannelFlags, VerificationLevel,
 };
 use crate::UInt64;
 
@@ -27,8 +28,83 @@ pub struct GuildTemplate {
     pub updated_at: DateTime<Utc>,
     pub source_guild_id: Snowflake,
     #[cfg_attr(feature = "sqlx", sqlx(skip))]
+    #[serde(default)]
     pub source_guild: Vec<Shared<Guild>>,
-    // Unsure how a {recursive: Guild} looks like, might be a Vec?
+    /// A snapshot of the source guild at the time of the last sync, which guilds created from
+    /// the template are copied from
     #[cfg_attr(feature = "sqlx", sqlx(skip))]
-    pub serialized_source_guild: Vec<Shared<Guild>>,
+    #[serde(default)]
+    pub serialized_source_guild: GuildTemplateSourceGuild,
+    /// Whether the source guild has changed since the template was last synced
+    #[cfg_attr(feature = "sqlx", sqlx(skip))]
+    pub is_dirty: Option<bool>,
+}
+
+/// The guild settings, roles and channels stored in a [GuildTemplate].
+///
+/// Roles and channels have placeholder ids, which are only unique within the template.
+///
+/// # Reference
+/// See <https://docs.discord.sex/resources/guild-template#serialized-source-guild-structure>
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct GuildTemplateSourceGuild {
+    pub name: String,
+    pub description: Option<String>,
+    pub region: Option<String>,
+    pub verification_level: Option<VerificationLevel>,
+    pub default_message_notifications: Option<MessageNotificationLevel>,
+    pub explicit_content_filter: Option<ExplicitContentFilterLevel>,
+    pub preferred_locale: Option<String>,
+    pub afk_timeout: Option<i32>,
+    #[serde(default)]
+    pub roles: Vec<GuildTemplateRole>,
+    #[serde(default)]
+    pub channels: Vec<GuildTemplateChannel>,
+    /// The placeholder id of the AFK channel
+    pub afk_channel_id: Option<Snowflake>,
+    /// The placeholder id of the system channel
+    pub system_channel_id: Option<Snowflake>,
+    pub system_channel_flags: Option<SystemChannelFlags>,
+    pub icon_hash: Option<String>,
+}
+
+/// A role in a [GuildTemplateSourceGuild].
+///
+/// The role with placeholder id 0 is the `@everyone` role.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct GuildTemplateRole {
+    pub id: Snowflake,
+    pub name: String,
+    #[serde(default)]
+    pub permissions: PermissionFlags,
+    #[serde(default)]
+    pub color: u32,
+    #[serde(default)]
+    pub hoist: bool,
+    #[serde(default)]
+    pub mentionable: bool,
+    pub icon: Option<String>,
+    pub unicode_emoji: Option<String>,
+}
+
+/// A channel in a [GuildTemplateSourceGuild].
+///
+/// [GuildTemplateChannel::parent_id] and the ids of role permission overwrites refer to the
+/// placeholder ids in the template.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct GuildTemplateChannel {
+    pub id: Snowflake,
+    #[serde(rename = "type")]
+    pub channel_type: ChannelType,
+    pub name: Option<String>,
+    pub position: Option<i32>,
+    pub topic: Option<String>,
+    pub bitrate: Option<i32>,
+    pub user_limit: Option<i32>,
+    pub nsfw: Option<bool>,
+    pub rate_limit_per_user: Option<i32>,
+    pub parent_id: Option<Snowflake>,
+    pub default_auto_archive_duration: Option<i32>,
+    #[serde(default)]
+    pub permission_overwrites: Vec<PermissionOverwrite>,
 }
@@ -5,6 +5,7 @@
 use std::fmt::Debug;
 
 use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::types::Shared;
 
@@ -27,7 +28,7 @@ use crate::types::{
 
 use super::option_arc_rwlock_ptr_eq;
 
-/// See <https://docs.spacebar.chat/routes/#cmp--schemas-webhook>
+/// See <https://docs.discord.sex/resources/webhook#webhook-object>
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[cfg_attr(feature = "client", derive(Updateable, Composite))]
 #[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
@@ -35,11 +36,13 @@ pub struct Webhook {
     pub id: Snowflake,
     #[serde(rename = "type")]
     pub webhook_type: WebhookType,
-    pub name: String,
-    pub avatar: String,
-    pub token: String,
-    pub guild_id: Snowflake,
-    pub channel_id: Snowflake,
+    pub name: Option<String>,
+    pub avatar: Option<String>,
+    /// The secret token of the webhook, only returned for incoming webhooks we can manage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    pub guild_id: Option<Snowflake>,
+    pub channel_id: Option<Snowflake>,
     pub application_id: Option<Snowflake>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "sqlx", sqlx(skip))]
@@ -47,6 +50,10 @@ pub struct Webhook {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "sqlx", sqlx(skip))]
     pub source_guild: Option<Shared<Guild>>,
+    /// The channel a [WebhookType::ChannelFollower] webhook is following
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "sqlx", sqlx(skip))]
+    pub source_channel: Option<WebhookSourceChannel>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
@@ -64,16 +71,39 @@ impl PartialEq for Webhook {
             && self.application_id == other.application_id
             && option_arc_rwlock_ptr_eq(&self.user, &other.user)
             && option_arc_rwlock_ptr_eq(&self.source_guild, &other.source_guild)
+            && self.source_channel == other.source_channel
             && self.url == other.url
     }
 }
 
+/// The announcement channel a [WebhookType::ChannelFollower] webhook is following
+///
+/// # Reference
+/// See <https://docs.discord.sex/resources/webhook#webhook-object>
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct WebhookSourceChannel {
+    pub id: Snowflake,
+    pub name: Option<String>,
+}
+
 #[derive(
-    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+    Serialize_repr,
+    Deserialize_repr,
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
 )]
 #[cfg_attr(not(feature = "sqlx"), repr(u8))]
 #[cfg_attr(feature = "sqlx", repr(i16))]
 #[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+/// # Reference
+/// See <https://docs.discord.sex/resources/webhook#webhook-type>
 pub enum WebhookType {
     #[default]
     Incoming = 1,
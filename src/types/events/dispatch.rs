@@ -0,0 +1,22 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use serde::{Deserialize, Serialize};
+
+use super::WebSocketEvent;
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone, WebSocketEvent, PartialEq)]
+/// Chorus-only event, published for every dispatch (opcode 0) the gateway receives, before it is
+/// parsed into its specific event type.
+///
+/// Useful if you want to handle all events in one place, or handle events chorus does not know
+/// about yet.
+pub struct GatewayDispatch {
+    /// The name of the event, such as `MESSAGE_CREATE`
+    pub event_name: String,
+    /// The sequence number of the event
+    pub sequence_number: Option<u64>,
+    /// The raw event data
+    pub data: serde_json::Value,
+}
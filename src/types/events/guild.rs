@@ -181,20 +181,90 @@ pub struct GuildAuditLogEntryCreate {
     pub entry: AuditLogEntry,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent, PartialEq)]
+#[derive(
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    Clone,
+    JsonField,
+    SourceUrlField,
+    WebSocketEvent,
+    PartialEq,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#guild-emojis-update>;
 /// Received to tell the client about a change to a guild's emoji list;
 pub struct GuildEmojisUpdate {
     pub guild_id: Snowflake,
     pub emojis: Vec<Emoji>,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent, PartialEq)]
+#[cfg(feature = "client")]
+impl UpdateMessage<Guild> for GuildEmojisUpdate {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.guild_id)
+    }
+
+    fn update(&mut self, write: &mut Guild) {
+        // Keep the emojis we already have, so that they stay observed
+        let mut previous: Vec<Shared<Emoji>> = std::mem::take(&mut write.emojis);
+        write.emojis = self
+            .emojis
+            .iter()
+            .map(|emoji| {
+                match previous
+                    .iter()
+                    .position(|shared| shared.read().unwrap().id == emoji.id)
+                {
+                    Some(index) => {
+                        let shared = previous.swap_remove(index);
+                        *shared.write().unwrap() = emoji.clone();
+                        shared
+                    }
+                    None => emoji.clone().into_shared(),
+                }
+            })
+            .collect();
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    Clone,
+    JsonField,
+    SourceUrlField,
+    WebSocketEvent,
+    PartialEq,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#guild-stickers-update>;
 /// Received to tell the client about a change to a guild's sticker list;
 pub struct GuildStickersUpdate {
     pub guild_id: Snowflake,
     pub stickers: Vec<Sticker>,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
+}
+
+#[cfg(feature = "client")]
+impl UpdateMessage<Guild> for GuildStickersUpdate {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.guild_id)
+    }
+
+    fn update(&mut self, write: &mut Guild) {
+        write.stickers = self.stickers.clone();
+    }
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent, PartialEq, Copy, Eq, Hash, PartialOrd, Ord)]
@@ -328,11 +398,33 @@ pub struct GuildScheduledEventCreate {
     pub event: GuildScheduledEvent,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent, PartialEq)]
+#[derive(
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    Clone,
+    JsonField,
+    SourceUrlField,
+    WebSocketEvent,
+    PartialEq,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#guild-scheduled-event-update>
 pub struct GuildScheduledEventUpdate {
     #[serde(flatten)]
     pub event: GuildScheduledEvent,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
+}
+
+#[cfg(feature = "client")]
+impl UpdateMessage<GuildScheduledEvent> for GuildScheduledEventUpdate {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.event.id)
+    }
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent, PartialEq)]
@@ -342,18 +434,94 @@ pub struct GuildScheduledEventDelete {
     pub event: GuildScheduledEvent,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent, PartialEq, Copy, Eq, Hash, PartialOrd, Ord)]
+#[derive(
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    Clone,
+    JsonField,
+    SourceUrlField,
+    WebSocketEvent,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#guild-scheduled-event-user-add>
 pub struct GuildScheduledEventUserAdd {
     pub guild_scheduled_event_id: Snowflake,
     pub user_id: Snowflake,
     pub guild_id: Snowflake,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent, PartialEq, Copy, Eq, Hash, PartialOrd, Ord)]
+#[cfg(feature = "client")]
+impl UpdateMessage<GuildScheduledEvent> for GuildScheduledEventUserAdd {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.guild_scheduled_event_id)
+    }
+
+    fn update(&mut self, write: &mut GuildScheduledEvent) {
+        adjust_user_count(write, 1);
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    Clone,
+    JsonField,
+    SourceUrlField,
+    WebSocketEvent,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#guild-scheduled-event-user-remove>
 pub struct GuildScheduledEventUserRemove {
     pub guild_scheduled_event_id: Snowflake,
     pub user_id: Snowflake,
     pub guild_id: Snowflake,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
+}
+
+#[cfg(feature = "client")]
+impl UpdateMessage<GuildScheduledEvent> for GuildScheduledEventUserRemove {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.guild_scheduled_event_id)
+    }
+
+    fn update(&mut self, write: &mut GuildScheduledEvent) {
+        adjust_user_count(write, -1);
+    }
+}
+
+/// Changes the subscriber count of an observed event, if the count is known.
+#[cfg(feature = "client")]
+fn adjust_user_count(event: &mut GuildScheduledEvent, delta: i64) {
+    let Some(count) = event.user_count.as_ref() else {
+        return;
+    };
+    #[cfg(not(feature = "sqlx"))]
+    {
+        event.user_count = Some(count.saturating_add_signed(delta));
+    }
+    #[cfg(feature = "sqlx")]
+    {
+        event.user_count = Some(count.to_uint().saturating_add_signed(delta).into());
+    }
 }
@@ -18,7 +18,8 @@ pub struct GatewayIdentifyPayload {
     pub large_threshold: Option<i16>,
     //default: 50
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shard: Option<Vec<(i32, i32)>>,
+    /// The shard information (shard_id, num_shards) for this connection, if sharded
+    pub shard: Option<(u64, u64)>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub presence: Option<GatewayIdentifyPresenceUpdate>,
     // What is the difference between these two?
@@ -4,30 +4,115 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::types::{Integration, Snowflake, WebSocketEvent};
-use chorus_macros::WebSocketEvent;
+use crate::types::{Integration, JsonField, Snowflake, SourceUrlField, WebSocketEvent};
+use chorus_macros::{JsonField, SourceUrlField, WebSocketEvent};
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent)]
+#[cfg(feature = "client")]
+use super::UpdateMessage;
+#[cfg(feature = "client")]
+use crate::types::Guild;
+
+#[derive(
+    Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#integration-create>
 pub struct IntegrationCreate {
     #[serde(flatten)]
     pub integration: Integration,
     pub guild_id: Snowflake,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
+}
+
+#[cfg(feature = "client")]
+impl UpdateMessage<Guild> for IntegrationCreate {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.guild_id)
+    }
+
+    fn update(&mut self, write: &mut Guild) {
+        upsert_integration(write, &self.integration);
+    }
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent)]
+#[derive(
+    Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#integration-update>
 pub struct IntegrationUpdate {
     #[serde(flatten)]
     pub integration: Integration,
     pub guild_id: Snowflake,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg(feature = "client")]
+impl UpdateMessage<Guild> for IntegrationUpdate {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.guild_id)
+    }
+
+    fn update(&mut self, write: &mut Guild) {
+        upsert_integration(write, &self.integration);
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    Clone,
+    JsonField,
+    SourceUrlField,
+    WebSocketEvent,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#integration-delete>
 pub struct IntegrationDelete {
     pub id: Snowflake,
     pub guild_id: Snowflake,
     pub application_id: Option<Snowflake>,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
 }
 
+#[cfg(feature = "client")]
+impl UpdateMessage<Guild> for IntegrationDelete {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.guild_id)
+    }
+
+    fn update(&mut self, write: &mut Guild) {
+        write
+            .integrations
+            .retain(|integration| integration.id != self.id);
+    }
+}
+
+/// Replaces the guild's integration with the same id, or adds it if the guild has none
+#[cfg(feature = "client")]
+fn upsert_integration(guild: &mut Guild, integration: &Integration) {
+    match guild
+        .integrations
+        .iter_mut()
+        .find(|existing| existing.id == integration.id)
+    {
+        Some(existing) => *existing = integration.clone(),
+        None => guild.integrations.push(integration.clone()),
+    }
+}
@@ -4,21 +4,64 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::types::{GuildInvite, Snowflake, WebSocketEvent};
-use chorus_macros::WebSocketEvent;
+use crate::types::{GuildInvite, JsonField, Snowflake, SourceUrlField, WebSocketEvent};
+use chorus_macros::{JsonField, SourceUrlField, WebSocketEvent};
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent)]
+#[cfg(feature = "client")]
+use super::UpdateMessage;
+#[cfg(feature = "client")]
+use crate::types::Guild;
+
+#[derive(
+    Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#invite-create>
 pub struct InviteCreate {
     #[serde(flatten)]
     pub invite: GuildInvite,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
+}
+
+#[cfg(feature = "client")]
+impl UpdateMessage<Guild> for InviteCreate {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.invite.guild_id)
+    }
+
+    fn update(&mut self, write: &mut Guild) {
+        write
+            .invites
+            .retain(|invite| invite.code != self.invite.code);
+        write.invites.push(self.invite.clone());
+    }
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent)]
+#[derive(
+    Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#invite-delete>
 pub struct InviteDelete {
     pub channel_id: Snowflake,
     pub guild_id: Option<Snowflake>,
     pub code: String,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
 }
 
+#[cfg(feature = "client")]
+impl UpdateMessage<Guild> for InviteDelete {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        self.guild_id
+    }
+
+    fn update(&mut self, write: &mut Guild) {
+        write.invites.retain(|invite| invite.code != self.code);
+    }
+}
@@ -8,6 +8,7 @@ pub use application::*;
 pub use auto_moderation::*;
 pub use call::*;
 pub use channel::*;
+pub use dispatch::*;
 pub use guild::*;
 pub use heartbeat::*;
 pub use hello::*;
@@ -59,6 +60,7 @@ mod application;
 mod auto_moderation;
 mod call;
 mod channel;
+mod dispatch;
 mod guild;
 mod heartbeat;
 mod hello;
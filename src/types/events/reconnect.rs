@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 use super::WebSocketEvent;
@@ -25,3 +27,37 @@ use super::WebSocketEvent;
 /// # Reference
 /// See <https://docs.discord.sex/topics/gateway-events#reconnect>
 pub struct GatewayReconnect {}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone, WebSocketEvent, Copy, PartialEq, Eq)]
+/// Chorus-only event, published when the gateway lost its connection and is about to try and
+/// reconnect.
+///
+/// See [GatewayReconnectOptions](crate::gateway::GatewayReconnectOptions)
+pub struct GatewayReconnecting {
+    /// Which attempt this is, starting at 1
+    pub attempt: u32,
+    /// How long we will wait before connecting
+    pub delay: Duration,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone, WebSocketEvent, Copy, PartialEq, Eq)]
+/// Chorus-only event, published when the gateway has re-established its connection.
+///
+/// If `resuming` is true, a [GatewayResume](crate::types::GatewayResume) was sent and the server
+/// will either replay missed events and send [GatewayResumed](crate::types::GatewayResumed), or
+/// answer with a [GatewayInvalidSession](crate::types::GatewayInvalidSession), after which a new
+/// session is identified.
+///
+/// If `resuming` is false, the last identify payload was sent again to start a new session.
+pub struct GatewayReconnected {
+    pub resuming: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone, WebSocketEvent, Copy, PartialEq, Eq)]
+/// Chorus-only event, published when the gateway has given up on reconnecting.
+///
+/// After this event, the gateway is closed for good.
+pub struct GatewayReconnectFailed {
+    /// How many attempts were made
+    pub attempts: u32,
+}
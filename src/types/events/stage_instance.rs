@@ -4,8 +4,13 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::types::{StageInstance, WebSocketEvent};
-use chorus_macros::WebSocketEvent;
+use crate::types::{JsonField, SourceUrlField, StageInstance, WebSocketEvent};
+use chorus_macros::{JsonField, SourceUrlField, WebSocketEvent};
+
+#[cfg(feature = "client")]
+use super::UpdateMessage;
+#[cfg(feature = "client")]
+use crate::types::Snowflake;
 
 #[derive(Debug, Deserialize, Serialize, Default, Clone, WebSocketEvent)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#stage-instance-create>
@@ -14,11 +19,26 @@ pub struct StageInstanceCreate {
     pub stage_instance: StageInstance,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default, Clone, WebSocketEvent)]
+#[derive(
+    Debug, Deserialize, Serialize, Default, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#stage-instance-update>
 pub struct StageInstanceUpdate {
     #[serde(flatten)]
     pub stage_instance: StageInstance,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
+}
+
+#[cfg(feature = "client")]
+#[cfg(not(tarpaulin_include))]
+impl UpdateMessage<StageInstance> for StageInstanceUpdate {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.stage_instance.id)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Default, Clone, WebSocketEvent)]
@@ -9,17 +9,48 @@ use crate::types::entities::{Channel, ThreadMember};
 use crate::types::events::WebSocketEvent;
 use crate::types::{JsonField, Snowflake, SourceUrlField};
 
+#[cfg(feature = "client")]
+use crate::types::{Guild, IntoShared, Shared};
+
 #[cfg(feature = "client")]
 use super::UpdateMessage;
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent)]
+#[derive(
+    Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#thread-create>
 pub struct ThreadCreate {
     #[serde(flatten)]
     pub thread: Channel,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent)]
+#[cfg(feature = "client")]
+impl UpdateMessage<Guild> for ThreadCreate {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        self.thread.guild_id
+    }
+
+    fn update(&mut self, write: &mut Guild) {
+        // Also sent when the current user is added to an existing private thread
+        if write
+            .channels
+            .iter()
+            .any(|channel| channel.read().unwrap().id == self.thread.id)
+        {
+            return;
+        }
+        write.channels.push(self.thread.clone().into_shared());
+    }
+}
+
+#[derive(
+    Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#thread-update>
 pub struct ThreadUpdate {
     #[serde(flatten)]
@@ -38,32 +69,140 @@ impl UpdateMessage<Channel> for ThreadUpdate {
     }
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent)]
+#[derive(
+    Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#thread-delete>
 pub struct ThreadDelete {
     #[serde(flatten)]
     pub thread: Channel,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
+}
+
+#[cfg(feature = "client")]
+impl UpdateMessage<Guild> for ThreadDelete {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        self.thread.guild_id
+    }
+
+    fn update(&mut self, write: &mut Guild) {
+        write
+            .channels
+            .retain(|channel| channel.read().unwrap().id != self.thread.id);
+    }
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent)]
+#[derive(
+    Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#thread-list-sync>
 pub struct ThreadListSync {
     pub guild_id: Snowflake,
+    /// The parent channels whose threads are being synced.
+    ///
+    /// If [None], the threads of the whole guild are synced.
     pub channel_ids: Option<Vec<Snowflake>>,
+    /// All active threads of the synced channels
     pub threads: Vec<Channel>,
+    /// The current user's thread members for the synced threads
     pub members: Option<Vec<ThreadMember>>,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent)]
+impl ThreadListSync {
+    /// Whether a thread with the given parent belongs to the synced channels
+    pub fn syncs_parent(&self, parent_id: Option<Snowflake>) -> bool {
+        match &self.channel_ids {
+            Some(channel_ids) => {
+                parent_id.is_some_and(|parent_id| channel_ids.contains(&parent_id))
+            }
+            None => true,
+        }
+    }
+}
+
+#[cfg(feature = "client")]
+impl UpdateMessage<Guild> for ThreadListSync {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.guild_id)
+    }
+
+    /// Replaces the threads of the synced channels with the synced threads
+    fn update(&mut self, write: &mut Guild) {
+        // Keep the threads we already have, so that they stay observed
+        let (mut previous, channels): (Vec<Shared<Channel>>, Vec<Shared<Channel>>) =
+            std::mem::take(&mut write.channels)
+                .into_iter()
+                .partition(|channel| {
+                    let channel = channel.read().unwrap();
+                    channel.channel_type.is_thread() && self.syncs_parent(channel.parent_id)
+                });
+        write.channels = channels;
+
+        for thread in &self.threads {
+            let mut thread = thread.clone();
+            thread.member = self
+                .members
+                .iter()
+                .flatten()
+                .find(|member| member.id == Some(thread.id))
+                .cloned();
+
+            let shared = match previous
+                .iter()
+                .position(|shared| shared.read().unwrap().id == thread.id)
+            {
+                Some(index) => {
+                    let shared = previous.swap_remove(index);
+                    *shared.write().unwrap() = thread;
+                    shared
+                }
+                None => thread.into_shared(),
+            };
+            write.channels.push(shared);
+        }
+    }
+}
+
+#[derive(
+    Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#thread-member-update>
 /// The inner payload is a thread member object with an extra field.
 pub struct ThreadMemberUpdate {
     #[serde(flatten)]
     pub member: ThreadMember,
     pub guild_id: Snowflake,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone, WebSocketEvent)]
+#[cfg(feature = "client")]
+impl UpdateMessage<Channel> for ThreadMemberUpdate {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        self.member.id
+    }
+
+    /// Updates the current user's thread member of the thread
+    fn update(&mut self, write: &mut Channel) {
+        write.member = Some(self.member.clone());
+    }
+}
+
+#[derive(
+    Debug, Default, Deserialize, Serialize, Clone, JsonField, SourceUrlField, WebSocketEvent,
+)]
 /// See <https://discord.com/developers/docs/topics/gateway-events#thread-members-update>
 pub struct ThreadMembersUpdate {
     pub id: Snowflake,
@@ -72,5 +211,82 @@ pub struct ThreadMembersUpdate {
     pub member_count: u8,
     pub added_members: Option<Vec<ThreadMember>>,
     pub removed_members: Option<Vec<Snowflake>>,
+    #[serde(skip)]
+    pub json: String,
+    #[serde(skip)]
+    pub source_url: String,
+}
+
+#[cfg(feature = "client")]
+impl UpdateMessage<Channel> for ThreadMembersUpdate {
+    #[cfg(not(tarpaulin_include))]
+    fn id(&self) -> Option<Snowflake> {
+        Some(self.id)
+    }
+
+    fn update(&mut self, write: &mut Channel) {
+        write.member_count = Some(self.member_count as i32);
+        // The current user's thread member is only known if it was part of the thread object
+        if let Some(member) = &write.member {
+            if member.user_id.is_some_and(|user_id| {
+                self.removed_members
+                    .iter()
+                    .flatten()
+                    .any(|removed| *removed == user_id)
+            }) {
+                write.member = None;
+            }
+        }
+    }
 }
 
+#[cfg(test)]
+#[cfg(feature = "client")]
+mod test {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::types::ChannelType;
+
+    #[test]
+    fn thread_list_sync_keeps_threads() {
+        let parent_id = Snowflake(2);
+        let thread = |id, name: &str| Channel {
+            id: Snowflake(id),
+            parent_id: Some(parent_id),
+            channel_type: ChannelType::GuildPublicThread,
+            name: Some(name.to_string()),
+            ..Default::default()
+        };
+
+        let parent = Channel {
+            id: parent_id,
+            ..Default::default()
+        }
+        .into_shared();
+        let kept = thread(3, "old").into_shared();
+        let archived = thread(4, "archived").into_shared();
+        let mut guild = Guild {
+            channels: vec![parent.clone(), kept.clone(), archived.clone()],
+            ..Default::default()
+        };
+
+        ThreadListSync {
+            guild_id: Snowflake(1),
+            channel_ids: Some(vec![parent_id]),
+            threads: vec![thread(3, "new"), thread(5, "created")],
+            ..Default::default()
+        }
+        .update(&mut guild);
+
+        assert_eq!(guild.channels.len(), 3);
+        assert!(Arc::ptr_eq(&guild.channels[0], &parent));
+        assert!(Arc::ptr_eq(&guild.channels[1], &kept));
+        assert_eq!(kept.read().unwrap().name.as_deref(), Some("new"));
+        assert_eq!(guild.channels[2].read().unwrap().id, Snowflake(5));
+        assert!(!guild
+            .channels
+            .iter()
+            .any(|channel| Arc::ptr_eq(channel, &archived)));
+    }
+}
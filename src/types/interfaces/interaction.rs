@@ -4,44 +4,170 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
+use crate::errors::ChorusError;
 use crate::types::entities::{AllowedMention, Embed};
 use crate::types::utils::Snowflake;
+use crate::types::{
+    ApplicationCommandInteractionData, ApplicationCommandOptionChoice, Component, GuildMember,
+    Message, MessageFlags, PermissionFlags, PublicUser,
+};
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+/// # Reference
+/// See <https://docs.discord.sex/interactions/receiving-and-responding#interaction-structure>
 pub struct Interaction {
     pub id: Snowflake,
+    #[serde(default)]
+    pub application_id: Snowflake,
     pub r#type: InteractionType,
+    /// The payload of the interaction; for application commands, see [Interaction::command_data]
+    #[serde(default)]
     pub data: Value,
-    pub guild_id: Snowflake,
-    pub channel_id: Snowflake,
-    pub member_id: Snowflake,
+    pub guild_id: Option<Snowflake>,
+    pub channel_id: Option<Snowflake>,
+    /// The member who invoked the interaction, if it was invoked in a guild
+    pub member: Option<GuildMember>,
+    /// The user who invoked the interaction, if it was invoked outside of a guild
+    pub user: Option<PublicUser>,
+    /// The token to respond to the interaction with, valid for 15 minutes
     pub token: String,
     pub version: i32,
+    /// The message a component was attached to, for component interactions
+    pub message: Option<Box<Message>>,
+    /// The permissions of the application in the channel
+    pub app_permissions: Option<PermissionFlags>,
+    /// The locale of the invoking user
+    pub locale: Option<String>,
+    pub guild_locale: Option<String>,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Eq, PartialOrd, Ord, Hash, Copy)]
+impl Interaction {
+    /// Parses [Interaction::data] as the data of an application command.
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the interaction is not an application command
+    /// (or autocomplete) interaction.
+    pub fn command_data(&self) -> Result<ApplicationCommandInteractionData, ChorusError> {
+        match self.r#type {
+            InteractionType::ApplicationCommand
+            | InteractionType::ApplicationCommandAutocomplete => {
+                serde_json::from_value(self.data.clone()).map_err(|e| {
+                    ChorusError::InvalidArguments {
+                        error: format!("Invalid application command data: {}", e),
+                    }
+                })
+            }
+            interaction_type => Err(ChorusError::InvalidArguments {
+                error: format!(
+                    "{:?} interactions don't carry application command data",
+                    interaction_type
+                ),
+            }),
+        }
+    }
+
+    /// Returns the id of the user who invoked the interaction
+    pub fn invoker_id(&self) -> Option<Snowflake> {
+        self.member
+            .as_ref()
+            .and_then(|member| member.user.as_ref())
+            .map(|user| {
+                #[cfg(feature = "client")]
+                {
+                    user.read().unwrap().id
+                }
+                #[cfg(not(feature = "client"))]
+                {
+                    user.id
+                }
+            })
+            .or(self.user.as_ref().map(|user| user.id))
+    }
+}
+
+#[derive(
+    Serialize_repr,
+    Deserialize_repr,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+)]
+#[repr(u8)]
+/// # Reference
+/// See <https://docs.discord.sex/interactions/receiving-and-responding#interaction-type>
 pub enum InteractionType {
     #[default]
     SelfCommand = 0,
     Ping = 1,
     ApplicationCommand = 2,
+    MessageComponent = 3,
+    ApplicationCommandAutocomplete = 4,
+    ModalSubmit = 5,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Copy, Eq, Hash, PartialOrd, Ord)]
+#[derive(
+    Serialize_repr, Deserialize_repr, Debug, Clone, PartialEq, Copy, Eq, Hash, PartialOrd, Ord,
+)]
+#[repr(u8)]
+/// # Reference
+/// See <https://docs.discord.sex/interactions/receiving-and-responding#interaction-callback-type>
 pub enum InteractionResponseType {
     SelfCommandResponse = 0,
     Pong = 1,
     Acknowledge = 2,
     ChannelMessage = 3,
+    /// Responds with a message
     ChannelMessageWithSource = 4,
+    /// Acknowledges the interaction, showing a loading state; the response is sent later as an
+    /// edit of the original response
     AcknowledgeWithSource = 5,
+    /// Acknowledges a component interaction; the message is edited later
+    DeferredUpdateMessage = 6,
+    /// Edits the message a component was attached to
+    UpdateMessage = 7,
+    /// Responds to an autocomplete interaction with choices
+    ApplicationCommandAutocompleteResult = 8,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+/// The data of an [InteractionResponse].
+///
+/// # Reference
+/// See <https://docs.discord.sex/interactions/receiving-and-responding#interaction-callback-data-structure>
 pub struct InteractionApplicationCommandCallbackData {
-    pub tts: bool,
-    pub content: String,
-    pub embeds: Vec<Embed>,
-    pub allowed_mentions: AllowedMention,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMention>,
+    /// Only [MessageFlags::EPHEMERAL] and [MessageFlags::SUPPRESS_EMBEDS] can be set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
+    /// The suggestions of an [InteractionResponseType::ApplicationCommandAutocompleteResult]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub choices: Option<Vec<ApplicationCommandOptionChoice>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// A response to an [Interaction].
+///
+/// # Reference
+/// See <https://docs.discord.sex/interactions/receiving-and-responding#interaction-response-structure>
+pub struct InteractionResponse {
+    #[serde(rename = "type")]
+    pub response_type: InteractionResponseType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<InteractionApplicationCommandCallbackData>,
 }
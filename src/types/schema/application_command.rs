@@ -0,0 +1,70 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{
+    ApplicationCommandOption, ApplicationCommandPermission, ApplicationCommandType,
+    PermissionFlags, Snowflake,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Where application commands are registered
+pub enum ApplicationCommandScope {
+    /// Commands available in every guild and DM the application is in.
+    ///
+    /// Changes to global commands can take a while to show up for users.
+    #[default]
+    Global,
+    /// Commands only available in a single guild
+    Guild(Snowflake),
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+/// Creates an application command, or overwrites the existing command with the same name and
+/// type.
+///
+/// # Reference
+/// See <https://docs.discord.sex/interactions/application-commands#create-global-application-command>
+pub struct ApplicationCommandCreateSchema {
+    /// 1-32 characters; chat input command names must be lowercase and can't contain spaces
+    pub name: String,
+    /// 1-100 characters for chat input commands, empty for context menu commands
+    pub description: String,
+    /// Defaults to [ApplicationCommandType::ChatInput]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub command_type: Option<ApplicationCommandType>,
+    /// Max 25 options; only chat input commands can have options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<ApplicationCommandOption>>,
+    /// The permissions a member needs to use the command by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_member_permissions: Option<PermissionFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+/// # Reference
+/// See <https://docs.discord.sex/interactions/application-commands#edit-global-application-command>
+pub struct ApplicationCommandModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<ApplicationCommandOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_member_permissions: Option<PermissionFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq, Hash)]
+/// # Reference
+/// See <https://docs.discord.sex/interactions/application-commands#edit-application-command-permissions>
+pub struct ApplicationCommandPermissionsModifySchema {
+    /// Max 100 permissions
+    pub permissions: Vec<ApplicationCommandPermission>,
+}
@@ -8,25 +8,70 @@ use crate::types::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/audit-log#audit-log-object>
 pub struct AuditLogObject {
     pub audit_log_entries: Vec<AuditLogEntry>,
+    #[serde(default)]
     pub application_commands: Vec<ApplicationCommand>,
+    #[serde(default)]
     pub auto_moderation_rules: Vec<AutoModerationRule>,
+    #[serde(default)]
     pub guild_scheduled_events: Vec<GuildScheduledEvent>,
+    #[serde(default)]
     pub integrations: Vec<Integration>,
+    #[serde(default)]
     pub threads: Vec<Channel>,
+    #[serde(default)]
     pub users: Vec<User>,
+    #[serde(default)]
     pub webhooks: Vec<Webhook>,
 }
 
 #[derive(
     Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default,
 )]
+/// # Reference
+/// See <https://docs.discord.sex/resources/audit-log#get-guild-audit-log>
 pub struct GetAuditLogsQuery {
+    /// Get entries before this entry id
     pub before: Option<Snowflake>,
+    /// Get entries after this entry id
     pub after: Option<Snowflake>,
+    /// Max number of entries to return (1 - 100, 50 by default)
     pub limit: Option<u8>,
+    /// Only get entries of actions made by this user
     pub user_id: Option<Snowflake>,
+    /// Only get entries of this type of action
     pub action_type: Option<AuditLogActionType>,
 }
+
+impl GetAuditLogsQuery {
+    /// Converts self to query string parameters
+    pub fn to_query(self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::with_capacity(5);
+
+        if let Some(before) = self.before {
+            query.push(("before", before.to_string()));
+        }
+
+        if let Some(after) = self.after {
+            query.push(("after", after.to_string()));
+        }
+
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+
+        if let Some(user_id) = self.user_id {
+            query.push(("user_id", user_id.to_string()));
+        }
+
+        if let Some(action_type) = self.action_type {
+            query.push(("action_type", (action_type as i32).to_string()));
+        }
+
+        query
+    }
+}
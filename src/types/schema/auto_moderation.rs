@@ -0,0 +1,281 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ChorusError;
+use crate::types::{
+    AutoModerationAction, AutoModerationActionMetadata, AutoModerationActionType,
+    AutoModerationRuleEventType, AutoModerationRuleTriggerMetadata, AutoModerationRuleTriggerType,
+    Snowflake,
+};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/auto-moderation#create-auto-moderation-rule>
+pub struct AutoModerationRuleCreateSchema {
+    /// The name of the rule (1-100 characters)
+    pub name: String,
+    pub event_type: AutoModerationRuleEventType,
+    pub trigger_type: AutoModerationRuleTriggerType,
+    /// Has to match the trigger type, e.g.
+    /// [AutoModerationRuleTriggerMetadata::ForKeyword] for
+    /// [AutoModerationRuleTriggerType::Keyword]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_metadata: Option<AutoModerationRuleTriggerMetadata>,
+    /// The actions to execute when the rule is triggered (at least one)
+    pub actions: Vec<AutoModerationAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Roles which are not affected by the rule (max 20)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_roles: Option<Vec<Snowflake>>,
+    /// Channels which are not affected by the rule (max 50)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_channels: Option<Vec<Snowflake>>,
+}
+
+impl AutoModerationRuleCreateSchema {
+    /// Checks that the trigger metadata and actions fit the trigger type, and are within the
+    /// documented limits.
+    ///
+    /// Returns [ChorusError::InvalidArguments] describing the first problem found.
+    pub fn validate(&self) -> Result<(), ChorusError> {
+        validate_name(&self.name)?;
+
+        match (self.trigger_type, &self.trigger_metadata) {
+            (
+                AutoModerationRuleTriggerType::Spam,
+                None | Some(AutoModerationRuleTriggerMetadata::None),
+            ) => {}
+            (AutoModerationRuleTriggerType::Spam, Some(_)) => {
+                return Err(invalid("Spam rules don't take trigger metadata"))
+            }
+            (_, None | Some(AutoModerationRuleTriggerMetadata::None)) => {
+                return Err(invalid(format!(
+                    "{:?} rules require trigger metadata",
+                    self.trigger_type
+                )))
+            }
+            (trigger_type, Some(metadata)) => {
+                validate_trigger_metadata(Some(trigger_type), metadata)?;
+            }
+        }
+
+        if self.actions.is_empty() {
+            return Err(invalid("Rules need at least one action"));
+        }
+        validate_actions(Some(self.trigger_type), &self.actions)?;
+        validate_exemptions(&self.exempt_roles, &self.exempt_channels)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/auto-moderation#modify-auto-moderation-rule>
+pub struct AutoModerationRuleModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<AutoModerationRuleEventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_metadata: Option<AutoModerationRuleTriggerMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<AutoModerationAction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_roles: Option<Vec<Snowflake>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_channels: Option<Vec<Snowflake>>,
+}
+
+impl AutoModerationRuleModifySchema {
+    /// Checks that the trigger metadata and actions fit together, and are within the documented
+    /// limits.
+    ///
+    /// The trigger type of a rule can't be changed, so it is inferred from
+    /// [AutoModerationRuleModifySchema::trigger_metadata] if that is set. Otherwise, only the
+    /// limits are checked.
+    ///
+    /// Returns [ChorusError::InvalidArguments] describing the first problem found.
+    pub fn validate(&self) -> Result<(), ChorusError> {
+        if let Some(name) = &self.name {
+            validate_name(name)?;
+        }
+        let trigger_type = match &self.trigger_metadata {
+            Some(metadata) => validate_trigger_metadata(None, metadata)?,
+            None => None,
+        };
+        if let Some(actions) = &self.actions {
+            if actions.is_empty() {
+                return Err(invalid("Rules need at least one action"));
+            }
+            validate_actions(trigger_type, actions)?;
+        }
+        validate_exemptions(&self.exempt_roles, &self.exempt_channels)
+    }
+}
+
+fn invalid(error: impl Into<String>) -> ChorusError {
+    ChorusError::InvalidArguments {
+        error: error.into(),
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), ChorusError> {
+    if name.is_empty() || name.chars().count() > 100 {
+        return Err(invalid("Rule names must be 1-100 characters long"));
+    }
+    Ok(())
+}
+
+/// Checks that every entry of a list is at most `max_length` characters long and that the list
+/// has at most `max_count` entries
+fn validate_list(
+    field: &str,
+    list: &[String],
+    max_count: usize,
+    max_length: usize,
+) -> Result<(), ChorusError> {
+    if list.len() > max_count {
+        return Err(invalid(format!(
+            "{field} can have at most {max_count} entries"
+        )));
+    }
+    if list.iter().any(|entry| entry.chars().count() > max_length) {
+        return Err(invalid(format!(
+            "Entries of {field} can be at most {max_length} characters long"
+        )));
+    }
+    Ok(())
+}
+
+/// Checks trigger metadata against its limits, and against the trigger type if it is known.
+///
+/// Returns the trigger type the metadata belongs to, if any.
+fn validate_trigger_metadata(
+    trigger_type: Option<AutoModerationRuleTriggerType>,
+    metadata: &AutoModerationRuleTriggerMetadata,
+) -> Result<Option<AutoModerationRuleTriggerType>, ChorusError> {
+    let expected = match metadata {
+        AutoModerationRuleTriggerMetadata::ForKeyword(keyword) => {
+            validate_list("keyword_filter", &keyword.keyword_filter, 1000, 60)?;
+            validate_list("regex_patterns", &keyword.regex_patterns, 10, 260)?;
+            validate_list("allow_list", &keyword.allow_list, 100, 60)?;
+            Some(AutoModerationRuleTriggerType::Keyword)
+        }
+        AutoModerationRuleTriggerMetadata::ForKeywordPreset(preset) => {
+            validate_list("allow_list", &preset.allow_list, 1000, 60)?;
+            Some(AutoModerationRuleTriggerType::KeywordPreset)
+        }
+        AutoModerationRuleTriggerMetadata::ForMentionSpam(mention_spam) => {
+            #[cfg(not(feature = "sqlx"))]
+            let limit = mention_spam.mention_total_limit;
+            #[cfg(feature = "sqlx")]
+            let limit = mention_spam.mention_total_limit.to_uint();
+            if limit > 50 {
+                return Err(invalid("mention_total_limit can be at most 50"));
+            }
+            Some(AutoModerationRuleTriggerType::MentionSpam)
+        }
+        AutoModerationRuleTriggerMetadata::None => None,
+    };
+
+    match (trigger_type, expected) {
+        (Some(trigger_type), Some(expected)) if trigger_type != expected => Err(invalid(format!(
+            "{:?} rules can't use trigger metadata for {:?} rules",
+            trigger_type, expected
+        ))),
+        _ => Ok(expected),
+    }
+}
+
+/// Checks that each action's metadata fits its type, and that the actions are allowed for the
+/// trigger type if it is known
+fn validate_actions(
+    trigger_type: Option<AutoModerationRuleTriggerType>,
+    actions: &[AutoModerationAction],
+) -> Result<(), ChorusError> {
+    for action in actions {
+        let metadata = action
+            .metadata
+            .as_ref()
+            .map(|metadata| {
+                #[cfg(feature = "client")]
+                {
+                    metadata.read().unwrap().clone()
+                }
+                #[cfg(not(feature = "client"))]
+                {
+                    metadata.clone()
+                }
+            })
+            .unwrap_or_default();
+
+        match (action.action_type, &metadata) {
+            (AutoModerationActionType::BlockMessage, AutoModerationActionMetadata::None) => {}
+            (
+                AutoModerationActionType::BlockMessage,
+                AutoModerationActionMetadata::ForBlockMessage(block),
+            ) => {
+                if block
+                    .custom_message
+                    .as_ref()
+                    .is_some_and(|message| message.chars().count() > 150)
+                {
+                    return Err(invalid("custom_message can be at most 150 characters long"));
+                }
+            }
+            (
+                AutoModerationActionType::SendAlertMessage,
+                AutoModerationActionMetadata::ForSendAlertMessage(_),
+            ) => {}
+            (
+                AutoModerationActionType::Timeout,
+                AutoModerationActionMetadata::ForTimeout(timeout),
+            ) => {
+                if timeout.duration_seconds > 2419200 {
+                    return Err(invalid(
+                        "Timeouts can be at most 2419200 seconds (4 weeks) long",
+                    ));
+                }
+                if trigger_type.is_some_and(|trigger_type| {
+                    !matches!(
+                        trigger_type,
+                        AutoModerationRuleTriggerType::Keyword
+                            | AutoModerationRuleTriggerType::MentionSpam
+                    )
+                }) {
+                    return Err(invalid(
+                        "Timeout actions can only be used by Keyword and MentionSpam rules",
+                    ));
+                }
+            }
+            (action_type, metadata) => {
+                return Err(invalid(format!(
+                    "{:?} actions can't use {:?} as metadata",
+                    action_type, metadata
+                )))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_exemptions(
+    exempt_roles: &Option<Vec<Snowflake>>,
+    exempt_channels: &Option<Vec<Snowflake>>,
+) -> Result<(), ChorusError> {
+    if exempt_roles.as_ref().is_some_and(|roles| roles.len() > 20) {
+        return Err(invalid("Rules can exempt at most 20 roles"));
+    }
+    if exempt_channels
+        .as_ref()
+        .is_some_and(|channels| channels.len() > 50)
+    {
+        return Err(invalid("Rules can exempt at most 50 channels"));
+    }
+    Ok(())
+}
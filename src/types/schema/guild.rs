@@ -12,10 +12,10 @@ use crate::errors::ChorusError;
 use crate::types::entities::Channel;
 use crate::types::types::guild_configuration::GuildFeatures;
 use crate::types::{
-    Emoji, ExplicitContentFilterLevel, GenericSearchQueryWithLimit, GuildJoinRequest,
-    GuildJoinRequestStatus, GuildMember, GuildMemberVerificationFormField, GuildOnboardingMode,
-    GuildOnboardingPrompt, JoinSourceType, MFALevel, MessageNotificationLevel, RoleObject,
-    Snowflake, Sticker, StickerFormatType, SupplementalGuildMember, SystemChannelFlags,
+    image_data_uri, Emoji, ExplicitContentFilterLevel, GenericSearchQueryWithLimit,
+    GuildJoinRequest, GuildJoinRequestStatus, GuildMember, GuildMemberVerificationFormField,
+    GuildOnboardingMode, GuildOnboardingPrompt, JoinSourceType, MFALevel, MessageNotificationLevel,
+    RoleObject, Snowflake, Sticker, StickerFormatType, SupplementalGuildMember, SystemChannelFlags,
     ThemeColors, VerificationLevel, WelcomeScreenChannel,
 };
 
@@ -718,11 +718,30 @@ pub struct EmojiCreateSchema {
     pub roles: Vec<Snowflake>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+impl EmojiCreateSchema {
+    /// Creates the schema from the raw bytes of a PNG, JPEG, GIF or WebP image, encoding it as a
+    /// data URI.
+    ///
+    /// Returns [ChorusError::InvalidArguments] if the image format is not supported.
+    pub fn from_image(
+        name: Option<String>,
+        image: &[u8],
+        roles: Vec<Snowflake>,
+    ) -> Result<Self, ChorusError> {
+        let image = image_data_uri(image).ok_or(ChorusError::InvalidArguments {
+            error: "Emoji images must be PNG, JPEG, GIF or WebP images".to_string(),
+        })?;
+        Ok(Self { name, image, roles })
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
 /// # Reference
 ///  See <https://docs.discord.sex/resources/emoji#modify-guild-emoji>
 pub struct EmojiModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub roles: Option<Vec<Snowflake>>,
 }
 
@@ -848,7 +867,7 @@ impl GuildCreateStickerSchema {
                 "tags" => {
                     _self.tags = Some(field.text().await?);
                 }
-                "file_data" => {
+                "file" | "file_data" => {
                     if _self.name.is_empty() {
                         _self.name =
                             field
@@ -885,12 +904,16 @@ impl GuildCreateStickerSchema {
     }
 
     // #[cfg(feature = "client")]
+    /// Creates the multipart form to upload the sticker with.
+    ///
+    /// The sticker's file is sent as the `file` field, like Discord.com and Spacebar expect it.
     pub fn to_multipart(&self) -> reqwest::multipart::Form {
         let mut form = reqwest::multipart::Form::new()
             .text("name", self.name.clone())
             .part(
-                "file_data",
+                "file",
                 reqwest::multipart::Part::bytes(self.file_data.clone())
+                    .file_name(self.name.clone())
                     .mime_str(self.sticker_format_type.to_mime())
                     .unwrap(),
             );
@@ -906,15 +929,15 @@ impl GuildCreateStickerSchema {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
 /// # Reference
 /// See <https://docs.discord.sex/resources/sticker#modify-guild-sticker>
 pub struct GuildModifyStickerSchema {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tags: Option<String>,
 }
 
@@ -938,6 +961,29 @@ pub struct GuildTemplateCreateSchema {
     pub description: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+/// # Reference:
+/// See <https://docs.discord.sex/resources/guild-template#modify-guild-template>
+pub struct GuildTemplateModifySchema {
+    /// Name of the template (1-100 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Description of the template (max 120 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+/// # Reference:
+/// See <https://docs.discord.sex/resources/guild-template#use-guild-template>
+pub struct GuildCreateFromTemplateSchema {
+    /// Name of the new guild (2-100 characters)
+    pub name: String,
+    /// The icon of the new guild, as a data URI (see [image_data_uri](crate::types::image_data_uri))
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 /// Return type for the [Guild::search_members](crate::types::Guild::search_members) endpoint.
 ///
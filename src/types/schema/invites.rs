@@ -4,13 +4,45 @@
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
-/// Query parameters for the `Get Invite` route.
+use crate::types::Snowflake;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, PartialOrd, Eq, Ord)]
+/// Query parameters for the [ChorusUser::get_invite](crate::instance::ChorusUser::get_invite)
+/// route.
 ///
 /// # Reference:
 /// See <https://docs.discord.sex/resources/invite#query-string-params>
 pub struct GetInvitesSchema {
+    /// Whether to include the approximate member and presence counts of the guild
     pub with_counts: Option<bool>,
+    /// Whether to include the expiration date of the invite
+    pub with_expiration: Option<bool>,
+    /// The guild scheduled event to include with the invite
+    pub guild_scheduled_event_id: Option<Snowflake>,
+}
+
+impl GetInvitesSchema {
+    /// Converts self to query string parameters
+    pub fn to_query(self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::with_capacity(3);
+
+        if let Some(with_counts) = self.with_counts {
+            query.push(("with_counts", with_counts.to_string()));
+        }
+
+        if let Some(with_expiration) = self.with_expiration {
+            query.push(("with_expiration", with_expiration.to_string()));
+        }
+
+        if let Some(guild_scheduled_event_id) = self.guild_scheduled_event_id {
+            query.push((
+                "guild_scheduled_event_id",
+                guild_scheduled_event_id.to_string(),
+            ));
+        }
+
+        query
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, PartialOrd, Eq, Ord)]
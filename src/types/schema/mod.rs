@@ -3,7 +3,9 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 pub use apierror::*;
+pub use application_command::*;
 pub use audit_log::*;
+pub use auto_moderation::*;
 pub use auth::*;
 pub use mfa::*;
 pub use channel::*;
@@ -11,13 +13,19 @@ pub use guild::*;
 pub use message::*;
 pub use relationship::*;
 pub use role::*;
+pub use scheduled_event::*;
+pub use stage_instance::*;
+pub use thread::*;
 pub use user::*;
 pub use invites::*;
 pub use voice_state::*;
 pub use instance::*;
+pub use webhook::*;
 
 mod apierror;
+mod application_command;
 mod audit_log;
+mod auto_moderation;
 mod auth;
 mod mfa;
 mod channel;
@@ -25,10 +33,14 @@ mod guild;
 mod message;
 mod relationship;
 mod role;
+mod scheduled_event;
+mod stage_instance;
+mod thread;
 mod user;
 mod invites;
 mod voice_state;
 mod instance;
+mod webhook;
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, PartialOrd, Eq, Ord)]
 pub struct GenericSearchQueryWithLimit {
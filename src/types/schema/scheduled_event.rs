@@ -0,0 +1,235 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ChorusError;
+use crate::types::{
+    GuildScheduledEventEntityMetadata, GuildScheduledEventEntityType,
+    GuildScheduledEventPrivacyLevel, GuildScheduledEventStatus, Snowflake,
+};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/guild-scheduled-event#create-guild-scheduled-event>
+pub struct GuildScheduledEventCreateSchema {
+    /// 1-100 characters
+    pub name: String,
+    /// Max 1000 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The stage or voice channel the event takes place in, required unless the entity type is
+    /// [GuildScheduledEventEntityType::External]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+    /// Required for [GuildScheduledEventEntityType::External] events, which need a location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_metadata: Option<GuildScheduledEventEntityMetadata>,
+    pub privacy_level: GuildScheduledEventPrivacyLevel,
+    pub scheduled_start_time: DateTime<Utc>,
+    /// Required for [GuildScheduledEventEntityType::External] events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_end_time: Option<DateTime<Utc>>,
+    pub entity_type: GuildScheduledEventEntityType,
+    /// The cover image, as a data URI (see [image_data_uri](crate::types::image_data_uri))
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+}
+
+impl GuildScheduledEventCreateSchema {
+    /// Checks that the fields the entity type requires are set and the times are in order.
+    ///
+    /// Returns [ChorusError::InvalidArguments] describing the first problem found.
+    pub fn validate(&self) -> Result<(), ChorusError> {
+        validate_name_and_description(Some(&self.name), self.description.as_deref())?;
+        validate_entity(
+            self.entity_type,
+            self.channel_id,
+            self.entity_metadata.as_ref(),
+            self.scheduled_end_time,
+        )?;
+        validate_times(Some(self.scheduled_start_time), self.scheduled_end_time)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/guild-scheduled-event#modify-guild-scheduled-event>
+pub struct GuildScheduledEventModifySchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `Some(None)` removes the channel, which is needed (along with setting
+    /// [GuildScheduledEventModifySchema::entity_metadata] to a location) when changing the entity
+    /// type to [GuildScheduledEventEntityType::External]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Option<Snowflake>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_metadata: Option<GuildScheduledEventEntityMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_level: Option<GuildScheduledEventPrivacyLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_start_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_end_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_type: Option<GuildScheduledEventEntityType>,
+    /// Starts, completes or cancels the event, see
+    /// [GuildScheduledEventStatus::can_transition_to]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<GuildScheduledEventStatus>,
+    /// The cover image, as a data URI (see [image_data_uri](crate::types::image_data_uri))
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+}
+
+impl GuildScheduledEventModifySchema {
+    /// Checks that the fields are valid for an event which currently has the given status.
+    ///
+    /// If the entity type is changed, the fields it requires have to be set as well.
+    ///
+    /// Returns [ChorusError::InvalidArguments] describing the first problem found.
+    pub fn validate(&self, current_status: GuildScheduledEventStatus) -> Result<(), ChorusError> {
+        if let Some(status) = self.status {
+            if !current_status.can_transition_to(status) {
+                return Err(ChorusError::InvalidArguments {
+                    error: format!(
+                        "Scheduled events can't go from {:?} to {:?}",
+                        current_status, status
+                    ),
+                });
+            }
+        }
+
+        validate_name_and_description(self.name.as_deref(), self.description.as_deref())?;
+        if let Some(entity_type) = self.entity_type {
+            if entity_type == GuildScheduledEventEntityType::External
+                && self.channel_id != Some(None)
+            {
+                return Err(ChorusError::InvalidArguments {
+                    error: "The channel has to be removed when making an event external"
+                        .to_string(),
+                });
+            }
+            validate_entity(
+                entity_type,
+                self.channel_id.flatten(),
+                self.entity_metadata.as_ref(),
+                self.scheduled_end_time,
+            )?;
+        }
+        validate_times(self.scheduled_start_time, self.scheduled_end_time)
+    }
+}
+
+fn validate_name_and_description(
+    name: Option<&str>,
+    description: Option<&str>,
+) -> Result<(), ChorusError> {
+    if name.is_some_and(|name| name.is_empty() || name.chars().count() > 100) {
+        return Err(ChorusError::InvalidArguments {
+            error: "Scheduled event names must be 1-100 characters long".to_string(),
+        });
+    }
+    if description.is_some_and(|description| description.chars().count() > 1000) {
+        return Err(ChorusError::InvalidArguments {
+            error: "Scheduled event descriptions can be at most 1000 characters long".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that an event has a channel, or a location and end time if it is external
+fn validate_entity(
+    entity_type: GuildScheduledEventEntityType,
+    channel_id: Option<Snowflake>,
+    entity_metadata: Option<&GuildScheduledEventEntityMetadata>,
+    scheduled_end_time: Option<DateTime<Utc>>,
+) -> Result<(), ChorusError> {
+    let error = match entity_type {
+        GuildScheduledEventEntityType::External => {
+            if channel_id.is_some() {
+                Some("External events can't take place in a channel")
+            } else if !entity_metadata
+                .and_then(|metadata| metadata.location.as_ref())
+                .is_some_and(|location| !location.is_empty())
+            {
+                Some("External events need a location")
+            } else if scheduled_end_time.is_none() {
+                Some("External events need an end time")
+            } else {
+                None
+            }
+        }
+        GuildScheduledEventEntityType::StageInstance | GuildScheduledEventEntityType::Voice => {
+            if channel_id.is_none() {
+                Some("Stage and voice events need a channel")
+            } else {
+                None
+            }
+        }
+    };
+
+    match error {
+        Some(error) => Err(ChorusError::InvalidArguments {
+            error: error.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn validate_times(
+    scheduled_start_time: Option<DateTime<Utc>>,
+    scheduled_end_time: Option<DateTime<Utc>>,
+) -> Result<(), ChorusError> {
+    if let (Some(start), Some(end)) = (scheduled_start_time, scheduled_end_time) {
+        if end <= start {
+            return Err(ChorusError::InvalidArguments {
+                error: "Scheduled events have to end after they start".to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/guild-scheduled-event#get-guild-scheduled-event-users>
+pub struct GetGuildScheduledEventUsersQuery {
+    /// Max number of users to return (1-100), default 100
+    pub limit: Option<u8>,
+    /// Whether to include the guild member of each user
+    pub with_member: Option<bool>,
+    /// Only return users with an id lower than this one
+    pub before: Option<Snowflake>,
+    /// Only return users with an id higher than this one
+    pub after: Option<Snowflake>,
+}
+
+impl GetGuildScheduledEventUsersQuery {
+    /// Converts self to query string parameters
+    pub fn to_query(self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::with_capacity(4);
+
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+
+        if let Some(with_member) = self.with_member {
+            query.push(("with_member", with_member.to_string()));
+        }
+
+        if let Some(before) = self.before {
+            query.push(("before", before.to_string()));
+        }
+
+        if let Some(after) = self.after {
+            query.push(("after", after.to_string()));
+        }
+
+        query
+    }
+}
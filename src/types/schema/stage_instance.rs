@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ChorusError;
+use crate::types::{Snowflake, StageInstancePrivacyLevel};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/stage-instance#create-stage-instance>
+pub struct StageInstanceCreateSchema {
+    /// The stage channel to start the stage instance in
+    pub channel_id: Snowflake,
+    /// 1-120 characters
+    pub topic: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_level: Option<StageInstancePrivacyLevel>,
+    /// Whether to notify the members of the guild that the stage instance started; requires the
+    /// [MENTION_EVERYONE](crate::types::PermissionFlags::MENTION_EVERYONE) permission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_start_notification: Option<bool>,
+    /// The scheduled event the stage instance belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_scheduled_event_id: Option<Snowflake>,
+}
+
+impl StageInstanceCreateSchema {
+    /// Checks the schema for values the server would reject.
+    pub fn validate(&self) -> Result<(), ChorusError> {
+        validate_topic(&self.topic)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/stage-instance#modify-stage-instance>
+pub struct StageInstanceModifySchema {
+    /// 1-120 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_level: Option<StageInstancePrivacyLevel>,
+}
+
+impl StageInstanceModifySchema {
+    /// Checks the schema for values the server would reject.
+    pub fn validate(&self) -> Result<(), ChorusError> {
+        match &self.topic {
+            Some(topic) => validate_topic(topic),
+            None => Ok(()),
+        }
+    }
+}
+
+fn validate_topic(topic: &str) -> Result<(), ChorusError> {
+    if topic.is_empty() || topic.chars().count() > 120 {
+        return Err(ChorusError::InvalidArguments {
+            error: "Stage instance topics have to be 1-120 characters long".to_string(),
+        });
+    }
+    Ok(())
+}
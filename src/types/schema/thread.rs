@@ -0,0 +1,189 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Channel, ChannelType, MessageSendSchema, Snowflake, ThreadMember};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/channel#create-thread-from-message>
+pub struct StartThreadFromMessageSchema {
+    /// 1-100 characters
+    pub name: String,
+    /// How long the thread stays active without new messages, in minutes.
+    ///
+    /// One of 60, 1440, 4320 or 10080
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<i32>,
+    /// Slowmode for the thread, in seconds (0-21600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/channel#create-thread>
+pub struct StartThreadSchema {
+    /// 1-100 characters
+    pub name: String,
+    /// How long the thread stays active without new messages, in minutes.
+    ///
+    /// One of 60, 1440, 4320 or 10080
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<i32>,
+    /// Slowmode for the thread, in seconds (0-21600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<i32>,
+    /// [ChannelType::GuildPublicThread], [ChannelType::GuildPrivateThread] or
+    /// [ChannelType::GuildNewsThread].
+    ///
+    /// Defaults to [ChannelType::GuildPrivateThread].
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub thread_type: Option<ChannelType>,
+    /// Whether non-moderators can add other non-moderators to a private thread
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+/// Creates a post (a thread with a first message) in a [ChannelType::GuildForum] or
+/// [ChannelType::GuildMedia] channel.
+///
+/// # Reference
+/// See <https://docs.discord.sex/resources/channel#create-thread>
+pub struct ForumPostCreateSchema {
+    /// 1-100 characters
+    pub name: String,
+    /// How long the post stays active without new messages, in minutes.
+    ///
+    /// One of 60, 1440, 4320 or 10080
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<i32>,
+    /// Slowmode for the post, in seconds (0-21600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<i32>,
+    /// The ids of the channel's [Tag](crate::types::Tag)s to apply to the post (max 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_tags: Option<Vec<Snowflake>>,
+    /// The first message of the post
+    pub message: MessageSendSchema,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+/// The active threads of a guild and the current user's thread members for them.
+///
+/// # Reference
+/// See <https://docs.discord.sex/resources/channel#get-guild-active-threads>
+pub struct ActiveThreads {
+    pub threads: Vec<Channel>,
+    #[serde(default)]
+    pub members: Vec<ThreadMember>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+/// A page of archived threads and the current user's thread members for them.
+///
+/// # Reference
+/// See <https://docs.discord.sex/resources/channel#get-public-archived-threads>
+pub struct ArchivedThreads {
+    pub threads: Vec<Channel>,
+    #[serde(default)]
+    pub members: Vec<ThreadMember>,
+    /// Whether there are more archived threads before the last one
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// Which archived threads to list, see
+/// [Channel::get_archived_threads](crate::types::Channel::get_archived_threads)
+pub enum ArchivedThreadsType {
+    /// Public threads of the channel
+    #[default]
+    Public,
+    /// Private threads of the channel.
+    ///
+    /// Requires the [MANAGE_THREADS](crate::types::PermissionFlags::MANAGE_THREADS) permission.
+    Private,
+    /// Private threads of the channel which the current user has joined
+    JoinedPrivate,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/channel#get-public-archived-threads>
+pub struct GetArchivedThreadsQuery {
+    /// Only return threads archived before this time
+    ///
+    /// For [ArchivedThreadsType::JoinedPrivate], only the id of the thread is used, so pass
+    /// [GetArchivedThreadsQuery::before_id] instead.
+    pub before: Option<DateTime<Utc>>,
+    /// Only return threads with an id lower than this one, only used for
+    /// [ArchivedThreadsType::JoinedPrivate]
+    pub before_id: Option<Snowflake>,
+    /// Max number of threads to return (2-100), default 50
+    pub limit: Option<u8>,
+}
+
+impl GetArchivedThreadsQuery {
+    /// Converts self to query string parameters
+    pub fn to_query(self, threads_type: ArchivedThreadsType) -> Vec<(&'static str, String)> {
+        let mut query = Vec::with_capacity(2);
+
+        match threads_type {
+            ArchivedThreadsType::JoinedPrivate => {
+                if let Some(before_id) = self.before_id {
+                    query.push(("before", before_id.to_string()));
+                }
+            }
+            _ => {
+                if let Some(before) = self.before {
+                    query.push(("before", before.to_rfc3339()));
+                }
+            }
+        }
+
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+
+        query
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// # Reference
+/// See <https://docs.discord.sex/resources/channel#get-thread-members>
+pub struct GetThreadMembersQuery {
+    /// Whether to include the guild member of each thread member
+    pub with_member: Option<bool>,
+    /// Only return thread members with a user id higher than this one
+    pub after: Option<Snowflake>,
+    /// Max number of thread members to return (1-100), only applies if
+    /// [GetThreadMembersQuery::with_member] is set
+    pub limit: Option<u8>,
+}
+
+impl GetThreadMembersQuery {
+    /// Converts self to query string parameters
+    pub fn to_query(self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::with_capacity(3);
+
+        if let Some(with_member) = self.with_member {
+            query.push(("with_member", with_member.to_string()));
+        }
+
+        if let Some(after) = self.after {
+            query.push(("after", after.to_string()));
+        }
+
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+
+        query
+    }
+}
@@ -6,14 +6,24 @@ use crate::types::Snowflake;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, PartialOrd, Copy)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, PartialOrd, Copy, Default)]
+/// Modifies the voice state of a user in a stage channel, see
+/// [VoiceState::modify_current](crate::types::VoiceState::modify_current) and
+/// [VoiceState::modify](crate::types::VoiceState::modify).
+///
 /// # Reference:
 /// See <https://docs.discord.sex/resources/voice#json-params>
 pub struct VoiceStateUpdateSchema {
     /// The ID of the channel the user is currently in
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_id: Option<Snowflake>,
-    /// Whether to suppress the user
+    /// Whether to suppress the user; suppressed users in stage channels are in the audience
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub suppress: Option<bool>,
-    /// The time at which the user requested to speak
-    pub request_to_speak_timestamp: Option<DateTime<Utc>>,
+    /// The time at which the user requested to speak.
+    ///
+    /// `Some(None)` withdraws the request, [None] leaves it as it is. Only the current user's
+    /// request can be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_to_speak_timestamp: Option<Option<DateTime<Utc>>>,
 }
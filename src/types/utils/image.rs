@@ -0,0 +1,36 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Helpers for sending images as [image data](https://docs.discord.sex/reference#image-data).
+
+use base64::Engine;
+
+/// Guesses the mime type of an image from its first bytes.
+///
+/// Supports PNG, JPEG, GIF and WebP images, which are the formats accepted for image data.
+pub fn image_mime_type(image: &[u8]) -> Option<&'static str> {
+    if image.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if image.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if image.starts_with(b"GIF87a") || image.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if image.len() >= 12 && image.starts_with(b"RIFF") && &image[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
+
+/// Encodes an image as a data URI, e.g. `data:image/png;base64,iVBORw0KGgo...`
+///
+/// Returns [None] if the image format is not supported, see [image_mime_type].
+pub fn image_data_uri(image: &[u8]) -> Option<String> {
+    let mime_type = image_mime_type(image)?;
+    Some(format!(
+        "data:{};base64,{}",
+        mime_type,
+        base64::prelude::BASE64_STANDARD.encode(image)
+    ))
+}
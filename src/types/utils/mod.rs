@@ -3,11 +3,13 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 #![allow(unused_imports)]
+pub use image::*;
 pub use opcode::*;
 pub use regexes::*;
 pub use rights::Rights;
 pub use snowflake::{Snowflake, OneOrMoreSnowflakes};
 
+mod image;
 pub mod jwt;
 pub mod opcode;
 mod regexes;
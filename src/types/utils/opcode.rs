@@ -167,6 +167,38 @@ pub enum CloseCode {
     DisallowedIntents = 4014,
 }
 
+impl CloseCode {
+    /// Returns whether it makes sense to reconnect after the gateway closed the connection with
+    /// this code.
+    ///
+    /// Codes like [CloseCode::AuthenticationFailed] or [CloseCode::ShardingRequired] will be sent
+    /// again if we reconnect with the same data.
+    pub fn is_reconnectable(&self) -> bool {
+        !matches!(
+            self,
+            CloseCode::AuthenticationFailed
+                | CloseCode::InvalidShard
+                | CloseCode::ShardingRequired
+                | CloseCode::InvalidApiVersion
+                | CloseCode::InvalidIntents
+                | CloseCode::DisallowedIntents
+        )
+    }
+
+    /// Returns whether the previous session can still be resumed after the gateway closed the
+    /// connection with this code.
+    ///
+    /// If this returns false (but [CloseCode::is_reconnectable] returns true), a new session must
+    /// be started by identifying again.
+    pub fn is_resumable(&self) -> bool {
+        self.is_reconnectable()
+            && !matches!(
+                self,
+                CloseCode::SessionNoLongerValid | CloseCode::InvalidSeq | CloseCode::SessionTimeout
+            )
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 impl CloseCode {
     /// Convert `&self` to a `tokio_tungstenite` [CloseFrame].
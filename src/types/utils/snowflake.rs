@@ -86,7 +86,24 @@ impl<'de> serde::Deserialize<'de> for Snowflake {
             type Value = Snowflake;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("snowflake string")
+                formatter.write_str("snowflake string or integer")
+            }
+
+            // Snowflakes are sent as integers when using etf on the gateway
+            fn visit_u64<E>(self, value: u64) -> Result<Snowflake, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(Snowflake(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Snowflake, E>
+            where
+                E: serde::de::Error,
+            {
+                u64::try_from(value)
+                    .map(Snowflake)
+                    .map_err(|_| serde::de::Error::custom("snowflake must not be negative"))
             }
 
             fn visit_str<E>(self, value: &str) -> Result<Snowflake, E>
@@ -99,7 +116,7 @@ impl<'de> serde::Deserialize<'de> for Snowflake {
                 }
             }
         }
-        deserializer.deserialize_str(SnowflakeVisitor)
+        deserializer.deserialize_any(SnowflakeVisitor)
     }
 }
 
@@ -225,6 +242,13 @@ mod test {
         assert_eq!(snow.timestamp(), timestamp);
     }
 
+    #[test]
+    fn deserialize_integer() {
+        let snow: Snowflake = serde_json::from_str("175928847299117063").unwrap();
+        assert_eq!(snow, Snowflake(175928847299117063));
+        assert!(serde_json::from_str::<Snowflake>("-1").is_err());
+    }
+
 	 #[test]
 	 fn serialize() {
 		  let snowflake = Snowflake(1303390110099968072_u64);
@@ -4,6 +4,7 @@
 
 use std::str::FromStr;
 
+use chorus::instance::GatewayConnectBehaviour;
 use chorus::types::{
     LoginSchema, MfaAuthenticationType, MfaVerifySchema, RegisterSchema, SendMfaSmsSchema,
 };
@@ -61,6 +62,42 @@ async fn test_login() {
     common::teardown(bundle).await;
 }
 
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn test_login_without_gateway() {
+    let mut bundle = common::setup().await;
+    let reg = RegisterSchema {
+        username: "Hiiii".into(),
+        email: Some("testuser1@integrationtesting.xyz".into()),
+        password: Some("Correct-Horse-Battery-Staple1".into()),
+        date_of_birth: Some(NaiveDate::from_str("2000-01-01").unwrap()),
+        consent: true,
+        ..Default::default()
+    };
+    bundle
+        .instance
+        .set_gateway_connect_behaviour(GatewayConnectBehaviour::Manual);
+    let registered = bundle.instance.register_account(reg).await.unwrap();
+    assert!(registered.gateway.is_none());
+
+    let login = LoginSchema {
+        login: "testuser1@integrationtesting.xyz".into(),
+        password: "Correct-Horse-Battery-Staple1".into(),
+        ..Default::default()
+    };
+    let mut user = bundle.instance.login_account(login).await.unwrap();
+    assert!(user.gateway_handle().is_err());
+
+    user.get_settings().await.unwrap();
+
+    user.connect_gateway().await.unwrap();
+    assert!(user.gateway_handle().is_ok());
+
+    user.disconnect_gateway().await;
+    assert!(user.gateway.is_none());
+    common::teardown(bundle).await;
+}
+
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
 #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
 async fn test_wrong_login() {
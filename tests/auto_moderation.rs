@@ -0,0 +1,159 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chorus::types::{
+    AutoModerationAction, AutoModerationActionMetadata, AutoModerationActionMetadataForTimeout,
+    AutoModerationActionType, AutoModerationRuleCreateSchema, AutoModerationRuleModifySchema,
+    AutoModerationRuleTriggerMetadata, AutoModerationRuleTriggerMetadataForKeyword,
+    AutoModerationRuleTriggerMetadataForKeywordPreset, AutoModerationRuleTriggerType, Guild,
+    IntoShared,
+};
+
+mod common;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn keyword_rule() -> AutoModerationRuleCreateSchema {
+    AutoModerationRuleCreateSchema {
+        name: "No bad words".to_string(),
+        trigger_type: AutoModerationRuleTriggerType::Keyword,
+        trigger_metadata: Some(AutoModerationRuleTriggerMetadata::ForKeyword(
+            AutoModerationRuleTriggerMetadataForKeyword {
+                keyword_filter: vec!["bad word".to_string()],
+                ..Default::default()
+            },
+        )),
+        actions: vec![AutoModerationAction {
+            action_type: AutoModerationActionType::BlockMessage,
+            metadata: None,
+        }],
+        ..Default::default()
+    }
+}
+
+fn timeout_action() -> AutoModerationAction {
+    AutoModerationAction {
+        action_type: AutoModerationActionType::Timeout,
+        metadata: Some(
+            AutoModerationActionMetadata::ForTimeout(AutoModerationActionMetadataForTimeout {
+                duration_seconds: 60,
+            })
+            .into_shared(),
+        ),
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn validate_auto_moderation_rule_schemas() {
+    assert!(keyword_rule().validate().is_ok());
+
+    let mut schema = keyword_rule();
+    schema.trigger_metadata = None;
+    assert!(schema.validate().is_err());
+
+    let mut schema = keyword_rule();
+    schema.trigger_type = AutoModerationRuleTriggerType::KeywordPreset;
+    assert!(schema.validate().is_err());
+
+    let mut schema = keyword_rule();
+    schema.actions.clear();
+    assert!(schema.validate().is_err());
+
+    let mut schema = keyword_rule();
+    schema.actions.push(timeout_action());
+    assert!(schema.validate().is_ok());
+
+    // Timeouts are not allowed for keyword preset rules
+    let mut schema = keyword_rule();
+    schema.trigger_type = AutoModerationRuleTriggerType::KeywordPreset;
+    schema.trigger_metadata = Some(AutoModerationRuleTriggerMetadata::ForKeywordPreset(
+        AutoModerationRuleTriggerMetadataForKeywordPreset::default(),
+    ));
+    assert!(schema.validate().is_ok());
+    schema.actions.push(timeout_action());
+    assert!(schema.validate().is_err());
+
+    // Alert actions need a channel to send the alert to
+    let mut schema = keyword_rule();
+    schema.actions[0].action_type = AutoModerationActionType::SendAlertMessage;
+    assert!(schema.validate().is_err());
+
+    let mut schema = keyword_rule();
+    schema.trigger_metadata = Some(AutoModerationRuleTriggerMetadata::ForKeyword(
+        AutoModerationRuleTriggerMetadataForKeyword {
+            keyword_filter: vec!["a".repeat(61)],
+            ..Default::default()
+        },
+    ));
+    assert!(schema.validate().is_err());
+
+    let modify_schema = AutoModerationRuleModifySchema {
+        name: Some(String::new()),
+        ..Default::default()
+    };
+    assert!(modify_schema.validate().is_err());
+
+    let modify_schema = AutoModerationRuleModifySchema {
+        trigger_metadata: Some(AutoModerationRuleTriggerMetadata::ForKeywordPreset(
+            AutoModerationRuleTriggerMetadataForKeywordPreset::default(),
+        )),
+        actions: Some(vec![timeout_action()]),
+        ..Default::default()
+    };
+    assert!(modify_schema.validate().is_err());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn create_modify_delete_auto_moderation_rule() {
+    let mut bundle = common::setup().await;
+    let guild_id = bundle.guild.read().unwrap().id;
+
+    let rule = Guild::create_auto_moderation_rule(&mut bundle.user, guild_id, keyword_rule(), None)
+        .await
+        .unwrap();
+    assert_eq!(rule.name, "No bad words");
+    assert_eq!(rule.guild_id, guild_id);
+
+    let rules = Guild::get_auto_moderation_rules(&mut bundle.user, guild_id)
+        .await
+        .unwrap();
+    assert!(rules.iter().any(|r| r.id == rule.id));
+
+    let modify_schema = AutoModerationRuleModifySchema {
+        name: Some("No very bad words".to_string()),
+        enabled: Some(false),
+        ..Default::default()
+    };
+    let modified = Guild::modify_auto_moderation_rule(
+        &mut bundle.user,
+        guild_id,
+        rule.id,
+        modify_schema,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(modified.name, "No very bad words");
+    assert!(!modified.enabled);
+
+    let fetched = Guild::get_auto_moderation_rule(&mut bundle.user, guild_id, rule.id)
+        .await
+        .unwrap();
+    assert_eq!(fetched.name, "No very bad words");
+
+    Guild::delete_auto_moderation_rule(&mut bundle.user, guild_id, rule.id, None)
+        .await
+        .unwrap();
+    assert!(
+        Guild::get_auto_moderation_rule(&mut bundle.user, guild_id, rule.id)
+            .await
+            .is_err()
+    );
+
+    common::teardown(bundle).await;
+}
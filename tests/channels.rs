@@ -3,9 +3,10 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use chorus::types::{
-    self, Channel, GetChannelMessagesSchema, MessageSendSchema, PermissionFlags,
+    self, ArchivedThreadsType, Channel, ChannelCreateSchema, ChannelType, GetArchivedThreadsQuery,
+    GetChannelMessagesSchema, GetThreadMembersQuery, Guild, MessageSendSchema, PermissionFlags,
     PermissionOverwrite, PermissionOverwriteType, PrivateChannelCreateSchema, RelationshipType,
-    Snowflake,
+    Snowflake, StartThreadFromMessageSchema,
 };
 
 mod common;
@@ -267,3 +268,128 @@ async fn remove_add_person_from_to_dm() {
         user_id
     );
 }
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn start_join_leave_thread() {
+    let mut bundle = common::setup().await;
+    let channel_id = bundle.channel.read().unwrap().id;
+    let guild_id = bundle.guild.read().unwrap().id;
+
+    let message = bundle
+        .user
+        .send_message(
+            MessageSendSchema {
+                content: Some("Let's talk about this".to_string()),
+                ..Default::default()
+            },
+            channel_id,
+        )
+        .await
+        .unwrap();
+
+    let schema = StartThreadFromMessageSchema {
+        name: "Discussion".to_string(),
+        ..Default::default()
+    };
+    let thread =
+        Channel::start_thread_from_message(&mut bundle.user, channel_id, message.id, schema, None)
+            .await
+            .unwrap();
+    assert_eq!(thread.name.as_deref(), Some("Discussion"));
+    assert_eq!(thread.parent_id, Some(channel_id));
+    assert!(thread.channel_type.is_thread());
+
+    let active_threads = Guild::get_active_threads(&mut bundle.user, guild_id)
+        .await
+        .unwrap();
+    assert!(active_threads.threads.iter().any(|t| t.id == thread.id));
+
+    Channel::leave_thread(&mut bundle.user, thread.id)
+        .await
+        .unwrap();
+    Channel::join_thread(&mut bundle.user, thread.id)
+        .await
+        .unwrap();
+
+    let user_id = bundle.user.object.read().unwrap().id;
+    let member = Channel::get_thread_member(&mut bundle.user, thread.id, user_id, false)
+        .await
+        .unwrap();
+    assert_eq!(member.user_id, Some(user_id));
+
+    let query = GetThreadMembersQuery::default();
+    let members = Channel::get_thread_members(&mut bundle.user, thread.id, query)
+        .await
+        .unwrap();
+    assert!(members.iter().any(|m| m.user_id == Some(user_id)));
+
+    common::teardown(bundle).await
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn archived_threads_query() {
+    let query = GetArchivedThreadsQuery {
+        before: Some(chrono::DateTime::from_timestamp(0, 0).unwrap()),
+        before_id: Some(Snowflake(1234)),
+        limit: Some(10),
+    };
+
+    assert_eq!(
+        query.to_query(ArchivedThreadsType::Public),
+        vec![
+            ("before", "1970-01-01T00:00:00+00:00".to_string()),
+            ("limit", "10".to_string())
+        ]
+    );
+    assert_eq!(
+        query.to_query(ArchivedThreadsType::JoinedPrivate),
+        vec![("before", "1234".to_string()), ("limit", "10".to_string())]
+    );
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn follow_unfollow_channel() {
+    let mut bundle = common::setup().await;
+    let guild_id = bundle.guild.read().unwrap().id;
+    let target_channel_id = bundle.channel.read().unwrap().id;
+
+    let announcements = Channel::create(
+        &mut bundle.user,
+        guild_id,
+        None,
+        ChannelCreateSchema {
+            name: "announcements".to_string(),
+            channel_type: Some(ChannelType::GuildNews),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let followed = Channel::follow(&mut bundle.user, announcements.id, target_channel_id, None)
+        .await
+        .unwrap();
+    assert_eq!(followed.channel_id, announcements.id);
+
+    let webhooks = Channel::get_followed_channels(&mut bundle.user, target_channel_id)
+        .await
+        .unwrap();
+    assert!(webhooks
+        .iter()
+        .any(|webhook| webhook.id == followed.webhook_id));
+
+    let deleted = Channel::unfollow(&mut bundle.user, announcements.id, target_channel_id, None)
+        .await
+        .unwrap();
+    assert_eq!(deleted, 1);
+    assert!(
+        Channel::get_followed_channels(&mut bundle.user, target_channel_id)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    common::teardown(bundle).await
+}
@@ -0,0 +1,118 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::sync::Arc;
+
+use chorus::client::{AccountKey, ChorusClient};
+use chorus::types::Snowflake;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn account_key_order() {
+    let first = AccountKey {
+        instance: "http://a.example".to_string(),
+        user_id: Snowflake(2),
+    };
+    let second = AccountKey {
+        instance: "http://b.example".to_string(),
+        user_id: Snowflake(1),
+    };
+
+    assert!(first < second);
+    assert_eq!(first.to_string(), "2@http://a.example");
+    assert!(ChorusClient::new().instance("a.example").is_none());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn manage_accounts() {
+    let mut bundle = common::setup().await;
+    let root = bundle.urls.root.clone();
+
+    let mut client = ChorusClient::new();
+    let first = client.add_account(bundle.user.clone());
+    let second = client.add_account(bundle.create_user("client test").await);
+    assert_ne!(first, second);
+    assert_eq!(first.instance, root);
+    assert_eq!(first.user_id, bundle.user.object.read().unwrap().id);
+
+    // Both accounts share one instance
+    assert_eq!(client.instances().count(), 1);
+    let instance = client.instance(&root).unwrap();
+    assert!(Arc::ptr_eq(
+        &client.account(&first).unwrap().belongs_to,
+        &instance
+    ));
+    assert!(Arc::ptr_eq(
+        &client.account(&second).unwrap().belongs_to,
+        &instance
+    ));
+    assert!(Arc::ptr_eq(
+        &client.get_or_add_instance(&root, None).await.unwrap(),
+        &instance
+    ));
+    assert_eq!(client.accounts_on(&root).count(), 2);
+
+    let guild_id = bundle.guild.read().unwrap().id;
+    assert!(!client.accounts_in_guild(&root, guild_id).contains(&&second));
+
+    assert!(client.remove_account(&second).is_some());
+    assert!(client.account(&second).is_none());
+
+    assert_eq!(client.remove_instance(&root).len(), 1);
+    assert!(client.instance(&root).is_none());
+    assert_eq!(client.accounts().count(), 0);
+
+    common::teardown(bundle).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn find_accounts_by_gateway_cache() {
+    use chorus::types::{Channel, GatewayReady, Guild, IntoShared, User};
+
+    let current_user = User {
+        id: Snowflake(1),
+        ..Default::default()
+    };
+    let ready = GatewayReady {
+        user: current_user.clone(),
+        guilds: vec![Guild {
+            id: Snowflake(2),
+            channels: vec![Channel {
+                id: Snowflake(3),
+                ..Default::default()
+            }
+            .into_shared()],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let (gateway_url, _payloads) = common::create_mock_gateway(ready).await;
+    let user = common::setup_with_mock_gateway(&gateway_url, current_user).await;
+    let root = user.belongs_to.read().unwrap().urls.root.clone();
+    let cache = user.gateway_handle().unwrap().cache().clone();
+
+    let mut client = ChorusClient::new();
+    let key = client.add_account(user);
+
+    // Give the gateway some time to receive the ready event
+    for _ in 0..20 {
+        if cache.current_user().is_some() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+
+    assert_eq!(client.accounts_in_guild(&root, Snowflake(2)), vec![&key]);
+    assert_eq!(client.accounts_in_channel(&root, Snowflake(3)), vec![&key]);
+    assert!(client.accounts_in_guild(&root, Snowflake(4)).is_empty());
+}
@@ -4,7 +4,6 @@
 
 use std::str::FromStr;
 
-use chorus::gateway::{Gateway, GatewayOptions};
 use chorus::types::{DeleteDisableUserSchema, IntoShared, PermissionFlags, Snowflake};
 use chorus::{
     instance::{ChorusUser, Instance},
@@ -57,11 +56,11 @@ impl TestBundle {
             token: self.user.token.clone(),
             mfa_token: None,
             limits: self.user.limits.clone(),
+            ratelimit_queues: self.user.ratelimit_queues.clone(),
+            route_ratelimits: self.user.route_ratelimits.clone(),
             settings: self.user.settings.clone(),
             object: self.user.object.clone(),
-            gateway: Gateway::spawn(&self.instance.urls.wss, GatewayOptions::default())
-                .await
-                .unwrap(),
+            gateway: None,
         }
     }
 }
@@ -388,3 +387,113 @@ pub(crate) fn create_mock_server() -> httptest::Server {
 
     server
 }
+
+/// Starts a mock gateway on a random local port, which says hello to every connection,
+/// dispatches `ready` when identified with and acknowledges resumes and heartbeats.
+///
+/// Returns the gateway's url and a receiver for every payload sent to it.
+///
+/// Note: this does not work on wasm!
+#[allow(dead_code)]
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn create_mock_gateway(
+    ready: chorus::types::GatewayReady,
+) -> (
+    String,
+    tokio::sync::mpsc::UnboundedReceiver<serde_json::Value>,
+) {
+    use futures_util::{SinkExt, StreamExt};
+    use serde_json::json;
+    use tokio_tungstenite::tungstenite::Message;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let (payload_send, payload_receive) = tokio::sync::mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let ready = ready.clone();
+            let payload_send = payload_send.clone();
+
+            tokio::spawn(async move {
+                let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let hello = json!({"op": 10, "d": {"heartbeat_interval": 45000}});
+                websocket
+                    .send(Message::text(hello.to_string()))
+                    .await
+                    .unwrap();
+
+                let mut sequence = 0;
+                while let Some(Ok(message)) = websocket.next().await {
+                    let Ok(text) = message.to_text() else {
+                        continue;
+                    };
+                    let Ok(payload) = serde_json::from_str::<serde_json::Value>(text) else {
+                        continue;
+                    };
+
+                    let response = match payload["op"].as_u64() {
+                        Some(1) => json!({"op": 11, "d": null}),
+                        Some(2) => {
+                            sequence += 1;
+                            json!({"op": 0, "t": "READY", "s": sequence, "d": ready})
+                        }
+                        Some(6) => {
+                            sequence += 1;
+                            json!({"op": 0, "t": "RESUMED", "s": sequence, "d": {"_trace": []}})
+                        }
+                        _ => json!(null),
+                    };
+                    let _ = payload_send.send(payload);
+
+                    if !response.is_null() {
+                        let _ = websocket.send(Message::text(response.to_string())).await;
+                    }
+                }
+            });
+        }
+    });
+
+    (url, payload_receive)
+}
+
+/// Creates a user on an instance which can't be reached over http, connected to a mock gateway
+/// created with [create_mock_gateway].
+///
+/// Note: this does not work on wasm!
+#[allow(dead_code)]
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn setup_with_mock_gateway(
+    gateway_url: &str,
+    user: chorus::types::User,
+) -> ChorusUser {
+    use chorus::gateway::{GatewayOptions, GatewayTransportCompression};
+    use chorus::types::{ClientProperties, UserSettings};
+
+    // Nothing listens on the discard port, so all http requests fail right away
+    let urls = UrlBundle::new(
+        "http://127.0.0.1:9",
+        "http://127.0.0.1:9/api",
+        gateway_url,
+        "http://127.0.0.1:9/cdn",
+    );
+    let options = GatewayOptions {
+        transport_compression: GatewayTransportCompression::None,
+        ..Default::default()
+    };
+    let instance = Instance::from_url_bundle(urls, Some(options))
+        .await
+        .unwrap();
+
+    let mut user = ChorusUser::new(
+        instance.into_shared(),
+        "faketoken".to_string(),
+        ClientProperties::default(),
+        None,
+        UserSettings::default().into_shared(),
+        user.into_shared(),
+        None,
+    );
+    user.connect_gateway().await.unwrap();
+    user
+}
@@ -0,0 +1,122 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chorus::types::{
+    image_data_uri, image_mime_type, Emoji, EmojiCreateSchema, EmojiModifySchema,
+    GuildCreateStickerSchema, GuildModifyStickerSchema, Sticker, StickerFormatType,
+};
+
+mod common;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// A transparent 1x1 PNG image
+const PNG: [u8; 68] = [
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1F, 0x15, 0xC4,
+    0x89, 0x00, 0x00, 0x00, 0x0B, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0x60, 0x00, 0x02, 0x00,
+    0x00, 0x05, 0x00, 0x01, 0x7A, 0x5E, 0xAB, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44,
+    0xAE, 0x42, 0x60, 0x82,
+];
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_image_data_uri() {
+    assert_eq!(image_mime_type(&PNG), Some("image/png"));
+    assert_eq!(image_mime_type(b"GIF89a..."), Some("image/gif"));
+    assert_eq!(image_mime_type(b"RIFF\0\0\0\0WEBPVP8 "), Some("image/webp"));
+    assert_eq!(image_mime_type(b"not an image"), None);
+
+    assert!(image_data_uri(&PNG)
+        .unwrap()
+        .starts_with("data:image/png;base64,iVBORw0KGgo"));
+
+    assert!(EmojiCreateSchema::from_image(None, b"not an image", Vec::new()).is_err());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn create_modify_delete_emoji() {
+    let mut bundle = common::setup().await;
+    let guild_id = bundle.guild.read().unwrap().id;
+
+    let schema =
+        EmojiCreateSchema::from_image(Some("chorus".to_string()), &PNG, Vec::new()).unwrap();
+    let emoji = Emoji::create(&mut bundle.user, guild_id, schema, None)
+        .await
+        .unwrap();
+    assert_eq!(emoji.name.as_deref(), Some("chorus"));
+
+    let emojis = Emoji::get_all(&mut bundle.user, guild_id).await.unwrap();
+    assert!(emojis.iter().any(|e| e.id == emoji.id));
+
+    let modify_schema = EmojiModifySchema {
+        name: Some("spacebar".to_string()),
+        ..Default::default()
+    };
+    let modified = Emoji::modify(&mut bundle.user, guild_id, emoji.id, modify_schema, None)
+        .await
+        .unwrap();
+    assert_eq!(modified.name.as_deref(), Some("spacebar"));
+
+    let fetched = Emoji::get(&mut bundle.user, guild_id, emoji.id)
+        .await
+        .unwrap();
+    assert_eq!(fetched.name.as_deref(), Some("spacebar"));
+
+    Emoji::delete(&mut bundle.user, guild_id, emoji.id, None)
+        .await
+        .unwrap();
+    assert!(Emoji::get(&mut bundle.user, guild_id, emoji.id)
+        .await
+        .is_err());
+
+    common::teardown(bundle).await;
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn create_modify_delete_sticker() {
+    let mut bundle = common::setup().await;
+    let guild_id = bundle.guild.read().unwrap().id;
+
+    let schema = GuildCreateStickerSchema {
+        name: "chorus".to_string(),
+        description: Some("A sticker".to_string()),
+        tags: Some("chorus".to_string()),
+        file_data: PNG.to_vec(),
+        sticker_format_type: StickerFormatType::PNG,
+    };
+    let sticker = Sticker::create(&mut bundle.user, guild_id, schema, None)
+        .await
+        .unwrap();
+    assert_eq!(sticker.name, "chorus");
+
+    let stickers = Sticker::get_guild_stickers(&mut bundle.user, guild_id)
+        .await
+        .unwrap();
+    assert!(stickers.iter().any(|s| s.id == sticker.id));
+
+    let modify_schema = GuildModifyStickerSchema {
+        name: Some("spacebar".to_string()),
+        ..Default::default()
+    };
+    let modified = Sticker::modify(&mut bundle.user, guild_id, sticker.id, modify_schema, None)
+        .await
+        .unwrap();
+    assert_eq!(modified.name, "spacebar");
+
+    Sticker::delete(&mut bundle.user, guild_id, sticker.id, None)
+        .await
+        .unwrap();
+    assert!(
+        Sticker::get_guild_sticker(&mut bundle.user, guild_id, sticker.id)
+            .await
+            .is_err()
+    );
+
+    common::teardown(bundle).await;
+}
@@ -77,7 +77,7 @@ async fn test_gateway_authenticate() {
 #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
 /// Tests establishing a connection and receiving errors
 async fn test_gateway_errors() {
-    let bundle = common::setup().await;
+    let mut bundle = common::setup().await;
 
     // FIXME: Without this, this test does not work
     //
@@ -87,7 +87,7 @@ async fn test_gateway_errors() {
     //
     // Anyway, if you have a free weekend to spend debugging wasm, you're welcome to have a crack
     // at this
-    bundle.user.gateway.close().await;
+    bundle.user.disconnect_gateway().await;
 
     let gateway: GatewayHandle = Gateway::spawn(&bundle.urls.wss, GatewayOptions::default())
         .await
@@ -140,7 +140,8 @@ async fn test_self_updating_structs() {
 
     let received_channel = bundle
         .user
-        .gateway
+        .gateway_handle()
+        .unwrap()
         .observe_and_into_inner(bundle.channel.clone())
         .await;
 
@@ -157,7 +158,8 @@ async fn test_self_updating_structs() {
     assert_eq!(
         bundle
             .user
-            .gateway
+            .gateway_handle()
+            .unwrap()
             .observe_and_into_inner(bundle.channel.clone())
             .await
             .name
@@ -167,7 +169,8 @@ async fn test_self_updating_structs() {
 
     let guild = bundle
         .user
-        .gateway
+        .gateway_handle()
+        .unwrap()
         .observe_and_into_inner(bundle.guild.clone())
         .await;
     assert!(guild.channels.is_empty());
@@ -187,7 +190,8 @@ async fn test_self_updating_structs() {
 
     let guild = bundle
         .user
-        .gateway
+        .gateway_handle()
+        .unwrap()
         .observe_and_into_inner(guild.into_shared())
         .await;
     assert!(!guild.channels.is_empty());
@@ -203,7 +207,12 @@ async fn test_recursive_self_updating_structs() {
     let mut bundle = common::setup().await;
     let guild = bundle.guild.clone();
     // Observe Guild, make sure it has no channels
-    let guild = bundle.user.gateway.observe(guild.clone()).await;
+    let guild = bundle
+        .user
+        .gateway_handle()
+        .unwrap()
+        .observe(guild.clone())
+        .await;
     let inner_guild = guild.read().unwrap().clone();
     assert!(inner_guild.roles.is_empty());
     // Create Role
@@ -225,7 +234,8 @@ async fn test_recursive_self_updating_structs() {
     // Watch role;
     bundle
         .user
-        .gateway
+        .gateway_handle()
+        .unwrap()
         .observe(role.clone().into_shared())
         .await;
     // Update Guild and check for Guild
@@ -238,15 +248,463 @@ async fn test_recursive_self_updating_structs() {
         .unwrap();
     let role_inner = bundle
         .user
-        .gateway
+        .gateway_handle()
+        .unwrap()
         .observe_and_into_inner(role.clone().into_shared())
         .await;
     assert_eq!(role_inner.name, "yippieee");
     // Check if the change propagated
-    let guild = bundle.user.gateway.observe(bundle.guild.clone()).await;
+    let guild = bundle
+        .user
+        .gateway_handle()
+        .unwrap()
+        .observe(bundle.guild.clone())
+        .await;
     let inner_guild = guild.read().unwrap().clone();
     let guild_roles = inner_guild.roles;
     let guild_role_inner = guild_roles.first().unwrap().read().unwrap().clone();
     assert_eq!(guild_role_inner.name, "yippieee".to_string());
     common::teardown(bundle).await;
 }
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+/// Tests that the gateway remembers what it needs to resume a session
+async fn test_gateway_session_state() {
+    let bundle = common::setup().await;
+
+    let gateway: GatewayHandle = Gateway::spawn(&bundle.urls.wss, GatewayOptions::default())
+        .await
+        .unwrap();
+
+    assert!(!gateway.session_state().await.is_resumable());
+
+    let mut identify = types::GatewayIdentifyPayload::common();
+    identify.token = bundle.user.token.clone();
+
+    let ready = gateway.identify(identify).await.unwrap();
+
+    let session = gateway.session_state().await;
+    assert_eq!(session.session_id, Some(ready.session_id));
+    assert!(session.sequence_number.is_some());
+    assert!(session.is_resumable());
+
+    common::teardown(bundle).await
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_reconnect_backoff() {
+    let options = GatewayReconnectOptions {
+        initial_backoff: Duration::from_secs(1),
+        max_backoff: Duration::from_secs(8),
+        ..Default::default()
+    };
+
+    for attempt in 1..10 {
+        let expected = Duration::from_secs(2u64.pow(attempt - 1).min(8));
+        let delay = options.backoff_for_attempt(attempt);
+
+        assert!(delay >= expected / 2);
+        assert!(delay <= expected);
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_close_code_reconnectable() {
+    use chorus::types::CloseCode;
+
+    assert!(CloseCode::UnknownError.is_reconnectable());
+    assert!(CloseCode::UnknownError.is_resumable());
+    assert!(CloseCode::SessionTimeout.is_reconnectable());
+    assert!(!CloseCode::SessionTimeout.is_resumable());
+    assert!(!CloseCode::AuthenticationFailed.is_reconnectable());
+    assert!(!CloseCode::ShardingRequired.is_resumable());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_etf_decode() {
+    // {"op": 10, "d": {"heartbeat_interval": 41250, "id": 175928847299117063, "trace": nil}}
+    let mut bytes = vec![131, 116, 0, 0, 0, 2];
+    bytes.extend([109, 0, 0, 0, 2, b'o', b'p', 97, 10]);
+    bytes.extend([109, 0, 0, 0, 1, b'd', 116, 0, 0, 0, 3]);
+    bytes.extend([109, 0, 0, 0, 18]);
+    bytes.extend(b"heartbeat_interval");
+    bytes.extend([98, 0, 0, 161, 34]);
+    bytes.extend([109, 0, 0, 0, 2, b'i', b'd']);
+    bytes.extend([110, 8, 0]);
+    bytes.extend(175928847299117063_u64.to_le_bytes());
+    bytes.extend([109, 0, 0, 0, 5]);
+    bytes.extend(b"trace");
+    bytes.extend([115, 3, b'n', b'i', b'l']);
+
+    let decoded = etf::decode(&bytes).unwrap();
+    assert_eq!(
+        decoded,
+        serde_json::json!({"op": 10, "d": {"heartbeat_interval": 41250, "id": 175928847299117063_u64, "trace": null}})
+    );
+
+    assert!(etf::decode(&[131, 107]).is_err());
+    assert!(etf::decode(&[130, 106]).is_err());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_etf_roundtrip() {
+    let value = serde_json::json!({
+        "op": 2,
+        "d": {
+            "token": "faketoken",
+            "large_threshold": 250,
+            "compress": false,
+            "presence": null,
+            "shard": [0, 1],
+            "capabilities": 16381,
+            "big": 9007199254740993_u64,
+            "negative": -5,
+            "very_negative": -9007199254740993_i64,
+            "float": 1.5,
+            "empty": [],
+            "nested": {"list": ["a", "b"]},
+        }
+    });
+
+    let encoded = etf::encode(&value);
+    assert_eq!(encoded[0], 131);
+    assert_eq!(etf::decode(&encoded).unwrap(), value);
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_etf_decode_compressed() {
+    use std::io::Write;
+
+    let value = serde_json::json!({"op": 11, "d": ["a", "b"]});
+    let term = &etf::encode(&value)[1..];
+
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(term).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let compressed_term = |uncompressed_size: u32| {
+        let mut bytes = vec![131, 80];
+        bytes.extend(uncompressed_size.to_be_bytes());
+        bytes.extend(&compressed);
+        bytes
+    };
+
+    let size = term.len() as u32;
+    assert_eq!(etf::decode(&compressed_term(size)).unwrap(), value);
+    // The announced size has to match exactly
+    assert!(matches!(
+        etf::decode(&compressed_term(size - 1)),
+        Err(etf::EtfError::InvalidCompressedTerm { .. })
+    ));
+    assert!(matches!(
+        etf::decode(&compressed_term(u32::MAX)),
+        Err(etf::EtfError::InvalidCompressedTerm { .. })
+    ));
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_etf_decode_nesting() {
+    // Tuples with a single element, wrapped around an empty list
+    let nested = |depth: usize| {
+        let mut bytes = vec![131];
+        for _ in 0..depth {
+            bytes.extend([104, 1]);
+        }
+        bytes.push(106);
+        bytes
+    };
+
+    assert!(etf::decode(&nested(127)).is_ok());
+    assert!(matches!(
+        etf::decode(&nested(128)),
+        Err(etf::EtfError::TooDeeplyNested { max_depth: 128 })
+    ));
+    assert!(matches!(
+        etf::decode(&nested(100_000)),
+        Err(etf::EtfError::TooDeeplyNested { .. })
+    ));
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+/// Tests running two shards and receiving both of their ready events
+async fn test_shard_manager() {
+    let bundle = common::setup().await;
+
+    let mut identify = types::GatewayIdentifyPayload::common();
+    identify.token = bundle.user.token.clone();
+
+    let options = ShardManagerOptions {
+        shard_count: 2,
+        identify_interval: Duration::from_millis(100),
+        ..Default::default()
+    };
+
+    let mut manager = ShardManager::new(
+        &bundle.urls.wss,
+        GatewayOptions::default(),
+        options,
+        identify,
+    )
+    .unwrap();
+    let mut events = manager.events();
+    manager.start().await.unwrap();
+
+    assert_eq!(manager.shards().len(), 2);
+
+    let mut ready_shards = Vec::new();
+
+    while ready_shards.len() < 2 {
+        let event = tokio::select! {
+            () = sleep(Duration::from_secs(10)) => panic!("Did not receive READY from every shard"),
+            event = events.recv() => event.unwrap(),
+        };
+
+        if event.dispatch.event_name == "READY" {
+            ready_shards.push(event.shard_id);
+        }
+    }
+
+    ready_shards.sort();
+    assert_eq!(ready_shards, vec![0, 1]);
+
+    manager.close().await;
+    common::teardown(bundle).await
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_shard_id_for_guild() {
+    // Example from https://docs.discord.sex/topics/gateway#sharding-formula
+    let guild_id = types::Snowflake(197038439483310086);
+
+    assert_eq!(shard_id_for_guild(guild_id, 1), 0);
+    assert_eq!(
+        shard_id_for_guild(guild_id, 2),
+        (197038439483310086 >> 22) % 2
+    );
+    assert_eq!(
+        shard_id_for_guild(guild_id, 1000),
+        (197038439483310086 >> 22) % 1000
+    );
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_identify_shard_serialization() {
+    let mut identify = types::GatewayIdentifyPayload::common();
+    identify.shard = Some((1, 4));
+
+    let value = serde_json::to_value(&identify).unwrap();
+    assert_eq!(value["shard"], serde_json::json!([1, 4]));
+
+    assert!(ShardManager::new(
+        "ws://localhost:3001",
+        GatewayOptions::default(),
+        ShardManagerOptions::with_shard_count(0),
+        identify,
+    )
+    .is_err());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+/// Tests that the gateway cache is kept up to date by dispatched events
+async fn test_gateway_cache() {
+    let mut bundle = common::setup().await;
+
+    let channel = bundle.channel.read().unwrap().clone();
+    let cache = bundle.user.gateway_handle().unwrap().cache().clone();
+    assert!(cache.is_enabled());
+    assert!(cache.current_user().is_some());
+
+    let modify_schema = ChannelModifySchema {
+        name: Some("cached".to_string()),
+        ..Default::default()
+    };
+    channel
+        .clone()
+        .modify(modify_schema, None, &mut bundle.user)
+        .await
+        .unwrap();
+
+    // Give the gateway some time to receive the update
+    let mut cached_name = None;
+    for _ in 0..20 {
+        cached_name = cache.channel(channel.id).and_then(|channel| channel.name);
+        if cached_name.as_deref() == Some("cached") {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(cached_name.as_deref(), Some("cached"));
+
+    common::teardown(bundle).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+/// Tests that the cache of a user's gateway connection is filled from the ready event
+async fn test_gateway_cache_from_ready() {
+    let current_user = types::User {
+        id: types::Snowflake(1),
+        username: "cached".to_string(),
+        ..Default::default()
+    };
+    let channel = Channel {
+        id: types::Snowflake(3),
+        ..Default::default()
+    };
+    let ready = types::GatewayReady {
+        user: current_user.clone(),
+        guilds: vec![types::Guild {
+            id: types::Snowflake(2),
+            channels: vec![channel.into_shared()],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let (gateway_url, _payloads) = common::create_mock_gateway(ready).await;
+    let user = common::setup_with_mock_gateway(&gateway_url, current_user).await;
+    let cache = user.gateway_handle().unwrap().cache().clone();
+
+    // Give the gateway some time to receive the ready event
+    for _ in 0..20 {
+        if cache.current_user().is_some() {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(cache.current_user().unwrap().username, "cached");
+    assert!(cache.guild(types::Snowflake(2)).is_some());
+    assert_eq!(
+        cache.channel(types::Snowflake(3)).unwrap().guild_id,
+        Some(types::Snowflake(2))
+    );
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_gateway_cache_disabled() {
+    let cache = GatewayCache::new(GatewayCacheOptions::disabled());
+
+    assert!(!cache.is_enabled());
+    assert!(cache.guild_ids().is_empty());
+    assert!(cache.current_user().is_none());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+/// Tests that thread members are tracked from thread events
+async fn test_gateway_cache_thread_members() {
+    let cache = GatewayCache::new(GatewayCacheOptions::default());
+    let guild_id = types::Snowflake(1);
+    let parent_id = types::Snowflake(2);
+    let thread_id = types::Snowflake(3);
+    let thread_member = |user_id| types::ThreadMember {
+        user_id: Some(types::Snowflake(user_id)),
+        ..Default::default()
+    };
+
+    let thread = Channel {
+        id: thread_id,
+        guild_id: Some(guild_id),
+        parent_id: Some(parent_id),
+        channel_type: types::ChannelType::GuildPublicThread,
+        ..Default::default()
+    };
+    cache
+        .update(&types::ThreadCreate {
+            thread: thread.clone(),
+            ..Default::default()
+        })
+        .await;
+
+    cache
+        .update(&types::ThreadMembersUpdate {
+            id: thread_id,
+            guild_id,
+            member_count: 2,
+            added_members: Some(vec![thread_member(10), thread_member(11)]),
+            ..Default::default()
+        })
+        .await;
+    assert_eq!(cache.thread_members_of(thread_id).len(), 2);
+    let member = cache
+        .thread_member(thread_id, types::Snowflake(10))
+        .unwrap();
+    assert_eq!(member.id, Some(thread_id));
+    assert_eq!(cache.channel(thread_id).unwrap().member_count, Some(2));
+
+    cache
+        .update(&types::ThreadMembersUpdate {
+            id: thread_id,
+            guild_id,
+            member_count: 1,
+            removed_members: Some(vec![types::Snowflake(10)]),
+            ..Default::default()
+        })
+        .await;
+    let user_ids: Vec<_> = cache
+        .thread_members_of(thread_id)
+        .into_iter()
+        .map(|member| member.user_id)
+        .collect();
+    assert_eq!(user_ids, vec![Some(types::Snowflake(11))]);
+
+    // Syncing the parent drops threads which are no longer active
+    cache
+        .update(&types::ThreadListSync {
+            guild_id,
+            channel_ids: Some(vec![parent_id]),
+            ..Default::default()
+        })
+        .await;
+    assert!(cache.channel(thread_id).is_none());
+    assert!(cache.thread_members_of(thread_id).is_empty());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+/// Tests receiving typed, filtered events from an event stream
+async fn test_gateway_event_stream() {
+    use futures_util::StreamExt;
+
+    let mut bundle = common::setup().await;
+    let channel = bundle.channel.read().unwrap().clone();
+
+    let mut updates = bundle
+        .user
+        .gateway_handle()
+        .unwrap()
+        .stream::<types::ChannelUpdate>()
+        .await
+        .in_channel(channel.id);
+
+    let modify_schema = ChannelModifySchema {
+        name: Some("streamed".to_string()),
+        ..Default::default()
+    };
+    channel
+        .clone()
+        .modify(modify_schema, None, &mut bundle.user)
+        .await
+        .unwrap();
+
+    let update = tokio::select! {
+        () = sleep(Duration::from_secs(5)) => panic!("Did not receive CHANNEL_UPDATE"),
+        update = updates.next() => update.unwrap(),
+    };
+    assert_eq!(update.channel.id, channel.id);
+    assert_eq!(update.channel.name.as_deref(), Some("streamed"));
+
+    common::teardown(bundle).await
+}
@@ -3,8 +3,11 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use chorus::types::{
-    CreateChannelInviteSchema, Guild, GuildBanCreateSchema, GuildCreateSchema, GuildModifySchema,
+    AuditLogActionType, CreateChannelInviteSchema, GetAuditLogsQuery, Guild, GuildBanCreateSchema,
+    GuildCreateFromTemplateSchema, GuildCreateSchema, GuildModifySchema, GuildTemplate,
+    GuildTemplateCreateSchema, GuildTemplateModifySchema,
 };
+use futures_util::StreamExt;
 
 mod common;
 #[cfg(target_arch = "wasm32")]
@@ -84,6 +87,47 @@ async fn modify_guild() {
     common::teardown(bundle).await
 }
 
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn get_audit_logs() {
+    let mut bundle = common::setup().await;
+    let guild_id = bundle.guild.read().unwrap().id;
+    let schema = GuildModifySchema {
+        name: Some("Audited".to_string()),
+        ..Default::default()
+    };
+    Guild::modify(guild_id, schema, None, &mut bundle.user)
+        .await
+        .unwrap();
+
+    let query = GetAuditLogsQuery {
+        action_type: Some(AuditLogActionType::GuildUpdate),
+        ..Default::default()
+    };
+    let audit_log = Guild::get_audit_logs(&mut bundle.user, guild_id, query)
+        .await
+        .unwrap();
+    let entry = audit_log.audit_log_entries.first().unwrap();
+    assert_eq!(entry.action_type, AuditLogActionType::GuildUpdate);
+    assert!(entry
+        .decoded_changes()
+        .iter()
+        .any(|change| change.key == "name"));
+
+    let query = GetAuditLogsQuery {
+        limit: Some(1),
+        ..Default::default()
+    };
+    let entries: Vec<_> = Guild::audit_log_paginator(&bundle.user, guild_id, query)
+        .into_stream()
+        .collect()
+        .await;
+    assert!(!entries.is_empty());
+    assert!(entries.iter().all(|entry| entry.is_ok()));
+
+    common::teardown(bundle).await
+}
+
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
 #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
 async fn guild_remove_member() {
@@ -109,3 +153,79 @@ async fn guild_remove_member() {
     );
     common::teardown(bundle).await
 }
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn guild_template_workflow() {
+    let mut bundle = common::setup().await;
+    let guild_id = bundle.guild.read().unwrap().id;
+
+    let template = GuildTemplate::create(
+        &mut bundle.user,
+        guild_id,
+        GuildTemplateCreateSchema {
+            name: "Test template".to_string(),
+            description: None,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(template.source_guild_id, guild_id);
+
+    let templates = GuildTemplate::get_all(&mut bundle.user, guild_id)
+        .await
+        .unwrap();
+    assert!(templates.iter().any(|t| t.code == template.code));
+
+    let resolved = bundle
+        .instance
+        .get_guild_template(&template.code)
+        .await
+        .unwrap();
+    assert_eq!(resolved.code, template.code);
+    assert_eq!(
+        Some(resolved.serialized_source_guild.name),
+        bundle.guild.read().unwrap().name.clone()
+    );
+
+    let modified = GuildTemplate::modify(
+        &mut bundle.user,
+        guild_id,
+        &template.code,
+        GuildTemplateModifySchema {
+            name: Some("Renamed template".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(modified.name, "Renamed template");
+
+    GuildTemplate::sync(&mut bundle.user, guild_id, &template.code)
+        .await
+        .unwrap();
+
+    let guild = GuildTemplate::create_guild(
+        &mut bundle.user,
+        &template.code,
+        GuildCreateFromTemplateSchema {
+            name: "From template".to_string(),
+            icon: None,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(guild.name.as_deref(), Some("From template"));
+    Guild::delete(&mut bundle.user, guild.id).await.unwrap();
+
+    GuildTemplate::delete(&mut bundle.user, guild_id, &template.code)
+        .await
+        .unwrap();
+    assert!(bundle
+        .instance
+        .get_guild_template(&template.code)
+        .await
+        .is_err());
+
+    common::teardown(bundle).await
+}
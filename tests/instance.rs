@@ -3,7 +3,9 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod common;
-use chorus::instance::InstanceSoftware;
+use std::time::Duration;
+
+use chorus::instance::{HttpConfig, HttpVersionPreference, InstanceSoftware};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_test::*;
 #[cfg(target_arch = "wasm32")]
@@ -33,3 +35,50 @@ async fn detect_instance_software() {
 
     common::teardown(bundle).await;
 }
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn build_http_client() {
+    let mut config = HttpConfig {
+        connect_timeout: Some(Duration::from_secs(5)),
+        timeout: Some(Duration::from_secs(30)),
+        http_version: HttpVersionPreference::Http1Only,
+        ..Default::default()
+    };
+    config
+        .default_headers
+        .insert("X-Test", "chorus".parse().unwrap());
+    assert!(config.build_client().is_ok());
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let invalid_proxy = HttpConfig {
+            proxy: Some("not a url".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            invalid_proxy.build_client(),
+            Err(chorus::errors::ChorusError::InvalidArguments { .. })
+        ));
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn set_http_config() {
+    let mut bundle = common::setup().await;
+
+    let config = HttpConfig {
+        timeout: Some(Duration::from_secs(30)),
+        ..Default::default()
+    };
+    bundle.instance.set_http_config(config.clone()).unwrap();
+    assert_eq!(bundle.instance.http_config(), &config);
+    bundle
+        .instance
+        .general_configuration_schema()
+        .await
+        .unwrap();
+
+    common::teardown(bundle).await;
+}
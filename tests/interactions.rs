@@ -0,0 +1,181 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+use std::sync::{Arc, Mutex};
+
+use chorus::api::{CommandContext, CommandOptions, FromCommandOptions, InteractionDispatcher};
+use chorus::errors::ChorusResult;
+use chorus::types::{Interaction, InteractionType, Snowflake};
+use serde_json::json;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn role_add_interaction() -> Interaction {
+    serde_json::from_value(json!({
+        "id": "1",
+        "application_id": "2",
+        "type": 2,
+        "data": {
+            "id": "3",
+            "name": "role",
+            "type": 1,
+            "options": [{
+                "name": "add",
+                "type": 1,
+                "options": [
+                    { "name": "user", "type": 6, "value": "4" },
+                    { "name": "days", "type": 4, "value": 7 },
+                    { "name": "silent", "type": 5, "value": true }
+                ]
+            }]
+        },
+        "guild_id": "5",
+        "channel_id": "6",
+        "user": { "id": "4", "username": "test", "discriminator": "0" },
+        "token": "token",
+        "version": 1
+    }))
+    .unwrap()
+}
+
+#[derive(Debug, PartialEq)]
+struct RoleAdd {
+    user: Snowflake,
+    days: i64,
+    silent: bool,
+    reason: Option<String>,
+}
+
+impl FromCommandOptions for RoleAdd {
+    fn from_options(options: &CommandOptions) -> ChorusResult<Self> {
+        Ok(RoleAdd {
+            user: options.get("user")?,
+            days: options.get("days")?,
+            silent: options.get("silent")?,
+            reason: options.get_optional("reason")?,
+        })
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn parse_command_options() {
+    let interaction = role_add_interaction();
+    assert_eq!(interaction.invoker_id(), Some(Snowflake(4)));
+
+    let data = interaction.command_data().unwrap();
+    assert_eq!(data.name, "role");
+
+    let options = CommandOptions::from_data(&data);
+    assert_eq!(options.subcommand(), Some("add"));
+    assert_eq!(options.subcommand_path(), &["add".to_string()]);
+    assert_eq!(
+        RoleAdd::from_options(&options).unwrap(),
+        RoleAdd {
+            user: Snowflake(4),
+            days: 7,
+            silent: true,
+            reason: None,
+        }
+    );
+
+    assert!(options.get::<String>("reason").is_err());
+    assert!(options.get::<String>("days").is_err());
+
+    let mut interaction = interaction;
+    interaction.r#type = InteractionType::MessageComponent;
+    assert!(interaction.command_data().is_err());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn dispatch_interactions() {
+    let bundle = common::setup().await;
+
+    let handled = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = InteractionDispatcher::new(bundle.user.clone());
+    let role_handled = handled.clone();
+    dispatcher.register("role", move |_: CommandContext| {
+        let handled = role_handled.clone();
+        async move {
+            handled.lock().unwrap().push("role".to_string());
+            Ok(())
+        }
+    });
+    let role_add_handled = handled.clone();
+    dispatcher.register("role add", move |context: CommandContext| {
+        let handled = role_add_handled.clone();
+        async move {
+            let options = context.parse::<RoleAdd>()?;
+            handled
+                .lock()
+                .unwrap()
+                .push(format!("role add {}", options.days));
+            Ok(())
+        }
+    });
+
+    let interaction = role_add_interaction();
+    let data = interaction.command_data().unwrap();
+    assert_eq!(dispatcher.route(&data), Some("role add"));
+    assert!(dispatcher.dispatch(&interaction).await.unwrap());
+
+    dispatcher.unregister("role add");
+    assert_eq!(dispatcher.route(&data), Some("role"));
+    assert!(dispatcher.dispatch(&interaction).await.unwrap());
+
+    let mut unknown = interaction.clone();
+    unknown.data["name"] = json!("unknown");
+    assert!(!dispatcher.dispatch(&unknown).await.unwrap());
+
+    let mut component = interaction;
+    component.r#type = InteractionType::MessageComponent;
+    assert!(!dispatcher.dispatch(&component).await.unwrap());
+
+    assert_eq!(
+        *handled.lock().unwrap(),
+        vec!["role add 7".to_string(), "role".to_string()]
+    );
+    common::teardown(bundle).await
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+#[cfg(not(target_arch = "wasm32"))]
+async fn dispatch_interactions_from_gateway() {
+    use chorus::types::InteractionCreate;
+    use pubserve::Subscriber;
+    use tokio::sync::Notify;
+
+    let bundle = common::setup().await;
+
+    let release = Arc::new(Notify::new());
+    let finished = Arc::new(Notify::new());
+    let mut dispatcher = InteractionDispatcher::new(bundle.user.clone());
+    let (handler_release, handler_finished) = (release.clone(), finished.clone());
+    dispatcher.register("role", move |_: CommandContext| {
+        let (release, finished) = (handler_release.clone(), handler_finished.clone());
+        async move {
+            release.notified().await;
+            finished.notify_one();
+            Ok(())
+        }
+    });
+
+    // The handler runs in its own task, so this returns while it is still waiting
+    dispatcher
+        .update(&InteractionCreate {
+            interaction: role_add_interaction(),
+        })
+        .await;
+
+    release.notify_one();
+    tokio::time::timeout(std::time::Duration::from_secs(5), finished.notified())
+        .await
+        .unwrap();
+
+    common::teardown(bundle).await
+}
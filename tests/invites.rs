@@ -3,7 +3,11 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod common;
-use chorus::types::CreateChannelInviteSchema;
+use chorus::api::{InviteAttribution, InviteTracker};
+use chorus::types::{
+    Channel, CreateChannelInviteSchema, GetInvitesSchema, Guild, GuildInvite, GuildMemberAdd,
+    InviteCreate, InviteDelete, Snowflake,
+};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_test::*;
 #[cfg(target_arch = "wasm32")]
@@ -39,3 +43,131 @@ async fn create_accept_invite() {
 
     common::teardown(bundle).await;
 }
+
+fn invite(code: &str, uses: i32, max_uses: i32) -> GuildInvite {
+    GuildInvite {
+        code: code.to_string(),
+        uses: Some(uses),
+        max_uses: Some(max_uses),
+        guild_id: Snowflake(1),
+        channel_id: Snowflake(2),
+        ..Default::default()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn invite_tracker_attributes_joins() {
+    let mut tracker = InviteTracker::from_invites(
+        Snowflake(1),
+        vec![invite("aaaa", 0, 0), invite("bbbb", 3, 0)],
+    );
+
+    assert_eq!(
+        tracker.update(vec![invite("aaaa", 0, 0), invite("bbbb", 4, 0)]),
+        InviteAttribution::Invite(invite("bbbb", 4, 0))
+    );
+    assert_eq!(
+        tracker.update(vec![invite("aaaa", 0, 0), invite("bbbb", 4, 0)]),
+        InviteAttribution::Unknown
+    );
+    assert_eq!(
+        tracker.update(vec![invite("aaaa", 1, 0), invite("bbbb", 5, 0)]),
+        InviteAttribution::Ambiguous(vec![invite("aaaa", 1, 0), invite("bbbb", 5, 0)])
+    );
+
+    // Invites of other guilds are ignored
+    let mut other_guild_invite = invite("cccc", 0, 1);
+    other_guild_invite.guild_id = Snowflake(2);
+    tracker.invite_created(&InviteCreate {
+        invite: other_guild_invite,
+        ..Default::default()
+    });
+    assert_eq!(tracker.invites().count(), 2);
+
+    // Single use invites are deleted once used, but the join is still attributed to them
+    tracker.invite_created(&InviteCreate {
+        invite: invite("dddd", 0, 1),
+        ..Default::default()
+    });
+    tracker.invite_deleted(&InviteDelete {
+        guild_id: Some(Snowflake(1)),
+        code: "dddd".to_string(),
+        ..Default::default()
+    });
+    assert_eq!(
+        tracker.update(vec![invite("aaaa", 1, 0), invite("bbbb", 5, 0)]),
+        InviteAttribution::Invite(invite("dddd", 1, 1))
+    );
+
+    tracker.invite_deleted(&InviteDelete {
+        guild_id: Some(Snowflake(1)),
+        code: "aaaa".to_string(),
+        ..Default::default()
+    });
+    assert_eq!(tracker.invites().count(), 1);
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn get_list_delete_invite() {
+    let mut bundle = common::setup().await;
+    let channel_id = bundle.channel.read().unwrap().id;
+    let guild_id = bundle.guild.read().unwrap().id;
+
+    let mut tracker = InviteTracker::new(&mut bundle.user, guild_id)
+        .await
+        .unwrap();
+
+    let invite = bundle
+        .user
+        .create_channel_invite(CreateChannelInviteSchema::default(), channel_id)
+        .await
+        .unwrap();
+
+    let resolved = bundle
+        .user
+        .get_invite(
+            &invite.code,
+            GetInvitesSchema {
+                with_counts: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(resolved.code, invite.code);
+
+    let guild_invites = Guild::get_invites(&mut bundle.user, guild_id)
+        .await
+        .unwrap();
+    assert!(guild_invites.iter().any(|i| i.code == invite.code));
+    let channel_invites = Channel::get_invites(&mut bundle.user, channel_id)
+        .await
+        .unwrap();
+    assert!(channel_invites.iter().any(|i| i.code == invite.code));
+
+    let mut other_user = bundle.create_user("testuser1312").await;
+    other_user.accept_invite(&invite.code, None).await.unwrap();
+    let join = GuildMemberAdd {
+        guild_id,
+        ..Default::default()
+    };
+    match tracker
+        .attribute_join(&mut bundle.user, &join)
+        .await
+        .unwrap()
+    {
+        InviteAttribution::Invite(used) => assert_eq!(used.code, invite.code),
+        attribution => panic!("Expected the join to be attributed, got {:?}", attribution),
+    }
+
+    bundle.user.delete_invite(&invite.code, None).await.unwrap();
+    assert!(bundle
+        .user
+        .get_invite(&invite.code, GetInvitesSchema::default())
+        .await
+        .is_err());
+
+    common::teardown(bundle).await;
+}
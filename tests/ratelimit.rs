@@ -2,7 +2,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use chorus::ratelimiter::ChorusRequest;
+use std::time::Duration;
+
+use chorus::errors::ChorusError;
+use chorus::instance::ChorusUser;
+use chorus::ratelimiter::{ChorusRequest, RatelimitBehaviour, RatelimitHeaders, RetryPolicy};
+use chorus::types::LimitType;
+use reqwest::Method;
 
 mod common;
 
@@ -28,3 +34,419 @@ async fn get_limit_config() {
     assert!(conf.user.max_guilds > 0);
     assert!(conf.user.max_friends > 0);
 }
+
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+#[cfg(not(target_arch = "wasm32"))]
+async fn get_limit_config_with_client() {
+    use chorus::types::LimitsConfiguration;
+    use httptest::matchers::{all_of, contains, request};
+    use httptest::responders::json_encoded;
+    use httptest::Expectation;
+
+    let server = httptest::Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/api/policies/instance/limits/"),
+            request::headers(contains(("x-client", "configured"))),
+        ])
+        .respond_with(json_encoded(LimitsConfiguration::default())),
+    );
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-client", "configured".parse().unwrap());
+    let client = reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap();
+
+    let conf =
+        ChorusRequest::get_limits_config_with_client(server.url_str("/api").as_str(), &client)
+            .await
+            .unwrap();
+    assert_eq!(conf, LimitsConfiguration::default());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn test_wait_for_rate_limit() {
+    let mut bundle = common::setup().await;
+
+    if bundle
+        .user
+        .belongs_to
+        .read()
+        .unwrap()
+        .limits_information
+        .is_none()
+    {
+        log::warn!("Instance is not rate limited, skipping test");
+        common::teardown(bundle).await;
+        return;
+    }
+
+    let exhaust_global_limit = |user: &ChorusUser| {
+        let mut instance = user.belongs_to.write().unwrap();
+        let global = instance
+            .limits_information
+            .as_mut()
+            .unwrap()
+            .ratelimits
+            .get_mut(&LimitType::Global)
+            .unwrap();
+        global.remaining = 0;
+        global.reset = chrono::Utc::now().timestamp() as u64 + 1;
+    };
+
+    // Waiting longer than the maximum wait time should still fail
+    bundle
+        .user
+        .belongs_to
+        .write()
+        .unwrap()
+        .set_ratelimit_behaviour(RatelimitBehaviour::Wait {
+            max_wait: Duration::from_millis(100),
+        });
+    exhaust_global_limit(&bundle.user);
+    let result = bundle.user.get_current_user().await;
+    assert!(matches!(result, Err(ChorusError::RateLimited { .. })));
+
+    // ..but the request should go out once the limit resets
+    bundle
+        .user
+        .belongs_to
+        .write()
+        .unwrap()
+        .set_ratelimit_behaviour(RatelimitBehaviour::Wait {
+            max_wait: Duration::from_secs(10),
+        });
+    exhaust_global_limit(&bundle.user);
+    bundle.user.get_current_user().await.unwrap();
+
+    bundle
+        .user
+        .belongs_to
+        .write()
+        .unwrap()
+        .set_ratelimit_behaviour(RatelimitBehaviour::ReturnError);
+    common::teardown(bundle).await
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_parse_ratelimit_headers() {
+    let mut headers = reqwest::header::HeaderMap::new();
+    assert_eq!(RatelimitHeaders::from_headers(&headers), None);
+
+    headers.insert("X-RateLimit-Bucket", "abcd1234".parse().unwrap());
+    headers.insert("X-RateLimit-Limit", "5".parse().unwrap());
+    headers.insert("X-RateLimit-Remaining", "0".parse().unwrap());
+    headers.insert("X-RateLimit-Reset-After", "1.5".parse().unwrap());
+    headers.insert("X-RateLimit-Global", "true".parse().unwrap());
+    headers.insert("Retry-After", "2".parse().unwrap());
+
+    let parsed = RatelimitHeaders::from_headers(&headers).unwrap();
+    assert_eq!(
+        parsed,
+        RatelimitHeaders {
+            bucket: Some("abcd1234".to_string()),
+            limit: Some(5),
+            remaining: Some(0),
+            reset_after: Some(Duration::from_millis(1500)),
+            global: true,
+            retry_after: Some(Duration::from_secs(2)),
+        }
+    );
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_retry_policy_backoff() {
+    let policy = RetryPolicy {
+        max_attempts: 5,
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_millis(300),
+        ..Default::default()
+    };
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(3), Duration::from_millis(300));
+    assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(300));
+
+    assert!(RetryPolicy::is_idempotent(&Method::GET));
+    assert!(RetryPolicy::is_idempotent(&Method::PUT));
+    assert!(RetryPolicy::is_idempotent(&Method::DELETE));
+    assert!(!RetryPolicy::is_idempotent(&Method::POST));
+    assert!(!RetryPolicy::is_idempotent(&Method::PATCH));
+
+    assert_eq!(RetryPolicy::default().max_attempts, 1);
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+#[cfg(not(target_arch = "wasm32"))]
+async fn test_retry_transient_failures() {
+    use std::sync::{Arc, Mutex};
+
+    use chorus::ratelimiter::{RetryAttempt, RetryReason};
+    use chorus::types::{Guild, GuildCreateSchema, Snowflake};
+    use httptest::matchers::{all_of, request};
+    use httptest::responders::{cycle, json_encoded, status_code};
+    use httptest::Expectation;
+
+    let server = common::create_mock_server();
+    let mut bundle = common::setup_with_mock_server(&server).await;
+
+    let retries: Arc<Mutex<Vec<RetryAttempt>>> = Arc::default();
+    let recorded = retries.clone();
+    bundle
+        .user
+        .belongs_to
+        .write()
+        .unwrap()
+        .set_retry_policy(RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(10),
+            on_retry: Some(Arc::new(move |attempt: &RetryAttempt| {
+                recorded.lock().unwrap().push(attempt.clone())
+            })),
+            ..Default::default()
+        });
+
+    let guild_id = Snowflake(1);
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/api/guilds/1"),
+        ])
+        .times(3)
+        .respond_with(cycle![
+            status_code(503),
+            status_code(502),
+            json_encoded(Guild {
+                id: guild_id,
+                ..Default::default()
+            }),
+        ]),
+    );
+
+    let guild = Guild::get(guild_id, None, &mut bundle.user).await.unwrap();
+    assert_eq!(guild.id, guild_id);
+    {
+        let retries = retries.lock().unwrap();
+        assert_eq!(retries.len(), 2);
+        assert_eq!(retries[0].attempt, 2);
+        assert_eq!(retries[0].reason, RetryReason::Status(503));
+        assert_eq!(retries[1].reason, RetryReason::Status(502));
+        assert!(retries[1].delay <= Duration::from_millis(20));
+    }
+
+    // Creating a guild is not idempotent, so it must not be sent twice
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/api/guilds"),
+        ])
+        .times(1)
+        .respond_with(status_code(503)),
+    );
+
+    let result = Guild::create(&mut bundle.user, GuildCreateSchema::default()).await;
+    assert!(matches!(
+        result,
+        Err(ChorusError::ReceivedErrorCode {
+            error_code: 503,
+            ..
+        })
+    ));
+    assert_eq!(retries.lock().unwrap().len(), 2);
+}
+
+/// Sets up a user on a mocked instance with the default rate limit configuration.
+///
+/// Unlike [common::setup_with_mock_server], this does not need a gateway.
+#[cfg(not(target_arch = "wasm32"))]
+async fn setup_with_limits_config(server: &httptest::Server) -> ChorusUser {
+    use chorus::instance::Instance;
+    use chorus::types::{ClientProperties, IntoShared, LimitsConfiguration, User, UserSettings};
+    use chorus::UrlBundle;
+    use httptest::matchers::{all_of, request};
+    use httptest::responders::{json_encoded, status_code};
+    use httptest::Expectation;
+
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/api/policies/instance/limits"),
+        ])
+        .respond_with(json_encoded(LimitsConfiguration::default())),
+    );
+    for path in ["/api/policies/instance/", "/api/version", "/api/ping"] {
+        server.expect(
+            Expectation::matching(all_of![request::method("GET"), request::path(path)])
+                .times(0..100)
+                .respond_with(status_code(404)),
+        );
+    }
+
+    let urls = UrlBundle::new(
+        server.url_str("").as_str(),
+        server.url_str("/api").as_str(),
+        "ws://127.0.0.1:9",
+        server.url_str("/cdn").as_str(),
+    );
+    let instance = Instance::from_url_bundle(urls, None).await.unwrap();
+    assert!(instance.limits_information.is_some());
+
+    let limits = instance
+        .limits_information
+        .as_ref()
+        .map(|information| information.ratelimits.clone());
+    ChorusUser::new(
+        instance.into_shared(),
+        "faketoken".to_string(),
+        ClientProperties::default(),
+        limits,
+        UserSettings::default().into_shared(),
+        User::default().into_shared(),
+        None,
+    )
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+#[cfg(not(target_arch = "wasm32"))]
+async fn test_learn_and_exhaust_route_bucket() {
+    use chorus::types::{Guild, Snowflake};
+    use httptest::matchers::{all_of, request};
+    use httptest::responders::json_encoded;
+    use httptest::Expectation;
+
+    let server = httptest::Server::run();
+    let mut user = setup_with_limits_config(&server).await;
+
+    let guild_id = Snowflake(1);
+    // The bucket is exhausted after the first request, so the second one must not be sent
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/api/guilds/1"),
+        ])
+        .times(1)
+        .respond_with(
+            json_encoded(Guild {
+                id: guild_id,
+                ..Default::default()
+            })
+            .insert_header("X-RateLimit-Bucket", "guild")
+            .insert_header("X-RateLimit-Limit", "1")
+            .insert_header("X-RateLimit-Remaining", "0")
+            .insert_header("X-RateLimit-Reset-After", "60"),
+        ),
+    );
+
+    let guild = Guild::get(guild_id, None, &mut user).await.unwrap();
+    assert_eq!(guild.id, guild_id);
+    assert_eq!(
+        user.route_ratelimits.bucket_of("GET /api/guilds/:id"),
+        Some("guild".to_string())
+    );
+
+    let result = Guild::get(guild_id, None, &mut user).await;
+    assert!(matches!(result, Err(ChorusError::RateLimited { .. })));
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+#[cfg(not(target_arch = "wasm32"))]
+async fn test_wait_for_route_bucket() {
+    use chorus::types::{Guild, Snowflake};
+    use httptest::matchers::{all_of, request};
+    use httptest::responders::json_encoded;
+    use httptest::Expectation;
+
+    let server = httptest::Server::run();
+    let mut user = setup_with_limits_config(&server).await;
+    user.belongs_to
+        .write()
+        .unwrap()
+        .set_ratelimit_behaviour(RatelimitBehaviour::Wait {
+            max_wait: Duration::from_secs(10),
+        });
+
+    let guild_id = Snowflake(1);
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/api/guilds/1"),
+        ])
+        .times(2)
+        .respond_with(
+            json_encoded(Guild {
+                id: guild_id,
+                ..Default::default()
+            })
+            .insert_header("X-RateLimit-Bucket", "guild")
+            .insert_header("X-RateLimit-Limit", "1")
+            .insert_header("X-RateLimit-Remaining", "0")
+            .insert_header("X-RateLimit-Reset-After", "1"),
+        ),
+    );
+
+    Guild::get(guild_id, None, &mut user).await.unwrap();
+    let started = std::time::Instant::now();
+    Guild::get(guild_id, None, &mut user).await.unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(900));
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+#[cfg(not(target_arch = "wasm32"))]
+async fn test_route_bucket_without_headers() {
+    use chorus::types::{Guild, LimitsConfiguration, Snowflake};
+    use httptest::matchers::{all_of, request};
+    use httptest::responders::json_encoded;
+    use httptest::Expectation;
+
+    let server = httptest::Server::run();
+    let mut user = setup_with_limits_config(&server).await;
+
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/api/guilds/1"),
+        ])
+        .times(1)
+        .respond_with(
+            json_encoded(Guild {
+                id: Snowflake(1),
+                ..Default::default()
+            })
+            .insert_header("X-RateLimit-Bucket", "guild")
+            .insert_header("X-RateLimit-Limit", "5")
+            .insert_header("X-RateLimit-Remaining", "4")
+            .insert_header("X-RateLimit-Reset-After", "60"),
+        ),
+    );
+    Guild::get(Snowflake(1), None, &mut user).await.unwrap();
+
+    // The route's bucket is known now, so the configured limits of another guild are not
+    // checked before sending; they still have to be counted if the response has no bucket
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/api/guilds/2"),
+        ])
+        .times(1)
+        .respond_with(json_encoded(Guild {
+            id: Snowflake(2),
+            ..Default::default()
+        })),
+    );
+    Guild::get(Snowflake(2), None, &mut user).await.unwrap();
+
+    let guild_limit = LimitsConfiguration::default().rate.routes.guild;
+    let limit = user
+        .limits
+        .as_ref()
+        .unwrap()
+        .get(&LimitType::Guild(Snowflake(2)))
+        .unwrap();
+    assert_eq!(limit.remaining, guild_limit.count - 1);
+}
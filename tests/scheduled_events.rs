@@ -0,0 +1,156 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chorus::types::{
+    GetGuildScheduledEventUsersQuery, GuildScheduledEvent, GuildScheduledEventCreateSchema,
+    GuildScheduledEventEntityMetadata, GuildScheduledEventEntityType,
+    GuildScheduledEventModifySchema, GuildScheduledEventStatus,
+};
+use chrono::{Duration, Utc};
+
+mod common;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn external_event() -> GuildScheduledEventCreateSchema {
+    let start = Utc::now() + Duration::days(1);
+    GuildScheduledEventCreateSchema {
+        name: "Movie night".to_string(),
+        entity_metadata: Some(GuildScheduledEventEntityMetadata {
+            location: Some("The living room".to_string()),
+        }),
+        scheduled_start_time: start,
+        scheduled_end_time: Some(start + Duration::hours(2)),
+        entity_type: GuildScheduledEventEntityType::External,
+        ..Default::default()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn scheduled_event_status_transitions() {
+    use GuildScheduledEventStatus::*;
+
+    assert!(Scheduled.can_transition_to(Active));
+    assert!(Scheduled.can_transition_to(Canceled));
+    assert!(Active.can_transition_to(Completed));
+
+    assert!(!Scheduled.can_transition_to(Completed));
+    assert!(!Active.can_transition_to(Scheduled));
+    assert!(!Active.can_transition_to(Canceled));
+    assert!(!Completed.can_transition_to(Active));
+    assert!(!Canceled.can_transition_to(Scheduled));
+
+    let schema = GuildScheduledEventModifySchema {
+        status: Some(Completed),
+        ..Default::default()
+    };
+    assert!(schema.validate(Scheduled).is_err());
+    assert!(schema.validate(Active).is_ok());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn validate_scheduled_event_schemas() {
+    assert!(external_event().validate().is_ok());
+
+    let mut schema = external_event();
+    schema.entity_metadata = None;
+    assert!(schema.validate().is_err());
+
+    let mut schema = external_event();
+    schema.scheduled_end_time = None;
+    assert!(schema.validate().is_err());
+
+    let mut schema = external_event();
+    schema.scheduled_end_time = Some(schema.scheduled_start_time - Duration::hours(1));
+    assert!(schema.validate().is_err());
+
+    let mut schema = external_event();
+    schema.name = String::new();
+    assert!(schema.validate().is_err());
+
+    // Voice events need a channel
+    let mut schema = external_event();
+    schema.entity_type = GuildScheduledEventEntityType::Voice;
+    assert!(schema.validate().is_err());
+
+    // Making an event external requires removing its channel
+    let mut modify_schema = GuildScheduledEventModifySchema {
+        entity_type: Some(GuildScheduledEventEntityType::External),
+        entity_metadata: external_event().entity_metadata,
+        scheduled_end_time: external_event().scheduled_end_time,
+        ..Default::default()
+    };
+    assert!(modify_schema
+        .validate(GuildScheduledEventStatus::Scheduled)
+        .is_err());
+    modify_schema.channel_id = Some(None);
+    assert!(modify_schema
+        .validate(GuildScheduledEventStatus::Scheduled)
+        .is_ok());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn create_modify_delete_scheduled_event() {
+    let mut bundle = common::setup().await;
+    let guild_id = bundle.guild.read().unwrap().id;
+
+    let event = GuildScheduledEvent::create(&mut bundle.user, guild_id, external_event(), None)
+        .await
+        .unwrap();
+    assert_eq!(event.name, "Movie night");
+    assert_eq!(event.guild_id, guild_id);
+    assert_eq!(event.status, GuildScheduledEventStatus::Scheduled);
+
+    let events = GuildScheduledEvent::get_all(&mut bundle.user, guild_id, true)
+        .await
+        .unwrap();
+    assert!(events.iter().any(|e| e.id == event.id));
+
+    let modify_schema = GuildScheduledEventModifySchema {
+        name: Some("Movie marathon".to_string()),
+        ..Default::default()
+    };
+    let modified =
+        GuildScheduledEvent::modify(&mut bundle.user, guild_id, event.id, modify_schema, None)
+            .await
+            .unwrap();
+    assert_eq!(modified.name, "Movie marathon");
+
+    // Completing an event which never started is rejected before sending anything
+    let modify_schema = GuildScheduledEventModifySchema {
+        status: Some(GuildScheduledEventStatus::Completed),
+        ..Default::default()
+    };
+    assert!(
+        GuildScheduledEvent::modify(&mut bundle.user, guild_id, event.id, modify_schema, None)
+            .await
+            .is_err()
+    );
+
+    let mut paginator = GuildScheduledEvent::users_paginator(
+        &bundle.user,
+        guild_id,
+        event.id,
+        GetGuildScheduledEventUsersQuery::default(),
+    );
+    while let Some(page) = paginator.next_page().await.unwrap() {
+        assert!(page.len() <= 100);
+    }
+
+    GuildScheduledEvent::delete(&mut bundle.user, guild_id, event.id, None)
+        .await
+        .unwrap();
+    assert!(
+        GuildScheduledEvent::get(&mut bundle.user, guild_id, event.id, false)
+            .await
+            .is_err()
+    );
+
+    common::teardown(bundle).await;
+}
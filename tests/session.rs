@@ -0,0 +1,191 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use chorus::api::auth::SessionSnapshot;
+use chorus::errors::ChorusError;
+use chorus::gateway::GatewaySessionState;
+use chorus::instance::GatewayConnectBehaviour;
+use chorus::types::{ClientProperties, Limit, LimitType, MfaToken, Snowflake, User, UserSettings};
+use chorus::UrlBundle;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn snapshot() -> SessionSnapshot {
+    let mut gateway_session = GatewaySessionState::default();
+    gateway_session.session_id = Some("session".to_string());
+    gateway_session.sequence_number = Some(42);
+
+    SessionSnapshot {
+        urls: UrlBundle::new(
+            "http://localhost:3001",
+            "http://localhost:3001/api",
+            "ws://localhost:3001",
+            "http://localhost:3001",
+        ),
+        token: "token".to_string(),
+        client_properties: ClientProperties::minimal(),
+        send_telemetry_headers: true,
+        mfa_token: None,
+        limits: Some(vec![Limit {
+            bucket: LimitType::Channel(Snowflake(1)),
+            limit: 5,
+            remaining: 4,
+            reset: 0,
+            window: 5,
+        }]),
+        settings: UserSettings::default(),
+        object: User {
+            id: Snowflake(2),
+            username: "test".to_string(),
+            ..Default::default()
+        },
+        gateway_session: Some(gateway_session),
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn snapshot_json_roundtrip() {
+    let snapshot = snapshot();
+    let restored = SessionSnapshot::from_json(&snapshot.to_json().unwrap()).unwrap();
+
+    assert_eq!(restored.urls, snapshot.urls);
+    assert_eq!(restored.token, snapshot.token);
+    assert_eq!(
+        restored.send_telemetry_headers,
+        snapshot.send_telemetry_headers
+    );
+    assert_eq!(restored.limits, snapshot.limits);
+    assert_eq!(restored.object, snapshot.object);
+    assert_eq!(restored.gateway_session, snapshot.gateway_session);
+
+    assert!(matches!(
+        SessionSnapshot::from_json("{}"),
+        Err(ChorusError::InvalidSessionSnapshot { .. })
+    ));
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn snapshot_debug_redacts_tokens() {
+    let mut snapshot = snapshot();
+    snapshot.token = "secret-user-token".to_string();
+    snapshot.mfa_token = Some(MfaToken {
+        token: "secret-mfa-token".to_string(),
+        expires_at: chrono::Utc::now(),
+    });
+
+    let debug = format!("{:?}", snapshot);
+    assert!(!debug.contains("secret-user-token"));
+    assert!(!debug.contains("secret-mfa-token"));
+    assert!(debug.contains("session"));
+}
+
+#[cfg(feature = "session_encryption")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn snapshot_encryption() {
+    let snapshot = snapshot();
+    let key = [7; 32];
+
+    let encrypted = snapshot.encrypt(&key).unwrap();
+    let decrypted = SessionSnapshot::decrypt(&encrypted, &key).unwrap();
+    assert_eq!(decrypted.token, snapshot.token);
+    assert_eq!(decrypted.object, snapshot.object);
+
+    assert!(SessionSnapshot::decrypt(&encrypted, &[8; 32]).is_err());
+    assert!(SessionSnapshot::decrypt(&encrypted[..10], &key).is_err());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn restore_session() {
+    let mut bundle = common::setup().await;
+
+    let snapshot = bundle.user.snapshot().await;
+    assert!(snapshot.gateway_session.is_some());
+
+    let restored = bundle
+        .instance
+        .restore_session(SessionSnapshot::from_json(&snapshot.to_json().unwrap()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(
+        restored.object.read().unwrap().id,
+        bundle.user.object.read().unwrap().id
+    );
+    assert!(restored.gateway_handle().is_ok());
+
+    bundle
+        .instance
+        .set_gateway_connect_behaviour(GatewayConnectBehaviour::Manual);
+    let mut without_gateway = snapshot.clone();
+    without_gateway.gateway_session = None;
+    let restored = bundle
+        .instance
+        .restore_session(without_gateway)
+        .await
+        .unwrap();
+    assert!(restored.gateway.is_none());
+
+    let mut invalid_token = snapshot.clone();
+    invalid_token.token = "invalid".to_string();
+    assert!(bundle
+        .instance
+        .restore_session(invalid_token)
+        .await
+        .is_err());
+
+    let mut other_instance = snapshot;
+    other_instance.urls.api = "http://localhost:3002/api".to_string();
+    assert!(matches!(
+        bundle.instance.restore_session(other_instance).await,
+        Err(ChorusError::InvalidArguments { .. })
+    ));
+
+    common::teardown(bundle).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+/// Tests that a gateway session is resumed on the url the server sent for resuming it
+async fn resume_gateway_on_resume_url() {
+    use std::time::Duration;
+
+    use chorus::types::GatewayReady;
+
+    let (gateway_url, mut gateway_payloads) =
+        common::create_mock_gateway(GatewayReady::default()).await;
+    let (resume_url, mut resume_payloads) =
+        common::create_mock_gateway(GatewayReady::default()).await;
+
+    let mut user = common::setup_with_mock_gateway(&gateway_url, User::default()).await;
+    user.disconnect_gateway().await;
+
+    let mut gateway_session = GatewaySessionState::default();
+    gateway_session.session_id = Some("session".to_string());
+    gateway_session.resume_gateway_url = Some(resume_url);
+    gateway_session.sequence_number = Some(42);
+    user.resume_gateway(gateway_session).await.unwrap();
+
+    let resume = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let payload = resume_payloads.recv().await.unwrap();
+            if payload["op"] == 6 {
+                return payload;
+            }
+        }
+    })
+    .await
+    .unwrap();
+    assert_eq!(resume["d"]["session_id"], "session");
+
+    while let Ok(payload) = gateway_payloads.try_recv() {
+        assert_ne!(payload["op"], 6);
+    }
+}
@@ -0,0 +1,108 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chorus::api::channels::SpeakerQueue;
+use chorus::types::{
+    Snowflake, StageInstanceCreateSchema, StageInstanceModifySchema, VoiceState,
+    VoiceStateUpdateSchema,
+};
+use chrono::{TimeZone, Utc};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn voice_state(
+    user_id: u64,
+    channel_id: u64,
+    suppress: bool,
+    requested_at: Option<i64>,
+) -> VoiceState {
+    VoiceState {
+        guild_id: Some(Snowflake(1)),
+        channel_id: Some(Snowflake(channel_id)),
+        user_id: Snowflake(user_id),
+        suppress,
+        request_to_speak_timestamp: requested_at
+            .map(|seconds| Utc.timestamp_opt(seconds, 0).unwrap()),
+        ..Default::default()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn speaker_queue_tracks_requests() {
+    let mut queue = SpeakerQueue::for_channel(Snowflake(1), Snowflake(10));
+
+    // Joining the audience is not a request
+    assert_eq!(queue.observe(&voice_state(2, 10, true, None)), None);
+    // Raising a hand is, but only once
+    let first = queue.observe(&voice_state(2, 10, true, Some(200))).unwrap();
+    assert_eq!(first.user_id, Snowflake(2));
+    assert_eq!(first.channel_id, Snowflake(10));
+    assert_eq!(queue.observe(&voice_state(2, 10, true, Some(200))), None);
+
+    let second = queue.observe(&voice_state(3, 10, true, Some(100))).unwrap();
+    // Other channels and guilds are ignored
+    assert_eq!(queue.observe(&voice_state(4, 11, true, Some(50))), None);
+    let mut other_guild = voice_state(5, 10, true, Some(50));
+    other_guild.guild_id = Some(Snowflake(2));
+    assert_eq!(queue.observe(&other_guild), None);
+
+    assert_eq!(queue.pending(), vec![second, first]);
+
+    // Becoming a speaker closes the request
+    assert_eq!(queue.observe(&voice_state(3, 10, false, Some(100))), None);
+    assert_eq!(queue.pending(), vec![first]);
+
+    assert_eq!(queue.remove(Snowflake(2)), Some(first));
+    assert!(queue.pending().is_empty());
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn validate_stage_instance_schemas() {
+    let mut schema = StageInstanceCreateSchema {
+        channel_id: Snowflake(1),
+        topic: "Weekly meeting".to_string(),
+        ..Default::default()
+    };
+    assert!(schema.validate().is_ok());
+    schema.topic = String::new();
+    assert!(schema.validate().is_err());
+    schema.topic = "a".repeat(121);
+    assert!(schema.validate().is_err());
+
+    assert!(StageInstanceModifySchema::default().validate().is_ok());
+    assert!(StageInstanceModifySchema {
+        topic: Some(String::new()),
+        ..Default::default()
+    }
+    .validate()
+    .is_err());
+
+    let unsuppress = serde_json::to_value(VoiceStateUpdateSchema {
+        channel_id: Some(Snowflake(1)),
+        suppress: Some(false),
+        request_to_speak_timestamp: None,
+    })
+    .unwrap();
+    assert_eq!(
+        unsuppress,
+        serde_json::json!({
+            "channel_id": "1",
+            "suppress": false
+        })
+    );
+
+    let withdraw = serde_json::to_value(VoiceStateUpdateSchema {
+        request_to_speak_timestamp: Some(None),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(
+        withdraw,
+        serde_json::json!({ "request_to_speak_timestamp": null })
+    );
+}
@@ -968,6 +968,189 @@ mod entities {
         }
     }
 
+    mod audit_log {
+        use chorus::types::{
+            AuditLogActionType, AuditLogChangeValue, AuditLogEntry, ChannelType, PermissionFlags,
+            Snowflake,
+        };
+        use serde_json::json;
+
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+        #[cfg_attr(not(target_arch = "wasm32"), test)]
+        fn decode_audit_log_changes() {
+            let entry: AuditLogEntry = serde_json::from_value(json!({
+                "id": "1",
+                "action_type": 11,
+                "target_id": "2",
+                "user_id": "3",
+                "changes": [
+                    { "key": "name", "old_value": "general", "new_value": "off-topic" },
+                    { "key": "type", "old_value": 0, "new_value": 5 },
+                    { "key": "parent_id", "new_value": "4" },
+                    { "key": "nsfw", "old_value": false, "new_value": true },
+                    { "key": "rate_limit_per_user", "old_value": 0, "new_value": 10 },
+                    { "key": "topic", "old_value": null, "new_value": "Chorus" },
+                    { "key": "allow", "new_value": "1024" }
+                ]
+            }))
+            .unwrap();
+            let changes = entry.decoded_changes();
+            assert_eq!(changes.len(), 7);
+
+            assert_eq!(
+                changes[0].new_value,
+                Some(AuditLogChangeValue::String("off-topic".to_string()))
+            );
+            assert_eq!(
+                changes[1].new_value,
+                Some(AuditLogChangeValue::ChannelType(ChannelType::GuildNews))
+            );
+            assert_eq!(changes[2].old_value, None);
+            assert_eq!(
+                changes[2].new_value,
+                Some(AuditLogChangeValue::Snowflake(Snowflake(4)))
+            );
+            assert_eq!(changes[3].new_value, Some(AuditLogChangeValue::Bool(true)));
+            assert_eq!(changes[4].new_value, Some(AuditLogChangeValue::Integer(10)));
+            assert_eq!(changes[5].old_value, None);
+            assert_eq!(
+                changes[6].new_value,
+                Some(AuditLogChangeValue::Permissions(
+                    PermissionFlags::VIEW_CHANNEL
+                ))
+            );
+            assert_eq!(entry.action_type, AuditLogActionType::ChannelUpdate);
+        }
+
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+        #[cfg_attr(not(target_arch = "wasm32"), test)]
+        fn decode_member_role_update() {
+            let entry: AuditLogEntry = serde_json::from_value(json!({
+                "id": "1",
+                "action_type": 25,
+                "changes": [
+                    { "key": "$add", "new_value": [{ "id": "5", "name": "Moderator" }] }
+                ]
+            }))
+            .unwrap();
+            let changes = entry.decoded_changes();
+
+            let Some(AuditLogChangeValue::Roles(roles)) = &changes[0].new_value else {
+                panic!("Expected roles, got {:?}", changes[0].new_value);
+            };
+            assert_eq!(roles[0].id, Snowflake(5));
+            assert_eq!(roles[0].name.as_deref(), Some("Moderator"));
+        }
+    }
+
+    mod template {
+        use chorus::types::{ChannelType, GuildTemplate, PermissionFlags, Snowflake};
+        use serde_json::json;
+
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+        #[cfg_attr(not(target_arch = "wasm32"), test)]
+        fn deserialize_serialized_source_guild() {
+            let template: GuildTemplate = serde_json::from_value(json!({
+                "code": "hgM48av5Q69A",
+                "name": "Friends & Family",
+                "description": null,
+                "usage_count": 3,
+                "creator_id": "132117086741839872",
+                "creator": {
+                    "id": "132117086741839872",
+                    "username": "template-creator",
+                    "discriminator": "0001",
+                    "avatar": null
+                },
+                "created_at": "2020-04-02T21:10:38+00:00",
+                "updated_at": "2020-05-01T17:57:38+00:00",
+                "source_guild_id": "678070694164299796",
+                "serialized_source_guild": {
+                    "name": "Friends & Family",
+                    "region": "us-west",
+                    "verification_level": 0,
+                    "default_message_notifications": 0,
+                    "afk_timeout": 300,
+                    "roles": [
+                        { "id": 0, "name": "@everyone", "permissions": "104324689", "color": 0,
+                          "hoist": false, "mentionable": false }
+                    ],
+                    "channels": [
+                        { "id": 1, "type": 4, "name": "Text Channels", "position": 0 },
+                        { "id": 2, "type": 0, "name": "general", "position": 0, "parent_id": 1,
+                          "permission_overwrites": [
+                              { "id": 0, "type": 0, "allow": "0", "deny": "2048" }
+                          ] }
+                    ],
+                    "afk_channel_id": null,
+                    "system_channel_id": 2,
+                    "system_channel_flags": 0
+                },
+                "is_dirty": null
+            }))
+            .unwrap();
+
+            let source_guild = template.serialized_source_guild;
+            assert_eq!(source_guild.name, "Friends & Family");
+            assert_eq!(source_guild.system_channel_id, Some(Snowflake(2)));
+            assert_eq!(source_guild.roles[0].id, Snowflake(0));
+            assert_eq!(source_guild.channels.len(), 2);
+            assert_eq!(
+                source_guild.channels[0].channel_type,
+                ChannelType::GuildCategory
+            );
+            assert_eq!(source_guild.channels[1].parent_id, Some(Snowflake(1)));
+            assert_eq!(
+                source_guild.channels[1].permission_overwrites[0].deny,
+                PermissionFlags::SEND_MESSAGES
+            );
+        }
+    }
+
+    mod integration {
+        use chorus::types::{IntegrationCreate, IntegrationDelete, IntegrationType, Snowflake};
+        use serde_json::json;
+
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+        #[cfg_attr(not(target_arch = "wasm32"), test)]
+        fn deserialize_integration_events() {
+            let create: IntegrationCreate = serde_json::from_value(json!({
+                "id": "33590653072239123",
+                "name": "A Twitch Channel",
+                "type": "twitch",
+                "enabled": true,
+                "syncing": false,
+                "role_id": "41771983429993000",
+                "enable_emoticons": true,
+                "expire_behavior": 1,
+                "expire_grace_period": 7,
+                "account": { "id": "1234567", "name": "twitchchannel" },
+                "synced_at": "2021-03-10T15:09:43+00:00",
+                "subscriber_count": 12,
+                "revoked": false,
+                "guild_id": "41771983423143937"
+            }))
+            .unwrap();
+            assert_eq!(create.guild_id, Snowflake(41771983423143937));
+            assert_eq!(create.integration.id, Snowflake(33590653072239123));
+            assert_eq!(create.integration.integration_type, IntegrationType::Twitch);
+            assert_eq!(create.integration.account.name, "twitchchannel");
+
+            let mut same = create.integration.clone();
+            assert_eq!(same, create.integration);
+            same.enabled = false;
+            assert_ne!(same, create.integration);
+
+            let delete: IntegrationDelete = serde_json::from_value(json!({
+                "id": "33590653072239123",
+                "guild_id": "41771983423143937"
+            }))
+            .unwrap();
+            assert_eq!(delete.id, create.integration.id);
+            assert_eq!(delete.application_id, None);
+        }
+    }
+
     mod message {
         use chorus::types::{Message, Snowflake};
 
@@ -15,7 +15,8 @@ wasm_bindgen_test_configure!(run_in_browser);
 async fn test_parse_url() {
     // TODO: Currently only tests two of the three branches in UrlBundle::from_root_domain.
     let url = url::Url::parse("http://localhost:3001/").unwrap();
-    UrlBundle::from_root_url(url.as_str()).await.unwrap();
+    let bundle = UrlBundle::from_root_url(url.as_str()).await.unwrap();
+    assert_eq!(bundle.root, "http://localhost:3001");
     let url = url::Url::parse("http://localhost:3001/api/").unwrap();
     UrlBundle::from_root_url(url.as_str()).await.unwrap();
 }
@@ -0,0 +1,125 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use chorus::api::WebhookClient;
+use chorus::types::{
+    CreateWebhookSchema, ModifyWebhookSchema, Snowflake, Webhook, WebhookExecuteSchema,
+    WebhookMessageModifySchema,
+};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn create_modify_delete_webhook() {
+    let mut bundle = common::setup().await;
+    let channel_id = bundle.channel.read().unwrap().id;
+    let guild_id = bundle.guild.read().unwrap().id;
+
+    let schema = CreateWebhookSchema {
+        name: "Chorus CI".to_string(),
+        avatar: None,
+    };
+    let webhook = Webhook::create(&mut bundle.user, channel_id, schema, None)
+        .await
+        .unwrap();
+    assert_eq!(webhook.name.as_deref(), Some("Chorus CI"));
+    assert_eq!(webhook.channel_id, Some(channel_id));
+
+    let fetched = Webhook::get(&mut bundle.user, webhook.id).await.unwrap();
+    assert_eq!(fetched.id, webhook.id);
+
+    let channel_webhooks = Webhook::get_channel_webhooks(&mut bundle.user, channel_id)
+        .await
+        .unwrap();
+    assert!(channel_webhooks.iter().any(|w| w.id == webhook.id));
+
+    let guild_webhooks = Webhook::get_guild_webhooks(&mut bundle.user, guild_id)
+        .await
+        .unwrap();
+    assert!(guild_webhooks.iter().any(|w| w.id == webhook.id));
+
+    let modify_schema = ModifyWebhookSchema {
+        name: Some("Chorus CD".to_string()),
+        ..Default::default()
+    };
+    let modified = Webhook::modify(&mut bundle.user, webhook.id, modify_schema, None)
+        .await
+        .unwrap();
+    assert_eq!(modified.name.as_deref(), Some("Chorus CD"));
+
+    Webhook::delete(&mut bundle.user, webhook.id, None)
+        .await
+        .unwrap();
+    assert!(Webhook::get(&mut bundle.user, webhook.id).await.is_err());
+
+    common::teardown(bundle).await;
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn execute_webhook_with_token() {
+    let mut bundle = common::setup().await;
+    let channel_id = bundle.channel.read().unwrap().id;
+
+    let schema = CreateWebhookSchema {
+        name: "Chorus CI".to_string(),
+        avatar: None,
+    };
+    let webhook = Webhook::create(&mut bundle.user, channel_id, schema, None)
+        .await
+        .unwrap();
+
+    // No user is needed from here on
+    let client = WebhookClient::new(&bundle.urls.api, webhook.id, &webhook.token.unwrap());
+    assert_eq!(client.get().await.unwrap().id, webhook.id);
+
+    let execute_schema = WebhookExecuteSchema {
+        content: Some("The build passed!".to_string()),
+        ..Default::default()
+    };
+    let message = client.execute(execute_schema, None).await.unwrap();
+    assert_eq!(message.content.as_deref(), Some("The build passed!"));
+
+    let edit_schema = WebhookMessageModifySchema {
+        content: Some("The build failed!".to_string()),
+        ..Default::default()
+    };
+    let edited = client
+        .edit_message(message.id, edit_schema, None)
+        .await
+        .unwrap();
+    assert_eq!(edited.content.as_deref(), Some("The build failed!"));
+
+    client.delete_message(message.id, None).await.unwrap();
+    assert!(client.get_message(message.id, None).await.is_err());
+
+    client.delete().await.unwrap();
+    assert!(client.get().await.is_err());
+
+    common::teardown(bundle).await;
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn webhook_client_from_url() {
+    let client =
+        WebhookClient::from_url("https://discord.com/api/webhooks/1234/some-token?wait=true")
+            .unwrap();
+    assert_eq!(client.api_url, "https://discord.com/api");
+    assert_eq!(client.id, Snowflake(1234));
+    assert_eq!(client.token, "some-token");
+    assert_eq!(
+        client.url(),
+        "https://discord.com/api/webhooks/1234/some-token"
+    );
+
+    assert!(WebhookClient::from_url("https://discord.com/api/webhooks/1234").is_err());
+    assert!(WebhookClient::from_url("https://discord.com/api/webhooks/abc/token").is_err());
+    assert!(WebhookClient::from_url("https://discord.com/api/channels/1234/token").is_err());
+}